@@ -0,0 +1,509 @@
+use clap::{Arg, App, SubCommand};
+
+fn main() {
+    let matches = App::new("nihonify")
+        .version("0.1")
+        .author("Jordan McQueen <j@jm.dev>")
+        .subcommand(
+            SubCommand::with_name("convert-date")
+                .arg(
+                    Arg::with_name("date")
+        .long("date") // allow --name
+        .takes_value(true)
+        .help("A YYYY-mm-dd gregorian date to convert to nengou.")
+        .required_unless("epoch"),
+                )
+                .arg(
+                    Arg::with_name("epoch")
+                        .long("epoch")
+                        .takes_value(true)
+                        .allow_hyphen_values(true)
+                        .conflicts_with("date")
+                        .help("A unix epoch to convert to nengou."),
+                )
+                .arg(
+                    Arg::with_name("epoch-unit")
+                        .long("epoch-unit")
+                        .takes_value(true)
+                        .possible_values(&["auto", "s", "ms", "us"])
+                        .default_value("auto")
+                        .help("The unit of --epoch. auto guesses from its magnitude."),
+                )
+                .arg(
+                    Arg::with_name("input-format")
+                        .long("input-format")
+                        .takes_value(true)
+                        .requires("date")
+                        .help("The chrono format string to parse --date with, e.g. %y/%m/%d, instead of YYYY-mm-dd."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("to-gregorian")
+                .about("Converts a wareki date to a YYYY-mm-dd gregorian date.")
+                .arg(
+                    Arg::with_name("date")
+                        .long("date")
+                        .takes_value(true)
+                        .required(true)
+                        .help("The wareki date to convert, e.g. 令和3年11月12日 or R3.11.12."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("list-eras")
+                .about("Lists the eras with their romaji names and start and end dates.")
+                .arg(
+                    Arg::with_name("jidai")
+                        .long("jidai")
+                        .takes_value(true)
+                        .help("Only lists the eras of the period, e.g. edo or 江戸."),
+                )
+                .arg(
+                    Arg::with_name("after")
+                        .long("after")
+                        .takes_value(true)
+                        .allow_hyphen_values(true)
+                        .help("Only lists the eras that started in or after the gregorian year."),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["table", "json"])
+                        .default_value("table"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("clock")
+                .about("Shows the current time in Japan, in wareki.")
+                .arg(
+                    Arg::with_name("once")
+                        .long("once")
+                        .help("Prints the time once instead of updating it every second."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("rewrite")
+                .about("Rewrites the wareki dates in a text to gregorian dates.")
+                .arg(Arg::with_name("file").help("The file to read instead of stdin."))
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .default_value("%Y-%m-%d")
+                        .help("The chrono format string to write dates with."),
+                )
+                .arg(
+                    Arg::with_name("log-timestamps")
+                        .long("log-timestamps")
+                        .takes_value(true)
+                        .possible_values(&["replace", "annotate"])
+                        .help("Rewrites the CLF, RFC 3339 and syslog timestamps in a log to wareki instead, replacing their dates or annotating them."),
+                )
+                .arg(
+                    Arg::with_name("year")
+                        .long("year")
+                        .takes_value(true)
+                        .requires("log-timestamps")
+                        .help("The year of syslog timestamps, which don't have one. Defaults to the current year."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("extract-dates")
+                .about("Lists the wareki dates in a text with their gregorian dates.")
+                .arg(Arg::with_name("file").help("The file to read instead of stdin.")),
+        )
+        .subcommand(
+            SubCommand::with_name("detect")
+                .about("Reports whether a text is Japanese and which scripts it uses.")
+                .arg(Arg::with_name("file").help("The file to read instead of stdin.")),
+        )
+        .subcommand(
+            SubCommand::with_name("table")
+                .about("Prints a wareki/seireki lookup table.")
+                .arg(
+                    Arg::with_name("years")
+                        .help("The gregorian years to cover, inclusive, e.g. 1980..2030.")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["text", "markdown", "csv"])
+                        .default_value("text"),
+                ),
+        )
+        .get_matches();
+
+    if let Some(matches) = matches.subcommand_matches("convert-date") {
+        let date = match matches.value_of("epoch") {
+            Some(epoch) => {
+                let epoch: i64 = match epoch.parse() {
+                    Ok(epoch) => epoch,
+                    Err(_) => {
+                        eprintln!("Invalid epoch {}", epoch);
+                        std::process::exit(1);
+                    }
+                };
+                epoch_to_datetime(epoch, matches.value_of("epoch-unit").unwrap())
+            }
+            None => match matches.value_of("input-format") {
+                Some(format) => parse_date_with(matches.value_of("date").unwrap(), format),
+                None => {
+                    let date = matches.value_of("date").unwrap();
+                    nihonify::try_utc_dt(date).unwrap_or_else(|_| {
+                        eprintln!("Invalid date {}, expected YYYY-mm-dd", date);
+                        std::process::exit(1);
+                    })
+                }
+            },
+        };
+        match nihonify::Era::try_to_jp_nenkou_string(date) {
+            Ok(nenkou) => println!("{}", nenkou),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("to-gregorian") {
+        let date = matches.value_of("date").unwrap();
+        match parse_wareki_date(date) {
+            Ok(date) => println!("{}", date),
+            Err(e) => {
+                eprintln!("Invalid wareki date {}: {}", date, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("list-eras") {
+        list_eras(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("clock") {
+        run_clock(matches.is_present("once"));
+    }
+
+    if let Some(matches) = matches.subcommand_matches("rewrite") {
+        if let Some(mode) = matches.value_of("log-timestamps") {
+            rewrite_log_timestamps(matches, mode == "annotate");
+            return;
+        }
+
+        let format = matches.value_of("format").unwrap().to_owned();
+        let stdout = std::io::stdout();
+        let output = std::io::BufWriter::new(stdout.lock());
+        exit_on_error(nihonify::rewrite::rewrite_wareki_stream(
+            open_input(matches.value_of("file")),
+            output,
+            |date| date.format(&format).to_string(),
+        ));
+    }
+
+    if let Some(matches) = matches.subcommand_matches("extract-dates") {
+        use std::io::Write;
+
+        let stdout = std::io::stdout();
+        let mut output = std::io::BufWriter::new(stdout.lock());
+        let mut result = Ok(());
+        exit_on_error(nihonify::rewrite::find_wareki_stream(
+            open_input(matches.value_of("file")),
+            |text, date| {
+                if result.is_ok() {
+                    result = writeln!(output, "{}\t{}", text, date);
+                }
+            },
+        ));
+        exit_on_error(result.and_then(|_| output.flush()));
+    }
+
+    if let Some(matches) = matches.subcommand_matches("detect") {
+        let report = exit_on_error(nihonify::text::script_report_stream(open_input(
+            matches.value_of("file"),
+        )));
+        println!("japanese: {}", report.hiragana + report.katakana > 0);
+        println!("hiragana: {}", report.hiragana);
+        println!("katakana: {}", report.katakana);
+        println!("cjk_ideographs: {}", report.cjk_ideographs);
+        println!("halfwidth_forms: {}", report.halfwidth_forms);
+        println!("fullwidth_forms: {}", report.fullwidth_forms);
+        println!("jp_punctuation: {}", report.jp_punctuation);
+        println!("latin: {}", report.latin);
+        println!("other: {}", report.other);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("table") {
+        let years = matches.value_of("years").unwrap();
+        let (first, last) = match parse_years(years) {
+            Some(years) => years,
+            None => {
+                eprintln!("Invalid year range {}, expected e.g. 1980..2030", years);
+                std::process::exit(1);
+            }
+        };
+
+        let table = nihonify::ConversionTable::new(first..=last);
+        match matches.value_of("format").unwrap() {
+            "markdown" => print!("{}", table.to_markdown()),
+            "csv" => print!("{}", table.to_csv()),
+            _ => print_aligned(&table),
+        }
+    }
+}
+
+/// Parses the date with the chrono format string, or exits if it doesn't
+/// match.
+fn parse_date_with(date: &str, format: &str) -> chrono::DateTime<chrono::Utc> {
+    match chrono::NaiveDate::parse_from_str(date, format) {
+        Ok(date) => date.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+        Err(e) => {
+            eprintln!("Invalid date {} for format {}: {}", date, format, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parses a wareki date written out, e.g. 令和3年11月12日, or compactly, e.g.
+/// R3.11.12.
+fn parse_wareki_date(s: &str) -> Result<chrono::NaiveDate, nihonify::NihonifyError> {
+    match s.parse::<nihonify::WarekiDate>() {
+        Ok(date) => Ok(date.into()),
+        // Compact dates don't start with an era name.
+        Err(nihonify::NihonifyError::UnknownEra) => nihonify::parse::parse_compact_wareki(s.trim()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Rewrites the log timestamps in the input to wareki, replacing the dates
+/// with their wareki dates or, with `annotate`, adding them after the
+/// timestamps. Timestamps outside the eras are left as is.
+fn rewrite_log_timestamps(matches: &clap::ArgMatches, annotate: bool) {
+    use chrono::Datelike;
+
+    let year = match matches.value_of("year") {
+        Some(year) => year.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid year {}", year);
+            std::process::exit(1);
+        }),
+        None => chrono::Local::now().year(),
+    };
+    let stdout = std::io::stdout();
+    let output = std::io::BufWriter::new(stdout.lock());
+    exit_on_error(nihonify::rewrite::rewrite_log_timestamps_stream(
+        open_input(matches.value_of("file")),
+        output,
+        year,
+        |found| {
+            let date = found.date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+            match nihonify::Era::to_jp_nenkou_string(date) {
+                Some(wareki) if annotate => format!("{} ({})", found.text, wareki),
+                Some(wareki) => format!("{} {}", wareki, found.time),
+                None => found.text.to_owned(),
+            }
+        },
+    ));
+}
+
+/// Prints the named eras that match the filters, as an aligned table or a
+/// JSON array.
+fn list_eras(matches: &clap::ArgMatches) {
+    use chrono::Datelike;
+    use nihonify::text::{display_width, pad_end};
+
+    let jidai = matches.value_of("jidai").map(|name| {
+        parse_jidai(name).unwrap_or_else(|| {
+            eprintln!("Unknown jidai {}, expected e.g. edo or 江戸", name);
+            std::process::exit(1);
+        })
+    });
+    let after: Option<i32> = matches.value_of("after").map(|year| {
+        year.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid year {}", year);
+            std::process::exit(1);
+        })
+    });
+    let eras: Vec<_> = nihonify::Era::all()
+        .filter(|era| era.kanji.is_some())
+        .filter(|era| jidai.is_none_or(|jidai| era.jidai == jidai))
+        .filter(|era| after.is_none_or(|year| era.start_date_jst().year() >= year))
+        .collect();
+
+    if matches.value_of("format") == Some("json") {
+        let eras: Vec<_> = eras
+            .iter()
+            .map(|era| {
+                serde_json::json!({
+                    "kanji": era.kanji,
+                    "romaji": era.romaji,
+                    "start": era.start_date_jst().to_string(),
+                    "end": era.end_date_jst().map(|date| date.to_string()),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&eras).unwrap());
+        return;
+    }
+
+    let rows: Vec<[String; 4]> = eras
+        .iter()
+        .map(|era| {
+            [
+                era.kanji.unwrap().to_owned(),
+                era.romaji.unwrap_or_default().to_owned(),
+                era.start_date_jst().to_string(),
+                era.end_date_jst().map(|date| date.to_string()).unwrap_or_default(),
+            ]
+        })
+        .collect();
+    let header = ["元号", "ローマ字", "開始", "終了"].map(String::from);
+    let widths: Vec<usize> = (0..4)
+        .map(|i| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| display_width(&row[i]))
+                .max()
+                .unwrap()
+        })
+        .collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| pad_end(cell, width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+/// The period named in romaji, case and hyphens aside, e.g. edo or
+/// azuchi-momoyama, or in kanji with or without 時代, e.g. 江戸.
+fn parse_jidai(name: &str) -> Option<nihonify::Jidai> {
+    let romaji = name.replace('-', "").to_lowercase();
+    nihonify::Era::all().map(|era| era.jidai).find(|jidai| {
+        jidai.romaji().replace('-', "") == romaji
+            || jidai.kanji() == name
+            || jidai.kanji().strip_suffix("時代") == Some(name)
+    })
+}
+
+/// Prints the table as text with a header, lining up the columns by display
+/// width so that kanji don't throw them off.
+fn print_aligned(table: &nihonify::ConversionTable) {
+    use nihonify::text::{display_width, pad_end};
+
+    let years: Vec<String> = table.rows.iter().map(|row| format!("{}年", row.year)).collect();
+    let width = years
+        .iter()
+        .map(|year| display_width(year))
+        .chain(std::iter::once(display_width("西暦")))
+        .max()
+        .unwrap();
+
+    println!("{}  和暦", pad_end("西暦", width));
+    for (year, row) in years.iter().zip(&table.rows) {
+        println!("{}  {}", pad_end(year, width), row.wareki());
+    }
+}
+
+/// The file to read, or stdin if there's none.
+fn open_input(file: Option<&str>) -> Box<dyn std::io::Read> {
+    match file {
+        Some(file) => Box::new(std::fs::File::open(file).unwrap_or_else(|e| {
+            eprintln!("{}: {}", file, e);
+            std::process::exit(1);
+        })),
+        None => Box::new(std::io::stdin()),
+    }
+}
+
+/// Unwraps the result, or prints the error and exits.
+fn exit_on_error<T>(result: std::io::Result<T>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    })
+}
+
+/// Prints the current JST time in wareki every second, in place on a
+/// terminal and a line at a time otherwise.
+fn run_clock(once: bool) {
+    use std::io::{IsTerminal, Write};
+
+    let tty = std::io::stdout().is_terminal();
+    loop {
+        let now = chrono::Utc::now();
+        let line = clock_line(now);
+        if once {
+            println!("{}", line);
+            return;
+        }
+
+        let mut stdout = std::io::stdout();
+        if tty {
+            // Clear to the end of the line in case the new time is shorter.
+            write!(stdout, "\r{}\x1b[K", line).unwrap();
+        } else {
+            writeln!(stdout, "{}", line).unwrap();
+        }
+        stdout.flush().unwrap();
+
+        let to_next_second = 1_000_000_000 - now.timestamp_subsec_nanos().min(999_999_999);
+        std::thread::sleep(std::time::Duration::from_nanos(to_next_second.into()));
+    }
+}
+
+/// Renders the time as it is in Japan, e.g. 令和３年１１月１２日 15:04:05 JST.
+fn clock_line(now: chrono::DateTime<chrono::Utc>) -> String {
+    let jst = chrono::FixedOffset::east_opt(nihonify::calendar::JST_OFFSET).unwrap();
+    let jst = now.with_timezone(&jst);
+    format!(
+        "{} {} JST",
+        nihonify::Era::to_jp_nenkou_string(now).unwrap_or_default(),
+        jst.format("%H:%M:%S")
+    )
+}
+
+/// Converts an epoch in the given unit to a datetime. With "auto", epochs
+/// past the year 5138 in seconds are taken to be in milliseconds, and those
+/// past it in milliseconds to be in microseconds.
+fn epoch_to_datetime(epoch: i64, unit: &str) -> chrono::DateTime<chrono::Utc> {
+    let unit = match unit {
+        "auto" if epoch.abs() < 100_000_000_000 => "s",
+        "auto" if epoch.abs() < 100_000_000_000_000 => "ms",
+        "auto" => "us",
+        unit => unit,
+    };
+    let datetime = match unit {
+        "s" => chrono::DateTime::from_timestamp(epoch, 0),
+        "ms" => chrono::DateTime::from_timestamp_millis(epoch),
+        _ => chrono::DateTime::from_timestamp_micros(epoch),
+    };
+
+    datetime.unwrap_or_else(|| {
+        eprintln!("Epoch {} is out of range", epoch);
+        std::process::exit(1);
+    })
+}
+
+/// Parses an inclusive year range, written 1980..2030 or 1980..=2030.
+fn parse_years(s: &str) -> Option<(i32, i32)> {
+    let (first, last) = s.split_once("..")?;
+    let last = last.strip_prefix('=').unwrap_or(last);
+    Some((first.parse().ok()?, last.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_line() {
+        let now = chrono::DateTime::from_timestamp(1_636_700_645, 0).unwrap();
+        assert_eq!(clock_line(now), "令和３年１１月１２日 16:04:05 JST");
+        // At 15:00 UTC on April 30 it's already Reiwa in Japan.
+        let now = chrono::DateTime::from_timestamp(1_556_636_400, 0).unwrap();
+        assert_eq!(clock_line(now), "令和元年５月１日 00:00:00 JST");
+    }
+}
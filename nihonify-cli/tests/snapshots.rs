@@ -0,0 +1,177 @@
+//! Runs the `nihonify` binary over a set of cases and compares its exit code,
+//! stdout and stderr with the snapshots in tests/snapshots, so that output
+//! scripts depend on can't change unnoticed. Run with UPDATE_SNAPSHOTS=1 to
+//! rewrite the snapshots after an intended change.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// A snapshot's name, the arguments to run the binary with, and its stdin.
+const CASES: &[(&str, &[&str], &str)] = &[
+    ("convert_date", &["convert-date", "--date", "2021-11-12"], ""),
+    ("convert_date_invalid", &["convert-date", "--date", "2021-02-30"], ""),
+    (
+        "convert_date_before_taika",
+        &["convert-date", "--date", "0600-01-01"],
+        "",
+    ),
+    (
+        "convert_date_epoch_seconds",
+        &["convert-date", "--epoch", "1636675200"],
+        "",
+    ),
+    (
+        "convert_date_epoch_millis",
+        &["convert-date", "--epoch", "1636675200123"],
+        "",
+    ),
+    (
+        "convert_date_epoch_unit",
+        &["convert-date", "--epoch", "1636675200", "--epoch-unit", "ms"],
+        "",
+    ),
+    (
+        "convert_date_input_format",
+        &["convert-date", "--date", "03/04/05", "--input-format", "%y/%m/%d"],
+        "",
+    ),
+    (
+        "convert_date_input_format_day_first",
+        &["convert-date", "--date", "03/04/05", "--input-format", "%d/%m/%y"],
+        "",
+    ),
+    (
+        "convert_date_input_format_mismatch",
+        &["convert-date", "--date", "2021-11-12", "--input-format", "%d/%m/%Y"],
+        "",
+    ),
+    (
+        "convert_date_invalid_epoch",
+        &["convert-date", "--epoch", "yesterday"],
+        "",
+    ),
+    (
+        "to_gregorian",
+        &["to-gregorian", "--date", "令和3年11月12日"],
+        "",
+    ),
+    (
+        "to_gregorian_gannen",
+        &["to-gregorian", "--date", "令和元年５月１日"],
+        "",
+    ),
+    ("to_gregorian_compact", &["to-gregorian", "--date", "R3.11.12"], ""),
+    (
+        "to_gregorian_out_of_era",
+        &["to-gregorian", "--date", "平成31年5月1日"],
+        "",
+    ),
+    ("to_gregorian_invalid", &["to-gregorian", "--date", "X3.11.12"], ""),
+    ("list_eras_jidai", &["list-eras", "--jidai", "Kingendai"], ""),
+    (
+        "list_eras_after",
+        &["list-eras", "--jidai", "edo", "--after", "1800"],
+        "",
+    ),
+    (
+        "list_eras_json",
+        &["list-eras", "--after", "1989", "--format", "json"],
+        "",
+    ),
+    ("list_eras_kanji_jidai", &["list-eras", "--jidai", "奈良時代"], ""),
+    ("list_eras_unknown_jidai", &["list-eras", "--jidai", "meiji"], ""),
+    (
+        "rewrite",
+        &["rewrite"],
+        "契約日：令和3年11月12日\n満了日：令和元年５月１日\n",
+    ),
+    (
+        "rewrite_format",
+        &["rewrite", "--format", "%Y年%-m月%-d日"],
+        "平成31年4月30日まで",
+    ),
+    (
+        "extract_dates",
+        &["extract-dates"],
+        "契約日：令和3年11月12日、満了日：令和元年５月１日。昭和65年1月1日は無効。",
+    ),
+    ("detect", &["detect"], "日本語の文です。Hello!"),
+    ("detect_other", &["detect"], "Hello, world!"),
+    (
+        "rewrite_log_timestamps",
+        &["rewrite", "--log-timestamps", "replace", "--year", "2021"],
+        "127.0.0.1 - - [12/Nov/2021:15:04:05 +0900] \"GET / HTTP/1.1\" 200 612\n\
+        2019-04-30T23:30:00.5-05:00 level=info\n\
+        Nov  2 09:00:01 host sshd[42]: Accepted publickey\n",
+    ),
+    (
+        "rewrite_log_timestamps_annotate",
+        &["rewrite", "--log-timestamps", "annotate", "--year", "2021"],
+        "127.0.0.1 - - [12/Nov/2021:15:04:05 +0900] \"GET / HTTP/1.1\" 200 612\n",
+    ),
+    ("rewrite_missing_file", &["rewrite", "does-not-exist.txt"], ""),
+    ("table_text", &["table", "1988..1990"], ""),
+    (
+        "table_markdown",
+        &["table", "2018..=2020", "--format", "markdown"],
+        "",
+    ),
+    ("table_csv", &["table", "1925..1927", "--format", "csv"], ""),
+    ("table_invalid_range", &["table", "1980-2030"], ""),
+];
+
+fn render(args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nihonify"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    format!(
+        "args: {}\nstatus: {}\n--- stdout\n{}--- stderr\n{}",
+        args.join(" "),
+        output.status.code().unwrap_or(-1),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
+
+#[test]
+fn test_snapshots() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    let mut mismatches = Vec::new();
+    for (name, args, stdin) in CASES {
+        let path = dir.join(format!("{}.snap", name));
+        let actual = render(args, stdin);
+        if update {
+            std::fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&path).unwrap_or_default();
+        if actual != expected {
+            mismatches.push(format!(
+                "{}:\n--- expected\n{}\n--- actual\n{}",
+                name, expected, actual
+            ));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "snapshots differ (rerun with UPDATE_SNAPSHOTS=1 to accept):\n{}",
+        mismatches.join("\n")
+    );
+}
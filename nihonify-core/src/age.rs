@@ -0,0 +1,114 @@
+//! Ages, by the modern count (満年齢) and the traditional one (数え年), in
+//! which everyone is one at birth and a year older each New Year.
+//!
+//! ```
+//! # use chrono::NaiveDate;
+//! # use nihonify_core::{age::{age, kazoedoshi}, WarekiDate};
+//! let birth: NaiveDate = "昭和60年4月1日".parse::<WarekiDate>().unwrap().into();
+//! let as_of = NaiveDate::from_ymd_opt(2021, 11, 12).unwrap();
+//! assert_eq!(age(birth, as_of), Some(36));
+//! assert_eq!(kazoedoshi(birth, as_of), Some(37));
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::numbers::to_kanji_numeral;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use chrono::prelude::*;
+
+/// How an age is counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeCounting {
+    /// 満年齢, in full years since birth.
+    Full,
+    /// 数え年, counting the calendar years lived in.
+    Kazoe,
+}
+
+/// The age in full years on the date, or `None` if it's before the birth.
+/// Someone born on February 29th turns a year older on March 1st in common
+/// years, as the Age Calculation Act has it.
+pub fn age(birth: NaiveDate, as_of: NaiveDate) -> Option<u32> {
+    as_of.years_since(birth)
+}
+
+/// The age in 数え年 on the date, or `None` if it's before the birth.
+pub fn kazoedoshi(birth: NaiveDate, as_of: NaiveDate) -> Option<u32> {
+    if as_of < birth {
+        return None;
+    }
+    Some((as_of.year() - birth.year()) as u32 + 1)
+}
+
+/// The age on the date by the counting.
+pub fn age_with(birth: NaiveDate, as_of: NaiveDate, counting: AgeCounting) -> Option<u32> {
+    match counting {
+        AgeCounting::Full => age(birth, as_of),
+        AgeCounting::Kazoe => kazoedoshi(birth, as_of),
+    }
+}
+
+/// The age on the date in Japanese, with kanji numerals, e.g. 満三十歳 or
+/// 数え三十一歳.
+#[cfg(feature = "alloc")]
+pub fn to_jp_age_string(
+    birth: NaiveDate,
+    as_of: NaiveDate,
+    counting: AgeCounting,
+) -> Option<String> {
+    let years = to_kanji_numeral(age_with(birth, as_of, counting)?.into());
+    Some(match counting {
+        AgeCounting::Full => format!("満{}歳", years),
+        AgeCounting::Kazoe => format!("数え{}歳", years),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_age() {
+        let birth = ymd(1991, 6, 15);
+        assert_eq!(age(birth, ymd(2021, 6, 14)), Some(29));
+        assert_eq!(age(birth, ymd(2021, 6, 15)), Some(30));
+        assert_eq!(age(birth, birth), Some(0));
+        assert_eq!(age(birth, ymd(1991, 6, 14)), None);
+
+        let leap_day = ymd(2000, 2, 29);
+        assert_eq!(age(leap_day, ymd(2021, 2, 28)), Some(20));
+        assert_eq!(age(leap_day, ymd(2021, 3, 1)), Some(21));
+        assert_eq!(age(leap_day, ymd(2024, 2, 29)), Some(24));
+    }
+
+    #[test]
+    fn test_kazoedoshi() {
+        let birth = ymd(2020, 12, 31);
+        assert_eq!(kazoedoshi(birth, birth), Some(1));
+        assert_eq!(kazoedoshi(birth, ymd(2021, 1, 1)), Some(2));
+        assert_eq!(kazoedoshi(birth, ymd(2020, 12, 30)), None);
+        assert_eq!(
+            age_with(ymd(1991, 6, 15), ymd(2021, 6, 14), AgeCounting::Kazoe),
+            Some(31)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_jp_age_string() {
+        let (birth, as_of) = (ymd(1991, 6, 15), ymd(2021, 6, 15));
+        assert_eq!(
+            to_jp_age_string(birth, as_of, AgeCounting::Full).unwrap(),
+            "満三十歳"
+        );
+        assert_eq!(
+            to_jp_age_string(birth, as_of, AgeCounting::Kazoe).unwrap(),
+            "数え三十一歳"
+        );
+        assert_eq!(to_jp_age_string(as_of, birth, AgeCounting::Full), None);
+    }
+}
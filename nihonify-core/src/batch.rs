@@ -0,0 +1,105 @@
+//! Conversions over whole columns of timestamps, e.g. from CSV or Parquet.
+//! With the `parallel` feature, the `par_` versions split the work across
+//! the available cores with `rayon`.
+//!
+//! ```
+//! # use nihonify_core::{Era, NenkouFormatter};
+//! let epochs = [1636675200, 0, i64::MIN];
+//! let eras = Era::from_unix_epochs(&epochs);
+//! assert_eq!(eras[0].and_then(|era| era.romaji), Some("reiwa"));
+//! assert_eq!(eras[2], None);
+//!
+//! let nenkou = NenkouFormatter::new().format_unix_epochs(&epochs);
+//! assert_eq!(nenkou[1].as_deref(), Some("昭和４５年１月１日"));
+//! ```
+
+use crate::calendar::Era;
+use crate::format::NenkouFormatter;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl Era {
+    /// The era of each epoch, as `from_unix_epoch`.
+    pub fn from_unix_epochs(unix_epochs: &[i64]) -> Vec<Option<&'static Era>> {
+        unix_epochs
+            .iter()
+            .map(|&unix_epoch| Era::from_unix_epoch(unix_epoch))
+            .collect()
+    }
+
+    /// Like `from_unix_epochs`, across the available cores.
+    #[cfg(feature = "parallel")]
+    pub fn par_from_unix_epochs(unix_epochs: &[i64]) -> Vec<Option<&'static Era>> {
+        unix_epochs
+            .par_iter()
+            .map(|&unix_epoch| Era::from_unix_epoch(unix_epoch))
+            .collect()
+    }
+}
+
+impl NenkouFormatter {
+    /// Formats each epoch, as `format`.
+    pub fn format_unix_epochs(&self, unix_epochs: &[i64]) -> Vec<Option<String>> {
+        unix_epochs
+            .iter()
+            .map(|&unix_epoch| self.format_unix_epoch(unix_epoch))
+            .collect()
+    }
+
+    /// Like `format_unix_epochs`, across the available cores.
+    #[cfg(feature = "parallel")]
+    pub fn par_format_unix_epochs(&self, unix_epochs: &[i64]) -> Vec<Option<String>> {
+        unix_epochs
+            .par_iter()
+            .map(|&unix_epoch| self.format_unix_epoch(unix_epoch))
+            .collect()
+    }
+
+    fn format_unix_epoch(&self, unix_epoch: i64) -> Option<String> {
+        self.format(DateTime::from_timestamp(unix_epoch, 0)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_unix_epochs() {
+        let epochs = [1556636399, 1556636400, i64::MIN];
+        let romaji: Vec<_> = Era::from_unix_epochs(&epochs)
+            .into_iter()
+            .map(|era| era.and_then(|era| era.romaji))
+            .collect();
+        assert_eq!(romaji, [Some("heisei"), Some("reiwa"), None]);
+        assert!(Era::from_unix_epochs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_format_unix_epochs() {
+        let formatter = NenkouFormatter::new();
+        assert_eq!(
+            formatter.format_unix_epochs(&[1636675200, i64::MAX]),
+            [Some(String::from("令和３年１１月１２日")), None]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel() {
+        let epochs: Vec<i64> = (0..10_000).map(|i| i * 86_400 * 7).collect();
+        assert_eq!(
+            Era::par_from_unix_epochs(&epochs),
+            Era::from_unix_epochs(&epochs)
+        );
+        let formatter = NenkouFormatter::new();
+        assert_eq!(
+            formatter.par_format_unix_epochs(&epochs),
+            formatter.format_unix_epochs(&epochs)
+        );
+        assert!(Era::par_from_unix_epochs(&[]).is_empty());
+    }
+}
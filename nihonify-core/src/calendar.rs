@@ -0,0 +1,1354 @@
+use crate::eras::{SORTED_ERAS, SOUTHERN_COURT_ERAS};
+#[cfg(feature = "chrono")]
+use crate::error::NihonifyError;
+#[cfg(feature = "private-eras")]
+use crate::private_eras::PRIVATE_ERAS;
+#[cfg(feature = "chrono")]
+use chrono::prelude::*;
+#[cfg(feature = "chrono")]
+use chrono::Duration;
+#[cfg(feature = "chrono")]
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jidai {
+    Asuka,
+    Nara,
+    Heian,
+    Kamakura,
+    Nanbokuchou,
+    Sengoku,
+    Muromachi,
+    AzuchiMomoyama,
+    Edo,
+    Modern,
+}
+
+impl Jidai {
+    /// The period's name in kanji, e.g. 江戸時代.
+    pub fn kanji(&self) -> &'static str {
+        match self {
+            Jidai::Asuka => "飛鳥時代",
+            Jidai::Nara => "奈良時代",
+            Jidai::Heian => "平安時代",
+            Jidai::Kamakura => "鎌倉時代",
+            Jidai::Nanbokuchou => "南北朝時代",
+            Jidai::Sengoku => "戦国時代",
+            Jidai::Muromachi => "室町時代",
+            Jidai::AzuchiMomoyama => "安土桃山時代",
+            Jidai::Edo => "江戸時代",
+            Jidai::Modern => "近現代",
+        }
+    }
+
+    /// The period's name in romaji, spelled like the era table's names, e.g.
+    /// edo or azuchi-momoyama.
+    pub fn romaji(&self) -> &'static str {
+        match self {
+            Jidai::Asuka => "asuka",
+            Jidai::Nara => "nara",
+            Jidai::Heian => "heian",
+            Jidai::Kamakura => "kamakura",
+            Jidai::Nanbokuchou => "nanbokuchou",
+            Jidai::Sengoku => "sengoku",
+            Jidai::Muromachi => "muromachi",
+            Jidai::AzuchiMomoyama => "azuchi-momoyama",
+            Jidai::Edo => "edo",
+            Jidai::Modern => "kingendai",
+        }
+    }
+
+    /// Every period, in the order the era table has them.
+    #[cfg(feature = "alloc")]
+    pub(crate) const ALL: [Jidai; 10] = [
+        Jidai::Asuka,
+        Jidai::Nara,
+        Jidai::Heian,
+        Jidai::Kamakura,
+        Jidai::Nanbokuchou,
+        Jidai::Muromachi,
+        Jidai::Sengoku,
+        Jidai::AzuchiMomoyama,
+        Jidai::Edo,
+        Jidai::Modern,
+    ];
+
+    /// The period the datetime falls in, by the era in use then. Periods are
+    /// only known from Taika on.
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Option<Jidai> {
+        Era::from_datetime(datetime).map(|era| era.jidai)
+    }
+
+    /// The eras of this period, in order.
+    pub fn eras(&self) -> impl DoubleEndedIterator<Item = &'static Era> {
+        let jidai = *self;
+        SORTED_ERAS.iter().filter(move |era| era.jidai == jidai)
+    }
+
+    /// The moment this period's first era started.
+    #[cfg(feature = "chrono")]
+    pub fn start(&self) -> DateTime<Utc> {
+        self.eras().next().unwrap().start_datetime()
+    }
+
+    /// The moment this period's last era ended, or `None` for the modern
+    /// period.
+    #[cfg(feature = "chrono")]
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.eras().next_back().unwrap().end_datetime()
+    }
+
+    /// The days this period spans, from the start of its first era to the end
+    /// of its last. The modern period runs to the end of time.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn date_range(&self) -> DateRange {
+        let mut eras = self.eras();
+        let first = eras.next().unwrap().date_range().first;
+        let last = eras.next_back().unwrap().date_range().last;
+        DateRange { first, last }
+    }
+}
+
+/// 9999-12-31T23:59:59Z, the last moment `try_from_unix_epoch` accepts.
+#[cfg(feature = "chrono")]
+const MAX_SUPPORTED_EPOCH: i64 = 253402300799;
+
+/// The last year a wareki date can fall in.
+#[cfg(feature = "chrono")]
+const MAX_SUPPORTED_YEAR: i32 = 9999;
+
+/// The year Taika began, the first the era table covers.
+const FIRST_ERA_YEAR: i32 = civil_from_unix_epoch(SORTED_ERAS[0].started_at + JST_OFFSET as i64).0;
+
+/// The number of years from Taika's to the current era's, inclusive.
+const ERA_YEARS: usize =
+    (civil_from_unix_epoch(SORTED_ERAS[SORTED_ERAS.len() - 1].started_at + JST_OFFSET as i64).0
+        - FIRST_ERA_YEAR
+        + 1) as usize;
+
+/// For each year since Taika's, the index of the era in effect on January
+/// 1st in Japan (or Taika's, for its own year), so that `Era::from_unix_epoch`
+/// doesn't need to scan the era table.
+static ERAS_BY_YEAR: [u8; ERA_YEARS] = eras_by_year();
+
+const fn eras_by_year() -> [u8; ERA_YEARS] {
+    assert!(SORTED_ERAS.len() <= u8::MAX as usize + 1);
+    let mut table = [0; ERA_YEARS];
+    let (mut year, mut index) = (0, 0);
+    while year < ERA_YEARS {
+        while index + 1 < SORTED_ERAS.len() {
+            let next = civil_from_unix_epoch(SORTED_ERAS[index + 1].started_at + JST_OFFSET as i64);
+            if next.0 >= FIRST_ERA_YEAR + year as i32 {
+                break;
+            }
+            index += 1;
+        }
+        table[year] = index as u8;
+        year += 1;
+    }
+
+    table
+}
+
+/// Japan Standard Time's offset from UTC, in seconds.
+pub const JST_OFFSET: i32 = 9 * 3600;
+
+/// The date it is in Japan at the given moment. Eras change over at midnight
+/// JST, so this is the date a moment's era and era year go by.
+#[cfg(feature = "chrono")]
+pub(crate) fn jst_date(datetime: DateTime<Utc>) -> NaiveDate {
+    datetime
+        .with_timezone(&FixedOffset::east_opt(JST_OFFSET).unwrap())
+        .date_naive()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Era {
+    pub kanji: Option<&'static str>,
+    pub romaji: Option<&'static str>,
+    /// The name's reading in hiragana, e.g. れいわ, as for furigana.
+    pub reading: Option<&'static str>,
+    /// What the name means in English, e.g. "beautiful harmony". Only the
+    /// modern eras, Meiji through Reiwa, have one.
+    pub gloss: Option<&'static str>,
+    pub jidai: Jidai,
+    /// The unix epoch of midnight JST on the era's first day, as eras change
+    /// over on the civil date in Japan.
+    pub started_at: i64,
+    /// The unix epoch of midnight JST after the era's last day, or `None` for
+    /// the current era.
+    pub ended_at: Option<i64>,
+}
+
+/// The two imperial courts that proclaimed their own eras during the
+/// Nanboku-chō period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Court {
+    /// The Northern Court (北朝) in Kyoto, whose eras the era table lists.
+    #[default]
+    Northern,
+    /// The Southern Court (南朝) in Yoshino, which the official succession
+    /// follows.
+    Southern,
+}
+
+/// A source of the current time. APIs that depend on "now" take a `Clock` so
+/// that callers can pin the time, e.g. in tests or when replaying records.
+#[cfg(feature = "chrono")]
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system's wall clock.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always reports the same instant.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+#[cfg(feature = "chrono")]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl Era {
+    /// Returns the era for the present moment, or `None` if the system clock
+    /// is set before Taika.
+    #[cfg(feature = "std")]
+    pub fn current() -> Option<&'static Era> {
+        Era::current_with(&SystemClock)
+    }
+
+    /// Returns the era for the present moment, as reported by `clock`, or
+    /// `None` if that's outside the era table, as `from_datetime`.
+    #[cfg(feature = "chrono")]
+    pub fn current_with<C: Clock>(clock: &C) -> Option<&'static Era> {
+        Era::from_datetime(clock.now())
+    }
+
+    /// The earliest date the era table covers: the first day of Taika.
+    #[cfg(feature = "chrono")]
+    pub fn earliest_supported_date() -> NaiveDate {
+        SORTED_ERAS[0].start_date_jst()
+    }
+
+    /// Like `from_datetime`, but explains why there's no era.
+    #[cfg(feature = "chrono")]
+    pub fn try_from_datetime(datetime: DateTime<Utc>) -> Result<&'static Era, NihonifyError> {
+        Era::try_from_unix_epoch(datetime.timestamp())
+    }
+
+    /// Like `from_unix_epoch`, but explains why there's no era: the epoch is
+    /// before Taika, in the gap between Shōkyō and Kenmu, or absurdly far in
+    /// the future (past the year 9999).
+    #[cfg(feature = "chrono")]
+    pub fn try_from_unix_epoch(unix_epoch: i64) -> Result<&'static Era, NihonifyError> {
+        if unix_epoch > MAX_SUPPORTED_EPOCH {
+            return Err(NihonifyError::DateOutOfRange);
+        } else if unix_epoch < SORTED_ERAS[0].started_at {
+            return Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date(),
+            });
+        }
+
+        Era::from_unix_epoch(unix_epoch).ok_or(NihonifyError::UnnamedEra)
+    }
+
+    /// The last era to start by the epoch, even if it has since ended.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn last_started_by(unix_epoch: i64) -> Option<&'static Era> {
+        let started = SORTED_ERAS.partition_point(|era| era.started_at <= unix_epoch);
+        started.checked_sub(1).map(|index| &SORTED_ERAS[index])
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Option<&'static Era> {
+        Era::from_unix_epoch(datetime.timestamp())
+    }
+
+    /// Like `from_unix_epoch`, for an epoch in milliseconds, as most logging
+    /// systems emit them.
+    pub fn from_unix_epoch_millis(unix_epoch_millis: i64) -> Option<&'static Era> {
+        Era::from_unix_epoch(unix_epoch_millis.div_euclid(1_000))
+    }
+
+    /// Like `from_unix_epoch`, for an epoch in microseconds.
+    pub fn from_unix_epoch_micros(unix_epoch_micros: i64) -> Option<&'static Era> {
+        Era::from_unix_epoch(unix_epoch_micros.div_euclid(1_000_000))
+    }
+
+    pub fn from_unix_epoch(unix_epoch: i64) -> Option<&'static Era> {
+        let last = SORTED_ERAS.len() - 1;
+        if unix_epoch < SORTED_ERAS[0].started_at {
+            return None;
+        } else if unix_epoch >= SORTED_ERAS[last].started_at {
+            return Some(&SORTED_ERAS[last]);
+        }
+
+        // Start from the era in effect at the beginning of the year in Japan,
+        // and step past any that ended during it; at most a few eras begin in
+        // any one year.
+        let (year, _, _) = civil_from_unix_epoch(unix_epoch + JST_OFFSET as i64);
+        let mut index = ERAS_BY_YEAR[(year - FIRST_ERA_YEAR) as usize] as usize;
+        while SORTED_ERAS[index + 1].started_at <= unix_epoch {
+            index += 1;
+        }
+
+        // The era table has a gap between Shōkyō and Kenmu.
+        let era = &SORTED_ERAS[index];
+        era.ended_at
+            .is_none_or(|ended_at| unix_epoch < ended_at)
+            .then_some(era)
+    }
+
+    /// The era of the civil date in Japan, given as a year, month and day, for
+    /// callers without chrono types at hand.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<&'static Era> {
+        let unix_epoch = unix_epoch_from_civil(year, month, day)?;
+        Era::from_unix_epoch(unix_epoch - JST_OFFSET as i64)
+    }
+
+    /// Like `from_datetime`, but for the Nanboku-chō period returns the era
+    /// the given court used. Both courts used the same eras before and after.
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime_with_court(datetime: DateTime<Utc>, court: Court) -> Option<&'static Era> {
+        Era::from_unix_epoch_with_court(datetime.timestamp(), court)
+    }
+
+    /// Like `from_unix_epoch`, but for the Nanboku-chō period returns the era
+    /// the given court used.
+    pub fn from_unix_epoch_with_court(unix_epoch: i64, court: Court) -> Option<&'static Era> {
+        let southern = match court {
+            Court::Northern => None,
+            Court::Southern => SOUTHERN_COURT_ERAS.iter().find(|era| {
+                era.started_at <= unix_epoch && era.ended_at.is_none_or(|end| unix_epoch < end)
+            }),
+        };
+        southern.or_else(|| Era::from_unix_epoch(unix_epoch))
+    }
+
+    /// The era with the given kanji name, e.g. 令和, including the Southern
+    /// Court's eras, and the private eras if the `private-eras` feature is
+    /// enabled.
+    pub fn from_kanji(kanji: &str) -> Option<&'static Era> {
+        named_eras().find(|era| era.kanji == Some(kanji))
+    }
+
+    /// The number of eras in the era table, including the two unnamed
+    /// interregna after Hakuchi and Shuchō.
+    pub fn count() -> usize {
+        SORTED_ERAS.len()
+    }
+
+    /// This era's position in the era table, in chronological order from 0
+    /// for Taika. Only one court's eras are listed for the Nanboku-chō period,
+    /// so later eras come before their traditional count, e.g. Reiwa is 240
+    /// rather than the 248th era. `None` for the Southern Court's and the
+    /// private eras, which aren't in the table.
+    pub fn index(&self) -> Option<usize> {
+        SORTED_ERAS.iter().position(|era| core::ptr::eq(era, self))
+    }
+
+    /// The era at the given position in the era table, if any.
+    pub fn from_index(index: usize) -> Option<&'static Era> {
+        SORTED_ERAS.get(index)
+    }
+
+    /// The number of eras in use at some point between the two datetimes, in
+    /// either order, e.g. 3 for 1988 to 2021 (Shōwa, Heisei and Reiwa).
+    #[cfg(feature = "chrono")]
+    pub fn count_spanned(a: DateTime<Utc>, b: DateTime<Utc>) -> usize {
+        Era::between(a, b).count()
+    }
+
+    /// The eras in use at some point between the two datetimes, in either
+    /// order, from the earliest.
+    #[cfg(feature = "chrono")]
+    pub fn between(
+        a: DateTime<Utc>,
+        b: DateTime<Utc>,
+    ) -> impl DoubleEndedIterator<Item = &'static Era> {
+        let (from, to) = (a.min(b).timestamp(), a.max(b).timestamp());
+        SORTED_ERAS
+            .iter()
+            .filter(move |era| era.started_at <= to && era.ended_at.is_none_or(|end| from < end))
+    }
+
+    /// Every era in the era table, in order, including the two unnamed
+    /// interregna.
+    pub fn all() -> impl DoubleEndedIterator<Item = &'static Era> + ExactSizeIterator {
+        SORTED_ERAS.iter()
+    }
+
+    /// The eras of the period, in order. The same as `Jidai::eras`.
+    pub fn in_jidai(jidai: Jidai) -> impl DoubleEndedIterator<Item = &'static Era> {
+        jidai.eras()
+    }
+
+    /// The moment this era started.
+    #[cfg(feature = "chrono")]
+    pub fn start_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_opt(self.started_at, 0).unwrap()
+    }
+
+    /// The moment this era ended, or `None` for the current era.
+    #[cfg(feature = "chrono")]
+    pub fn end_datetime(&self) -> Option<DateTime<Utc>> {
+        self.ended_at
+            .map(|ended_at| Utc.timestamp_opt(ended_at, 0).unwrap())
+    }
+
+    /// The date this era started on in Japan.
+    #[cfg(feature = "chrono")]
+    pub fn start_date_jst(&self) -> NaiveDate {
+        jst_date(self.start_datetime())
+    }
+
+    /// The last date of this era in Japan, or `None` for the current era.
+    #[cfg(feature = "chrono")]
+    pub fn end_date_jst(&self) -> Option<NaiveDate> {
+        self.end_datetime()
+            .map(|ended_at| jst_date(ended_at - Duration::seconds(1)))
+    }
+
+    /// The days this era spans in Japan. The current era runs to the end of
+    /// time.
+    #[cfg(feature = "chrono")]
+    pub(crate) fn date_range(&self) -> DateRange {
+        DateRange {
+            first: self.start_date_jst(),
+            last: self.end_date_jst().unwrap_or(NaiveDate::MAX),
+        }
+    }
+
+    /// The Latin initial conventionally used for the modern eras, e.g. R for
+    /// Reiwa. Only Meiji through Reiwa have one.
+    pub fn initial(&self) -> Option<char> {
+        match self.romaji? {
+            "meiji" => Some('M'),
+            "taishou" => Some('T'),
+            "shouwa" => Some('S'),
+            "heisei" => Some('H'),
+            "reiwa" => Some('R'),
+            _ => None,
+        }
+    }
+
+    /// The first kanji of the era's name, by which the modern eras are
+    /// abbreviated, e.g. 令 for 令和. Only eras with an initial have one.
+    pub fn abbreviation(&self) -> Option<char> {
+        self.initial()?;
+        self.kanji?.chars().next()
+    }
+
+    /// The era's number in JIS X 0301, 1 for Meiji through 5 for Reiwa. Only
+    /// the modern eras have one.
+    pub fn jis_code(&self) -> Option<u8> {
+        Jidai::Modern
+            .eras()
+            .position(|era| core::ptr::eq(era, self))
+            .map(|i| i as u8 + 1)
+    }
+
+    /// The name the era is serialized by: its romaji name, or its kanji name if
+    /// a later era has the same romaji name, e.g. 承和 rather than shouwa.
+    #[cfg(any(feature = "serde", feature = "schemars"))]
+    pub(crate) fn identifier(&self) -> Option<&'static str> {
+        let romaji = self.romaji?;
+        let reused =
+            named_eras().any(|era| era.started_at > self.started_at && era.romaji == Some(romaji));
+        if reused {
+            self.kanji
+        } else {
+            Some(romaji)
+        }
+    }
+
+    /// The year of this era that the given datetime falls in in Japan,
+    /// counting from 1. Era years roll over on January 1st, so the year an era
+    /// starts in is its first year however late in the year it started.
+    #[cfg(feature = "chrono")]
+    pub(crate) fn year_of(&self, date: DateTime<Utc>) -> u32 {
+        (1 + jst_date(date).year() - self.start_date_jst().year())
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// Writes the era's kanji name, falling back to its romaji name, and to 無年号
+/// for the unnamed interregna.
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.kanji.or(self.romaji).unwrap_or("無年号"))
+    }
+}
+
+/// The official eras in order, followed by the Southern Court's eras and the
+/// private eras if the `private-eras` feature is enabled.
+pub(crate) fn named_eras() -> impl DoubleEndedIterator<Item = &'static Era> {
+    let eras = SORTED_ERAS.iter().chain(SOUTHERN_COURT_ERAS);
+    #[cfg(feature = "private-eras")]
+    let eras = eras.chain(PRIVATE_ERAS);
+
+    eras
+}
+
+// Era lookups straight from the common time types. Naive datetimes are taken
+// to be in UTC, like everywhere else in the crate.
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime<Utc>> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: DateTime<Utc>) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(datetime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime<FixedOffset>> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(datetime.with_timezone(&Utc))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<DateTime<Local>> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: DateTime<Local>) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(datetime.with_timezone(&Utc))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<NaiveDateTime> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(datetime.and_utc())
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(DateTime::<Utc>::from(time))
+    }
+}
+
+/// The Gregorian date of the given day of the era, which must fall in it.
+#[cfg(feature = "chrono")]
+pub(crate) fn era_date(
+    era: &Era,
+    year: u32,
+    month: u32,
+    day: u32,
+) -> Result<NaiveDate, NihonifyError> {
+    let range = era.date_range();
+    let year = i32::try_from(year)
+        .ok()
+        .and_then(|year| range.first.year().checked_add(year - 1))
+        .filter(|&year| year <= MAX_SUPPORTED_YEAR)
+        .ok_or(NihonifyError::DateOutOfRange)?;
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or(NihonifyError::ParseError)?;
+    if !range.contains(date) {
+        return Err(NihonifyError::DateOutOfRange);
+    }
+
+    Ok(date)
+}
+
+/// A day given in wareki: an era, the year of the era counting from 1, and
+/// the month and day. Always a day that falls within its era.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarekiDate {
+    era: &'static Era,
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "chrono")]
+impl WarekiDate {
+    /// The day, if it exists and falls within the era, e.g. not 平成31年5月1日.
+    pub fn new(
+        era: &'static Era,
+        year: u16,
+        month: u8,
+        day: u8,
+    ) -> Result<WarekiDate, NihonifyError> {
+        era_date(era, year.into(), month.into(), day.into())?;
+        Ok(WarekiDate {
+            era,
+            year,
+            month,
+            day,
+        })
+    }
+
+    /// The wareki date of the Gregorian date given as a year, month and day.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<WarekiDate, NihonifyError> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or(NihonifyError::DateOutOfRange)?
+            .try_into()
+    }
+
+    /// The wareki date of the epoch's date in Japan.
+    pub fn from_unix_epoch(unix_epoch: i64) -> Result<WarekiDate, NihonifyError> {
+        DateTime::from_timestamp(unix_epoch, 0)
+            .ok_or(NihonifyError::DateOutOfRange)?
+            .try_into()
+    }
+
+    pub fn era(&self) -> &'static Era {
+        self.era
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The Gregorian date.
+    pub fn to_naive_date(&self) -> NaiveDate {
+        era_date(
+            self.era,
+            self.year.into(),
+            self.month.into(),
+            self.day.into(),
+        )
+        .unwrap()
+    }
+
+    /// The Gregorian date as a year, month and day.
+    pub fn to_ymd(&self) -> (i32, u32, u32) {
+        let date = self.to_naive_date();
+        (date.year(), date.month(), date.day())
+    }
+
+    /// The unix epoch of the start of the day in UTC.
+    pub fn to_unix_epoch(&self) -> i64 {
+        self.to_datetime_utc().timestamp()
+    }
+
+    /// The start of the day in UTC.
+    pub fn to_datetime_utc(&self) -> DateTime<Utc> {
+        self.to_naive_date().and_hms_opt(0, 0, 0).unwrap().and_utc()
+    }
+
+    /// The start of the day in JST, which is 9 hours before its start in UTC.
+    pub fn to_datetime_jst(&self) -> DateTime<FixedOffset> {
+        let jst = FixedOffset::east_opt(JST_OFFSET).unwrap();
+        self.to_naive_date()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(jst)
+            .unwrap()
+    }
+}
+
+/// Writes the date as it parses, e.g. 令和3年11月12日 or 令和元年5月1日,
+/// without allocating.
+#[cfg(feature = "chrono")]
+impl fmt::Display for WarekiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.era)?;
+        match self.year {
+            1 => f.write_str("元")?,
+            year => write!(f, "{}", year)?,
+        }
+        write!(f, "年{}月{}日", self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PartialOrd for WarekiDate {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Wareki dates are ordered by their Gregorian dates.
+#[cfg(feature = "chrono")]
+impl Ord for WarekiDate {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_naive_date().cmp(&other.to_naive_date())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<NaiveDate> for WarekiDate {
+    type Error = NihonifyError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        let datetime = date.and_time(NaiveTime::MIN).and_utc();
+        let era = Era::try_from_datetime(datetime)?;
+        Ok(WarekiDate {
+            era,
+            year: era.year_of(datetime).try_into().unwrap(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        })
+    }
+}
+
+/// The wareki date of the datetime's date in Japan.
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime<Utc>> for WarekiDate {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: DateTime<Utc>) -> Result<Self, Self::Error> {
+        WarekiDate::try_from(jst_date(datetime))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<WarekiDate> for NaiveDate {
+    fn from(date: WarekiDate) -> Self {
+        date.to_naive_date()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<WarekiDate> for DateTime<Utc> {
+    fn from(date: WarekiDate) -> Self {
+        date.to_datetime_utc()
+    }
+}
+
+/// An inclusive span of days, for inputs that name a period rather than a
+/// day, e.g. 令和3年11月上旬.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    pub first: NaiveDate,
+    pub last: NaiveDate,
+}
+
+#[cfg(feature = "chrono")]
+impl DateRange {
+    /// The range from `first` to `last`, or `None` if `last` is before
+    /// `first`.
+    pub fn new(first: NaiveDate, last: NaiveDate) -> Option<DateRange> {
+        if last < first {
+            return None;
+        }
+        Some(DateRange { first, last })
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.first <= date && date <= self.last
+    }
+
+    /// The number of days in the range.
+    pub fn days(&self) -> i64 {
+        (self.last - self.first).num_days() + 1
+    }
+
+    /// The days the two ranges have in common, if any.
+    pub fn intersection(&self, other: &DateRange) -> Option<DateRange> {
+        DateRange::new(self.first.max(other.first), self.last.min(other.last))
+    }
+}
+
+/// The UTC civil date (year, month, day) of a unix epoch, usable in const
+/// contexts. See http://howardhinnant.github.io/date_algorithms.html.
+pub(crate) const fn civil_from_unix_epoch(unix_epoch: i64) -> (i32, u32, u32) {
+    let z = unix_epoch.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year as i32, month, day)
+}
+
+/// The unix epoch of the start of the UTC civil date, if it exists. The
+/// inverse of `civil_from_unix_epoch`.
+pub(crate) const fn unix_epoch_from_civil(year: i32, month: u32, day: u32) -> Option<i64> {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if day < 1 || day > days_in_month {
+        return None;
+    }
+
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    Some((era * 146097 + doe - 719468) * 86400)
+}
+
+/// An era transition close to a date, as found by `boundary_proximity`.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EraBoundary {
+    /// The era that ends at the transition.
+    pub before: &'static Era,
+    /// The era that starts at the transition.
+    pub after: &'static Era,
+    /// How far the transition is from the date: positive if the transition
+    /// comes after the date, negative if it came before.
+    pub distance: Duration,
+}
+
+/// Returns the era transition closest to `date`, if there's one within
+/// `within` of it (in either direction).
+#[cfg(feature = "chrono")]
+pub fn boundary_proximity(date: DateTime<Utc>, within: Duration) -> Option<EraBoundary> {
+    SORTED_ERAS
+        .windows(2)
+        .map(|pair| EraBoundary {
+            before: &pair[0],
+            after: &pair[1],
+            distance: Duration::seconds(pair[1].started_at - date.timestamp()),
+        })
+        .filter(|boundary| boundary.distance.abs() <= within)
+        .min_by_key(|boundary| boundary.distance.abs())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::utc_dt;
+
+    #[test]
+    fn test_from_unix_epoch_various_eras() {
+        // Testing various era happy paths
+        assert_eq!(
+            Era::from_unix_epoch(-1556668810).unwrap().romaji,
+            Some("taishou")
+        );
+        assert_eq!(
+            Era::from_unix_epoch(-23123213123).unwrap().romaji,
+            Some("katei")
+        );
+    }
+
+    #[test]
+    fn test_from_unix_epoch_first_era_boundary_cases() {
+        // 1 second before the earliest era we have should be None.
+        assert!(Era::from_unix_epoch(-41795686801).is_none());
+        // 1 second after the start of the earliest era we have should exist
+        assert!(Era::from_unix_epoch(-41795686799).is_some());
+        // Taika should be the first era.
+        assert_eq!(
+            Era::from_unix_epoch(-41795686799).unwrap().romaji,
+            Some("taika")
+        );
+        // An era starts at exactly its started_at.
+        assert_eq!(
+            Era::from_unix_epoch(-41795686800).unwrap().romaji,
+            Some("taika")
+        );
+        assert_eq!(
+            Era::from_unix_epoch(1556636400).unwrap().romaji,
+            Some("reiwa")
+        );
+    }
+
+    #[test]
+    fn test_from_unix_epoch_last_era_boundary_cases() {
+        // The last era should be reiwa (this should never change)
+        assert_eq!(
+            Era::from_unix_epoch(1636346788).unwrap().romaji,
+            Some("reiwa")
+        );
+
+        // Asking for a far future date, e.g. the year 2211, should also yield
+        // the last era. (this assertion will change if latest era changes)
+        assert_eq!(
+            Era::from_unix_epoch(7636346788).unwrap().romaji,
+            Some("reiwa")
+        );
+    }
+
+    #[test]
+    fn test_boundaries_are_midnight_jst() {
+        let era = |rfc3339| {
+            let datetime = DateTime::parse_from_rfc3339(rfc3339).unwrap();
+            Era::from_datetime(datetime.with_timezone(&Utc))
+                .unwrap()
+                .kanji
+        };
+        // The evening of April 30th, 2019 in UTC was already May 1st in Japan.
+        assert_eq!(era("2019-04-30T14:59:59Z"), Some("平成"));
+        assert_eq!(era("2019-04-30T15:00:00Z"), Some("令和"));
+        assert_eq!(era("2019-04-30T20:00:00Z"), Some("令和"));
+        assert_eq!(era("2019-04-30T23:59:59+09:00"), Some("平成"));
+        assert_eq!(era("1989-01-07T14:59:59Z"), Some("昭和"));
+        assert_eq!(era("1989-01-07T15:00:00Z"), Some("平成"));
+        assert_eq!(era("1989-01-08T00:00:00+09:00"), Some("平成"));
+
+        // The date and era year are those in Japan too.
+        let evening = Utc.with_ymd_and_hms(2019, 4, 30, 20, 0, 0).unwrap();
+        assert_eq!(
+            Era::to_jp_nenkou_string(evening),
+            Some("令和元年５月１日".to_owned())
+        );
+        let evening = Utc.with_ymd_and_hms(1989, 1, 7, 16, 0, 0).unwrap();
+        let date = WarekiDate::try_from(evening).unwrap();
+        assert_eq!(date.era().kanji, Some("平成"));
+        assert_eq!((date.year(), date.month(), date.day()), (1, 1, 8));
+
+        // Civil dates keep their day.
+        let date = WarekiDate::try_from(NaiveDate::from_ymd_opt(2019, 4, 30).unwrap()).unwrap();
+        assert_eq!((date.era().kanji, date.year()), (Some("平成"), 31));
+    }
+
+    #[test]
+    fn test_from_datetime_with_court() {
+        let kanji = |date, court| {
+            Era::from_datetime_with_court(utc_dt(date), court).and_then(|era| era.kanji)
+        };
+        assert_eq!(kanji("1350-01-01", Court::Northern), Some("貞和"));
+        assert_eq!(kanji("1350-01-01", Court::Southern), Some("正平"));
+        assert_eq!(kanji("1331-10-01", Court::Northern), Some("元徳"));
+        assert_eq!(kanji("1331-10-01", Court::Southern), Some("元弘"));
+        assert_eq!(kanji("1392-11-26", Court::Southern), Some("元中"));
+        // Both courts used Kenmu at first, and the same eras around the period.
+        assert_eq!(kanji("1335-01-01", Court::Southern), Some("建武"));
+        assert_eq!(kanji("1392-11-27", Court::Southern), Some("明徳"));
+        assert_eq!(kanji("1200-01-01", Court::Southern), Some("正治"));
+        assert_eq!(
+            Era::from_datetime_with_court(utc_dt("2021-11-12"), Court::default()),
+            Era::from_datetime(utc_dt("2021-11-12"))
+        );
+
+        let shouhei = Era::from_kanji("正平").unwrap();
+        assert_eq!(shouhei.emperor().unwrap().kanji, "後村上");
+        assert_eq!(
+            WarekiDate::new(shouhei, 4, 1, 1).unwrap().to_naive_date(),
+            NaiveDate::from_ymd_opt(1350, 1, 1).unwrap()
+        );
+        // Kenmu, which both courts used, comes between Genkō and Engen.
+        for pair in SOUTHERN_COURT_ERAS[1..].windows(2) {
+            assert_eq!(pair[0].ended_at, Some(pair[1].started_at));
+        }
+    }
+
+    #[test]
+    fn test_count_spanned() {
+        assert_eq!(
+            Era::count_spanned(utc_dt("1988-04-01"), utc_dt("2021-11-12")),
+            3
+        );
+        assert_eq!(
+            Era::count_spanned(utc_dt("2021-11-12"), utc_dt("1988-04-01")),
+            3
+        );
+        assert_eq!(
+            Era::count_spanned(utc_dt("2020-01-01"), utc_dt("2021-11-12")),
+            1
+        );
+        assert_eq!(
+            Era::count_spanned(utc_dt("0600-01-01"), utc_dt("0600-12-31")),
+            0
+        );
+    }
+
+    #[test]
+    fn test_from_kanji() {
+        let heisei = Era::from_kanji("平成").unwrap();
+        assert_eq!(heisei.romaji, Some("heisei"));
+        assert_eq!(
+            heisei.start_date_jst(),
+            NaiveDate::from_ymd_opt(1989, 1, 8).unwrap()
+        );
+        assert_eq!(heisei.end_date_jst(), NaiveDate::from_ymd_opt(2019, 4, 30));
+        assert_eq!(Era::from_kanji("令和").unwrap().end_date_jst(), None);
+        assert_eq!(Era::from_kanji("大化").unwrap().index(), Some(0));
+        assert_eq!(Era::from_kanji("令"), None);
+        assert_eq!(Era::from_kanji(""), None);
+    }
+
+    #[test]
+    fn test_initial_and_abbreviation() {
+        let initials: Vec<_> = Jidai::Modern.eras().filter_map(Era::initial).collect();
+        assert_eq!(initials, vec!['M', 'T', 'S', 'H', 'R']);
+        let abbreviations: Vec<_> = Jidai::Modern.eras().filter_map(Era::abbreviation).collect();
+        assert_eq!(abbreviations, vec!['明', '大', '昭', '平', '令']);
+        let edo = Era::from_kanji("慶応").unwrap();
+        assert_eq!((edo.initial(), edo.abbreviation()), (None, None));
+        assert_eq!(edo.jis_code(), None);
+        assert_eq!(Era::from_kanji("明治").unwrap().jis_code(), Some(1));
+        assert_eq!(Era::from_kanji("令和").unwrap().jis_code(), Some(5));
+    }
+
+    #[test]
+    fn test_jidai() {
+        assert_eq!(Jidai::from_datetime(utc_dt("1700-01-01")), Some(Jidai::Edo));
+        assert_eq!(
+            Jidai::from_datetime(utc_dt("2021-11-12")),
+            Some(Jidai::Modern)
+        );
+        assert_eq!(Jidai::from_datetime(utc_dt("0600-01-01")), None);
+        assert_eq!(Jidai::AzuchiMomoyama.romaji(), "azuchi-momoyama");
+        assert_eq!(Jidai::Modern.romaji(), "kingendai");
+
+        let modern: Vec<_> = Jidai::Modern.eras().filter_map(|era| era.kanji).collect();
+        assert_eq!(modern, vec!["明治", "大正", "昭和", "平成", "令和"]);
+        assert_eq!(
+            Jidai::Modern.start(),
+            Era::from_kanji("明治").unwrap().start_datetime()
+        );
+        assert_eq!(Jidai::Modern.end(), None);
+        assert_eq!(Jidai::Edo.end(), Some(Jidai::Modern.start()));
+        for pair in Jidai::ALL.windows(2) {
+            assert!(pair[0].start() < pair[1].start());
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let reiwa = Era::from_datetime(utc_dt("2021-11-12")).unwrap();
+        assert_eq!(reiwa.reading, Some("れいわ"));
+        assert_eq!(reiwa.gloss, Some("beautiful harmony"));
+        assert_eq!(Era::from_kanji("寛和").unwrap().reading, Some("かんな"));
+        assert_eq!(Era::from_kanji("天安").unwrap().reading, Some("てんあん"));
+        assert_eq!(Era::from_kanji("天保").unwrap().gloss, None);
+        assert!(SORTED_ERAS
+            .iter()
+            .all(|era| era.kanji.is_some() == era.reading.is_some()));
+    }
+
+    #[test]
+    fn test_display() {
+        let reiwa = Era::from_datetime(utc_dt("2021-11-12")).unwrap();
+        assert_eq!(format!("{}", reiwa), "令和");
+        assert_eq!(format!("{}", Era::from_index(2).unwrap()), "無年号");
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Era::count(), 241);
+        assert_eq!(Era::from_index(0).unwrap().romaji, Some("taika"));
+        let reiwa = Era::from_datetime(utc_dt("2021-11-12")).unwrap();
+        assert_eq!(reiwa.index(), Some(240));
+        assert_eq!(Era::from_index(239).unwrap().romaji, Some("heisei"));
+        assert_eq!(Era::from_index(Era::count()), None);
+        for index in 0..Era::count() {
+            assert_eq!(Era::from_index(index).unwrap().index(), Some(index));
+        }
+        assert_eq!(Era::from_kanji("正平").unwrap().index(), None);
+    }
+
+    #[test]
+    fn test_iteration() {
+        assert_eq!(Era::all().len(), Era::count());
+        assert_eq!(Era::all().next_back().unwrap().romaji, Some("reiwa"));
+
+        let eras: Vec<_> = Era::between(utc_dt("2021-11-12"), utc_dt("1988-04-01"))
+            .filter_map(|era| era.kanji)
+            .collect();
+        assert_eq!(eras, vec!["昭和", "平成", "令和"]);
+        assert_eq!(
+            Era::between(utc_dt("0600-01-01"), utc_dt("0601-01-01")).count(),
+            0
+        );
+
+        let edo: Vec<_> = Era::in_jidai(Jidai::Edo).collect();
+        assert_eq!(edo.len(), 35);
+        assert!(edo.iter().all(|era| era.jidai == Jidai::Edo));
+    }
+
+    #[test]
+    fn test_current_with() {
+        let clock = FixedClock(utc_dt("1995-01-17"));
+        assert_eq!(Era::current_with(&clock).unwrap().romaji, Some("heisei"));
+        let before_taika = FixedClock(utc_dt("0600-01-01"));
+        assert_eq!(Era::current_with(&before_taika), None);
+    }
+
+    #[test]
+    fn test_try_from_time_types() {
+        let reiwa = Era::from_datetime(utc_dt("2021-11-12")).unwrap();
+        let era = |result: Result<&'static Era, NihonifyError>| result.unwrap();
+
+        assert_eq!(era(utc_dt("2021-11-12").try_into()), reiwa);
+        assert_eq!(
+            era(DateTime::parse_from_rfc3339("2021-11-12T09:00:00+09:00")
+                .unwrap()
+                .try_into()),
+            reiwa
+        );
+        assert_eq!(
+            era(utc_dt("2021-11-12").with_timezone(&Local).try_into()),
+            reiwa
+        );
+        assert_eq!(era(utc_dt("2021-11-12").naive_utc().try_into()), reiwa);
+        assert_eq!(
+            era(SystemTime::from(utc_dt("2021-11-12")).try_into()),
+            reiwa
+        );
+
+        // Heisei ended at midnight JST on May 1st, 2019.
+        let eve = DateTime::parse_from_rfc3339("2019-04-30T23:59:59+09:00").unwrap();
+        assert_eq!(era(eve.try_into()).kanji, Some("平成"));
+        assert_eq!(
+            <&Era>::try_from(utc_dt("0600-01-01").naive_utc()),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: NaiveDate::from_ymd_opt(645, 7, 20).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn test_wareki_date() {
+        let reiwa = Era::from_datetime(utc_dt("2021-11-12")).unwrap();
+        let date = WarekiDate::new(reiwa, 3, 11, 12).unwrap();
+        assert_eq!(
+            date.to_naive_date(),
+            NaiveDate::from_ymd_opt(2021, 11, 12).unwrap()
+        );
+        assert_eq!(date.to_unix_epoch(), 1636675200);
+        assert_eq!(
+            date.to_datetime_utc().to_rfc3339(),
+            "2021-11-12T00:00:00+00:00"
+        );
+        assert_eq!(
+            date.to_datetime_jst().to_rfc3339(),
+            "2021-11-12T00:00:00+09:00"
+        );
+        assert_eq!(date.to_datetime_jst().timestamp(), 1636642800);
+
+        assert_eq!(
+            WarekiDate::new(reiwa, 1, 4, 30),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            WarekiDate::new(reiwa, 3, 2, 30),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            WarekiDate::new(reiwa, 7981, 12, 31).map(|date| date.to_ymd()),
+            Ok((9999, 12, 31))
+        );
+        assert_eq!(
+            WarekiDate::new(reiwa, 7982, 1, 1),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_wareki_date_conversions() {
+        let date = WarekiDate::try_from(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()).unwrap();
+        assert_eq!(date.era().romaji, Some("reiwa"));
+        assert_eq!((date.year(), date.month(), date.day()), (1, 5, 1));
+        assert_eq!(
+            NaiveDate::from(date),
+            NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()
+        );
+
+        let showa_end = WarekiDate::try_from(utc_dt("1989-01-07")).unwrap();
+        assert_eq!(showa_end.era().romaji, Some("shouwa"));
+        assert_eq!(showa_end.year(), 64);
+        assert_eq!(
+            DateTime::<Utc>::from(showa_end).to_rfc3339(),
+            "1989-01-07T00:00:00+00:00"
+        );
+
+        assert!(showa_end < date);
+        let heisei_start: WarekiDate = utc_dt("1989-01-08").try_into().unwrap();
+        assert_eq!(heisei_start.year(), 1);
+        assert_eq!(showa_end.max(heisei_start), heisei_start);
+
+        assert_eq!(
+            WarekiDate::try_from(NaiveDate::from_ymd_opt(600, 1, 1).unwrap()),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_unix_epoch_matches_era_table() {
+        let linear = |unix_epoch: i64| {
+            SORTED_ERAS.iter().find(|era| {
+                era.started_at <= unix_epoch && era.ended_at.is_none_or(|end| unix_epoch < end)
+            })
+        };
+        let new_years = (FIRST_ERA_YEAR..=2100).map(|year| {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .and_time(NaiveTime::MIN)
+                .and_utc()
+                .timestamp()
+                - JST_OFFSET as i64
+        });
+        for unix_epoch in SORTED_ERAS
+            .iter()
+            .map(|era| era.started_at)
+            .chain(new_years)
+        {
+            for unix_epoch in unix_epoch - 1..=unix_epoch + 1 {
+                assert_eq!(Era::from_unix_epoch(unix_epoch), linear(unix_epoch));
+            }
+        }
+        assert_eq!(Era::from_unix_epoch(i64::MIN), None);
+        assert_eq!(Era::from_unix_epoch(i64::MAX), SORTED_ERAS.last());
+    }
+
+    #[test]
+    fn test_raw_inputs() {
+        assert_eq!(Era::from_ymd(2019, 4, 30).unwrap().romaji, Some("heisei"));
+        assert_eq!(Era::from_ymd(2019, 5, 1).unwrap().romaji, Some("reiwa"));
+        assert_eq!(Era::from_ymd(2019, 2, 30), None);
+
+        let date = WarekiDate::from_ymd(2021, 11, 12).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (3, 11, 12));
+        assert_eq!(date.to_ymd(), (2021, 11, 12));
+        // 2021-11-11T15:00:00Z is midnight of the 12th in Japan.
+        assert_eq!(WarekiDate::from_unix_epoch(1636642800), Ok(date));
+        assert_eq!(
+            WarekiDate::from_unix_epoch(i64::MAX),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            WarekiDate::from_ymd(2021, 13, 1),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_wareki_date_display() {
+        let date = WarekiDate::from_ymd(2021, 11, 12).unwrap();
+        assert_eq!(date.to_string(), "令和3年11月12日");
+        assert_eq!(
+            WarekiDate::from_ymd(2019, 5, 1).unwrap().to_string(),
+            "令和元年5月1日"
+        );
+        assert_eq!(date.to_string().parse::<WarekiDate>(), Ok(date));
+    }
+
+    #[test]
+    fn test_from_unix_epoch_millis_and_micros() {
+        let heisei = Era::from_unix_epoch(1556636399);
+        let reiwa = Era::from_unix_epoch(1556636400);
+        assert_eq!(Era::from_unix_epoch_millis(1556636399999), heisei);
+        assert_eq!(Era::from_unix_epoch_millis(1556636400000), reiwa);
+        assert_eq!(Era::from_unix_epoch_micros(1556636399999999), heisei);
+        assert_eq!(Era::from_unix_epoch_micros(1556636400000000), reiwa);
+        // Just before 1970 rounds down, not towards zero.
+        assert_eq!(Era::from_unix_epoch_millis(-1).unwrap().kanji, Some("昭和"));
+    }
+
+    #[test]
+    fn test_start_and_end() {
+        let heisei = Era::from_datetime(utc_dt("2000-01-01")).unwrap();
+        assert_eq!(
+            heisei.start_datetime().to_rfc3339(),
+            "1989-01-07T15:00:00+00:00"
+        );
+        assert_eq!(
+            heisei.end_datetime().map(|end| end.to_rfc3339()),
+            Some("2019-04-30T15:00:00+00:00".to_owned())
+        );
+        assert_eq!(
+            heisei.start_date_jst(),
+            NaiveDate::from_ymd_opt(1989, 1, 8).unwrap()
+        );
+        assert_eq!(Era::from_kanji("令和").unwrap().end_datetime(), None);
+    }
+
+    #[test]
+    fn test_try_from_unix_epoch() {
+        assert_eq!(
+            Era::try_from_unix_epoch(-41795686801),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: NaiveDate::from_ymd_opt(645, 7, 20).unwrap()
+            })
+        );
+        assert_eq!(
+            Era::try_from_unix_epoch(253402300800),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            Era::try_from_unix_epoch(1636346788).unwrap().romaji,
+            Some("reiwa")
+        );
+        // 1333-07-15, when Shōkyō ended, a year before Kenmu began.
+        assert_eq!(
+            Era::try_from_unix_epoch(-20084922000),
+            Err(NihonifyError::UnnamedEra)
+        );
+        assert_eq!(
+            Era::last_started_by(-20084922000).unwrap().kanji,
+            Some("正慶")
+        );
+        assert_eq!(Era::last_started_by(-41795686801), None);
+    }
+
+    #[test]
+    fn test_civil_from_unix_epoch() {
+        assert_eq!(civil_from_unix_epoch(0), (1970, 1, 1));
+        assert_eq!(civil_from_unix_epoch(1556668800), (2019, 5, 1));
+        assert_eq!(civil_from_unix_epoch(1556668799), (2019, 4, 30));
+        assert_eq!(civil_from_unix_epoch(-41795654400), (645, 7, 20));
+
+        for unix_epoch in (-41795654400..=253402214400).step_by(86400 * 97) {
+            let (year, month, day) = civil_from_unix_epoch(unix_epoch);
+            assert_eq!(unix_epoch_from_civil(year, month, day), Some(unix_epoch));
+        }
+        assert_eq!(unix_epoch_from_civil(2000, 2, 29), Some(951782400));
+        assert_eq!(unix_epoch_from_civil(1900, 2, 29), None);
+        assert_eq!(unix_epoch_from_civil(2021, 13, 1), None);
+        assert_eq!(unix_epoch_from_civil(2021, 4, 0), None);
+    }
+
+    #[test]
+    fn test_boundary_proximity() {
+        let boundary = boundary_proximity(utc_dt("2019-04-15"), Duration::days(30)).unwrap();
+        assert_eq!(boundary.before.romaji, Some("heisei"));
+        assert_eq!(boundary.after.romaji, Some("reiwa"));
+        assert_eq!(boundary.distance.num_days(), 15);
+
+        let boundary = boundary_proximity(utc_dt("1989-01-20"), Duration::days(30)).unwrap();
+        assert_eq!(boundary.after.romaji, Some("heisei"));
+        assert_eq!(boundary.distance.num_days(), -12);
+
+        assert!(boundary_proximity(utc_dt("2019-06-15"), Duration::days(30)).is_none());
+    }
+}
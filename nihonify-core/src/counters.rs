@@ -0,0 +1,214 @@
+//! Counter words (助数詞): writing and reading counted quantities, e.g. 三本
+//! read さんぼん, with the sound changes and irregular readings of the common
+//! counters.
+//!
+//! ```
+//! use nihonify_core::counters::{counted_reading, format_counted, Counter};
+//!
+//! assert_eq!(format_counted(3, Counter::Hon), "三本");
+//! assert_eq!(counted_reading(3, Counter::Hon), "さんぼん");
+//! assert_eq!(counted_reading(2, Counter::Nin), "ふたり");
+//! ```
+
+use crate::format::Numerals;
+use crate::numbers::{geminated, reading_parts};
+use alloc::string::String;
+
+/// A counter word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    /// 人, for people.
+    Nin,
+    /// 本, for long, thin things.
+    Hon,
+    /// 枚, for flat things.
+    Mai,
+    /// 個, for small things.
+    Ko,
+    /// 匹, for small animals.
+    Hiki,
+    /// 回, for times.
+    Kai,
+    /// 冊, for books.
+    Satsu,
+    /// 台, for machines and vehicles.
+    Dai,
+    /// 杯, for cupfuls.
+    Hai,
+    /// 歳, for years of age.
+    Sai,
+}
+
+/// How the sound of a counter and the number before it change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rule {
+    /// No change, as in よんまい.
+    Regular,
+    /// The number geminates after いち, ろく, はち, じゅう and ひゃく, as in
+    /// いっこ.
+    Geminating,
+    /// As `Geminating`, but ろく stays, as in ろくさつ.
+    GeminatingExceptSix,
+    /// An h-row counter, which geminates like `Geminating` and then takes its
+    /// p-row form, e.g. いっぽん, and takes its b-row form after さん, せん
+    /// and まん, e.g. さんぼん.
+    HRow { p: &'static str, b: &'static str },
+}
+
+impl Counter {
+    /// The counter's kanji, e.g. 本.
+    pub fn kanji(&self) -> &'static str {
+        self.spec().0
+    }
+
+    /// The counter's own reading, e.g. ほん.
+    pub fn reading(&self) -> &'static str {
+        self.spec().1
+    }
+
+    fn spec(&self) -> (&'static str, &'static str, Rule) {
+        match self {
+            Counter::Nin => ("人", "にん", Rule::Regular),
+            Counter::Hon => (
+                "本",
+                "ほん",
+                Rule::HRow {
+                    p: "ぽん",
+                    b: "ぼん",
+                },
+            ),
+            Counter::Mai => ("枚", "まい", Rule::Regular),
+            Counter::Ko => ("個", "こ", Rule::Geminating),
+            Counter::Hiki => (
+                "匹",
+                "ひき",
+                Rule::HRow {
+                    p: "ぴき",
+                    b: "びき",
+                },
+            ),
+            Counter::Kai => ("回", "かい", Rule::Geminating),
+            Counter::Satsu => ("冊", "さつ", Rule::GeminatingExceptSix),
+            Counter::Dai => ("台", "だい", Rule::Regular),
+            Counter::Hai => (
+                "杯",
+                "はい",
+                Rule::HRow {
+                    p: "ぱい",
+                    b: "ばい",
+                },
+            ),
+            Counter::Sai => ("歳", "さい", Rule::GeminatingExceptSix),
+        }
+    }
+}
+
+/// Writes the quantity in kanji numerals followed by the counter, e.g. 三本.
+pub fn format_counted(num: u64, counter: Counter) -> String {
+    format_counted_with(num, counter, Numerals::Kanji)
+}
+
+/// Like `format_counted`, in the given numerals, e.g. ３本.
+pub fn format_counted_with(num: u64, counter: Counter, numerals: Numerals) -> String {
+    numerals.render(num) + counter.kanji()
+}
+
+/// Reads the counted quantity out in hiragana, e.g. さんぼん for three of
+/// 本, ひとり for one person, or はたち for twenty years of age.
+pub fn counted_reading(num: u64, counter: Counter) -> String {
+    match (counter, num) {
+        (Counter::Nin, 1) => return String::from("ひとり"),
+        (Counter::Nin, 2) => return String::from("ふたり"),
+        (Counter::Sai, 20) => return String::from("はたち"),
+        _ => (),
+    }
+
+    let (_, reading, rule) = counter.spec();
+    let mut parts = reading_parts(num);
+    let last = parts.last_mut().unwrap();
+    let geminate = match rule {
+        Rule::Regular => None,
+        Rule::GeminatingExceptSix if *last == "ろく" => None,
+        Rule::Geminating | Rule::GeminatingExceptSix | Rule::HRow { .. } => geminated(last),
+    };
+    let counter_reading = match (rule, geminate) {
+        (Rule::HRow { p, .. }, Some(_)) => p,
+        (Rule::HRow { b, .. }, None) if matches!(*last, "さん" | "せん" | "ぜん" | "まん") => {
+            b
+        }
+        _ => reading,
+    };
+    if let Some(part) = geminate {
+        *last = part;
+    }
+    // 四 is read よ before 人, as in よにん.
+    if counter == Counter::Nin && *last == "よん" {
+        *last = "よ";
+    }
+
+    parts.concat() + counter_reading
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_format_counted() {
+        assert_eq!(format_counted(3, Counter::Hon), "三本");
+        assert_eq!(format_counted(31, Counter::Mai), "三十一枚");
+        assert_eq!(
+            format_counted_with(3, Counter::Hon, Numerals::FullWidth),
+            "３本"
+        );
+        assert_eq!(
+            format_counted_with(12, Counter::Nin, Numerals::Ascii),
+            "12人"
+        );
+    }
+
+    #[test]
+    fn test_counted_reading() {
+        let readings = |counter| -> Vec<String> {
+            [1, 3, 6, 8, 10]
+                .iter()
+                .map(|&num| counted_reading(num, counter))
+                .collect()
+        };
+        assert_eq!(
+            readings(Counter::Hon),
+            vec!["いっぽん", "さんぼん", "ろっぽん", "はっぽん", "じゅっぽん"]
+        );
+        assert_eq!(
+            readings(Counter::Hiki),
+            vec!["いっぴき", "さんびき", "ろっぴき", "はっぴき", "じゅっぴき"]
+        );
+        assert_eq!(
+            readings(Counter::Ko),
+            vec!["いっこ", "さんこ", "ろっこ", "はっこ", "じゅっこ"]
+        );
+        assert_eq!(
+            readings(Counter::Satsu),
+            vec!["いっさつ", "さんさつ", "ろくさつ", "はっさつ", "じゅっさつ"]
+        );
+        assert_eq!(
+            readings(Counter::Mai),
+            vec!["いちまい", "さんまい", "ろくまい", "はちまい", "じゅうまい"]
+        );
+        assert_eq!(
+            readings(Counter::Nin),
+            vec!["ひとり", "さんにん", "ろくにん", "はちにん", "じゅうにん"]
+        );
+        assert_eq!(counted_reading(4, Counter::Nin), "よにん");
+        assert_eq!(counted_reading(14, Counter::Nin), "じゅうよにん");
+        assert_eq!(counted_reading(21, Counter::Nin), "にじゅういちにん");
+        assert_eq!(counted_reading(20, Counter::Sai), "はたち");
+        assert_eq!(counted_reading(100, Counter::Hon), "ひゃっぽん");
+        assert_eq!(counted_reading(300, Counter::Hon), "さんびゃっぽん");
+        assert_eq!(counted_reading(1000, Counter::Hon), "せんぼん");
+        assert_eq!(counted_reading(4, Counter::Hai), "よんはい");
+        assert_eq!(counted_reading(3, Counter::Hai), "さんばい");
+    }
+}
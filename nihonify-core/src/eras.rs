@@ -0,0 +1,2264 @@
+use crate::calendar::{Era, Jidai};
+
+/// Pre-sorted array of all Japanese Eras. For the Nanboku-chō period, these
+/// are the Northern Court's eras; see `SOUTHERN_COURT_ERAS`.
+pub const SORTED_ERAS: &[Era] = &[
+    Era {
+        kanji: Some("大化"),
+        romaji: Some("taika"),
+        reading: Some("たいか"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -41795686800,
+        ended_at: Some(-41648029200),
+    },
+    Era {
+        kanji: Some("白雉"),
+        romaji: Some("hakuchi"),
+        reading: Some("はくち"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -41648029200,
+        ended_at: Some(-41500458000),
+    },
+    Era {
+        kanji: None,
+        romaji: None,
+        reading: None,
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -41500458000,
+        ended_at: Some(-40499427600),
+    },
+    Era {
+        kanji: Some("朱鳥"),
+        romaji: Some("shuchou"),
+        reading: Some("しゅちょう"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -40499427600,
+        ended_at: Some(-40495280400),
+    },
+    Era {
+        kanji: None,
+        romaji: None,
+        reading: None,
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -40495280400,
+        ended_at: Some(-40034941200),
+    },
+    Era {
+        kanji: Some("大宝"),
+        romaji: Some("taihou"),
+        reading: Some("たいほう"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -40034941200,
+        ended_at: Some(-39936445200),
+    },
+    Era {
+        kanji: Some("慶雲"),
+        romaji: Some("keiun"),
+        reading: Some("けいうん"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -39936445200,
+        ended_at: Some(-39821446800),
+    },
+    Era {
+        kanji: Some("和銅"),
+        romaji: Some("wadou"),
+        reading: Some("わどう"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -39821446800,
+        ended_at: Some(-39579958800),
+    },
+    Era {
+        kanji: Some("霊亀"),
+        romaji: Some("reiki"),
+        reading: Some("れいき"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -39579958800,
+        ended_at: Some(-39509715600),
+    },
+    Era {
+        kanji: Some("養老"),
+        romaji: Some("yourou"),
+        reading: Some("ようろう"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -39509715600,
+        ended_at: Some(-39314365200),
+    },
+    Era {
+        kanji: Some("神亀"),
+        romaji: Some("jinki"),
+        reading: Some("じんき"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -39314365200,
+        ended_at: Some(-39140787600),
+    },
+    Era {
+        kanji: Some("天平"),
+        romaji: Some("tempyou"),
+        reading: Some("てんぴょう"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -39140787600,
+        ended_at: Some(-38520090000),
+    },
+    Era {
+        kanji: Some("天平感宝"),
+        romaji: Some("tempyoukampou"),
+        reading: Some("てんぴょうかんぽう"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -38520090000,
+        ended_at: Some(-38510845200),
+    },
+    Era {
+        kanji: Some("天平勝宝"),
+        romaji: Some("tempyoushouhou"),
+        reading: Some("てんぴょうしょうほう"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -38510845200,
+        ended_at: Some(-38256829200),
+    },
+    Era {
+        kanji: Some("天平宝字"),
+        romaji: Some("tempyouhouji"),
+        reading: Some("てんぴょうほうじ"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -38256829200,
+        ended_at: Some(-38023117200),
+    },
+    Era {
+        kanji: Some("天平神護"),
+        romaji: Some("tempyoujingo"),
+        reading: Some("てんぴょうじんご"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -38023117200,
+        ended_at: Some(-37940691600),
+    },
+    Era {
+        kanji: Some("神護景雲"),
+        romaji: Some("jingokeiun"),
+        reading: Some("じんごけいうん"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -37940691600,
+        ended_at: Some(-37842541200),
+    },
+    Era {
+        kanji: Some("宝亀"),
+        romaji: Some("houki"),
+        reading: Some("ほうき"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -37842541200,
+        ended_at: Some(-37518368400),
+    },
+    Era {
+        kanji: Some("天応"),
+        romaji: Some("tennou"),
+        reading: Some("てんおう"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -37518368400,
+        ended_at: Some(-37465837200),
+    },
+    Era {
+        kanji: Some("延暦"),
+        romaji: Some("enryaku"),
+        reading: Some("えんりゃく"),
+        gloss: None,
+        jidai: Jidai::Nara,
+        started_at: -37465837200,
+        ended_at: Some(-36718304400),
+    },
+    Era {
+        kanji: Some("大同"),
+        romaji: Some("daidou"),
+        reading: Some("だいどう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -36718304400,
+        ended_at: Some(-36580496400),
+    },
+    Era {
+        kanji: Some("弘仁"),
+        romaji: Some("kounin"),
+        reading: Some("こうにん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -36580496400,
+        ended_at: Some(-36160678800),
+    },
+    Era {
+        kanji: Some("天長"),
+        romaji: Some("tenchou"),
+        reading: Some("てんちょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -36160678800,
+        ended_at: Some(-35844541200),
+    },
+    Era {
+        kanji: Some("承和"),
+        romaji: Some("jouwa"),
+        reading: Some("じょうわ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35844541200,
+        ended_at: Some(-35389558800),
+    },
+    Era {
+        kanji: Some("嘉祥"),
+        romaji: Some("kashou"),
+        reading: Some("かしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35389558800,
+        ended_at: Some(-35298838800),
+    },
+    Era {
+        kanji: Some("仁寿"),
+        romaji: Some("ninju"),
+        reading: Some("にんじゅ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35298838800,
+        ended_at: Some(-35186432400),
+    },
+    Era {
+        kanji: Some("斉衡"),
+        romaji: Some("saikou"),
+        reading: Some("さいこう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35186432400,
+        ended_at: Some(-35115757200),
+    },
+    Era {
+        kanji: Some("天安"),
+        romaji: Some("tennan"),
+        reading: Some("てんあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35115757200,
+        ended_at: Some(-35047414800),
+    },
+    Era {
+        kanji: Some("貞観"),
+        romaji: Some("jougan"),
+        reading: Some("じょうがん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -35047414800,
+        ended_at: Some(-34478298000),
+    },
+    Era {
+        kanji: Some("元慶"),
+        romaji: Some("gangyou"),
+        reading: Some("がんぎょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -34478298000,
+        ended_at: Some(-34232922000),
+    },
+    Era {
+        kanji: Some("仁和"),
+        romaji: Some("ninna"),
+        reading: Some("にんな"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -34232922000,
+        ended_at: Some(-34099779600),
+    },
+    Era {
+        kanji: Some("寛平"),
+        romaji: Some("kampyou"),
+        reading: Some("かんぴょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -34099779600,
+        ended_at: Some(-33816646800),
+    },
+    Era {
+        kanji: Some("昌泰"),
+        romaji: Some("shoutai"),
+        reading: Some("しょうたい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -33816646800,
+        ended_at: Some(-33713053200),
+    },
+    Era {
+        kanji: Some("延喜"),
+        romaji: Some("engi"),
+        reading: Some("えんぎ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -33713053200,
+        ended_at: Some(-33026950800),
+    },
+    Era {
+        kanji: Some("延長"),
+        romaji: Some("enchou"),
+        reading: Some("えんちょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -33026950800,
+        ended_at: Some(-32775613200),
+    },
+    Era {
+        kanji: Some("承平"),
+        romaji: Some("jouhei"),
+        reading: Some("じょうへい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -32775613200,
+        ended_at: Some(-32551491600),
+    },
+    Era {
+        kanji: Some("天慶"),
+        romaji: Some("tengyou"),
+        reading: Some("てんぎょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -32551491600,
+        ended_at: Some(-32270778000),
+    },
+    Era {
+        kanji: Some("天暦"),
+        romaji: Some("tenryaku"),
+        reading: Some("てんりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -32270778000,
+        ended_at: Some(-31938742800),
+    },
+    Era {
+        kanji: Some("天徳"),
+        romaji: Some("tentoku"),
+        reading: Some("てんとく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31938742800,
+        ended_at: Some(-31835062800),
+    },
+    Era {
+        kanji: Some("応和"),
+        romaji: Some("ouwa"),
+        reading: Some("おうわ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31835062800,
+        ended_at: Some(-31725939600),
+    },
+    Era {
+        kanji: Some("康保"),
+        romaji: Some("kouhou"),
+        reading: Some("こうほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31725939600,
+        ended_at: Some(-31597981200),
+    },
+    Era {
+        kanji: Some("安和"),
+        romaji: Some("anna"),
+        reading: Some("あんな"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31597981200,
+        ended_at: Some(-31545968400),
+    },
+    Era {
+        kanji: Some("天禄"),
+        romaji: Some("tenroku"),
+        reading: Some("てんろく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31545968400,
+        ended_at: Some(-31428982800),
+    },
+    Era {
+        kanji: Some("天延"),
+        romaji: Some("tenen"),
+        reading: Some("てんえん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31428982800,
+        ended_at: Some(-31347939600),
+    },
+    Era {
+        kanji: Some("貞元"),
+        romaji: Some("jougen"),
+        reading: Some("じょうげん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31347939600,
+        ended_at: Some(-31272598800),
+    },
+    Era {
+        kanji: Some("天元"),
+        romaji: Some("tengen"),
+        reading: Some("てんげん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31272598800,
+        ended_at: Some(-31133494800),
+    },
+    Era {
+        kanji: Some("永観"),
+        romaji: Some("eikan"),
+        reading: Some("えいかん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31133494800,
+        ended_at: Some(-31071200400),
+    },
+    Era {
+        kanji: Some("寛和"),
+        romaji: Some("kanna"),
+        reading: Some("かんな"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31071200400,
+        ended_at: Some(-31009338000),
+    },
+    Era {
+        kanji: Some("永延"),
+        romaji: Some("eien"),
+        reading: Some("えいえん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -31009338000,
+        ended_at: Some(-30935120400),
+    },
+    Era {
+        kanji: Some("永祚"),
+        romaji: Some("eiso"),
+        reading: Some("えいそ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30935120400,
+        ended_at: Some(-30896931600),
+    },
+    Era {
+        kanji: Some("正暦"),
+        romaji: Some("shouryaku"),
+        reading: Some("しょうりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30896931600,
+        ended_at: Some(-30760419600),
+    },
+    Era {
+        kanji: Some("長徳"),
+        romaji: Some("choutoku"),
+        reading: Some("ちょうとく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30760419600,
+        ended_at: Some(-30638682000),
+    },
+    Era {
+        kanji: Some("長保"),
+        romaji: Some("chouhou"),
+        reading: Some("ちょうほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30638682000,
+        ended_at: Some(-30464586000),
+    },
+    Era {
+        kanji: Some("寛弘"),
+        romaji: Some("kankou"),
+        reading: Some("かんこう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30464586000,
+        ended_at: Some(-30196227600),
+    },
+    Era {
+        kanji: Some("長和"),
+        romaji: Some("chouwa"),
+        reading: Some("ちょうわ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30196227600,
+        ended_at: Some(-30061184400),
+    },
+    Era {
+        kanji: Some("寛仁"),
+        romaji: Some("kannin"),
+        reading: Some("かんにん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -30061184400,
+        ended_at: Some(-29940570000),
+    },
+    Era {
+        kanji: Some("治安"),
+        romaji: Some("jian"),
+        reading: Some("じあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29940570000,
+        ended_at: Some(-29832483600),
+    },
+    Era {
+        kanji: Some("万寿"),
+        romaji: Some("manju"),
+        reading: Some("まんじゅ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29832483600,
+        ended_at: Some(-29706339600),
+    },
+    Era {
+        kanji: Some("長元"),
+        romaji: Some("chougen"),
+        reading: Some("ちょうげん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29706339600,
+        ended_at: Some(-29431069200),
+    },
+    Era {
+        kanji: Some("長暦"),
+        romaji: Some("chouryaku"),
+        reading: Some("ちょうりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29431069200,
+        ended_at: Some(-29317280400),
+    },
+    Era {
+        kanji: Some("長久"),
+        romaji: Some("choukyuu"),
+        reading: Some("ちょうきゅう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29317280400,
+        ended_at: Some(-29191050000),
+    },
+    Era {
+        kanji: Some("寛徳"),
+        romaji: Some("kantoku"),
+        reading: Some("かんとく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29191050000,
+        ended_at: Some(-29145949200),
+    },
+    Era {
+        kanji: Some("永承"),
+        romaji: Some("eishou"),
+        reading: Some("えいしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -29145949200,
+        ended_at: Some(-28934442000),
+    },
+    Era {
+        kanji: Some("天喜"),
+        romaji: Some("tenki"),
+        reading: Some("てんき"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28934442000,
+        ended_at: Some(-28756890000),
+    },
+    Era {
+        kanji: Some("康平"),
+        romaji: Some("kouhei"),
+        reading: Some("こうへい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28756890000,
+        ended_at: Some(-28537261200),
+    },
+    Era {
+        kanji: Some("治暦"),
+        romaji: Some("jiryaku"),
+        reading: Some("じりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28537261200,
+        ended_at: Some(-28421485200),
+    },
+    Era {
+        kanji: Some("延久"),
+        romaji: Some("enkyuu"),
+        reading: Some("えんきゅう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28421485200,
+        ended_at: Some(-28252227600),
+    },
+    Era {
+        kanji: Some("承保"),
+        romaji: Some("jouhou"),
+        reading: Some("じょうほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28252227600,
+        ended_at: Some(-28150621200),
+    },
+    Era {
+        kanji: Some("承暦"),
+        romaji: Some("jouryaku"),
+        reading: Some("じょうりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28150621200,
+        ended_at: Some(-28046682000),
+    },
+    Era {
+        kanji: Some("永保"),
+        romaji: Some("eihou"),
+        reading: Some("えいほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -28046682000,
+        ended_at: Some(-27952592400),
+    },
+    Era {
+        kanji: Some("応徳"),
+        romaji: Some("outoku"),
+        reading: Some("おうとく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27952592400,
+        ended_at: Some(-27853059600),
+    },
+    Era {
+        kanji: Some("寛治"),
+        romaji: Some("kanji"),
+        reading: Some("かんじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27853059600,
+        ended_at: Some(-27609930000),
+    },
+    Era {
+        kanji: Some("嘉保"),
+        romaji: Some("kahou"),
+        reading: Some("かほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27609930000,
+        ended_at: Some(-27548499600),
+    },
+    Era {
+        kanji: Some("永長"),
+        romaji: Some("eichou"),
+        reading: Some("えいちょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27548499600,
+        ended_at: Some(-27517568400),
+    },
+    Era {
+        kanji: Some("承徳"),
+        romaji: Some("joutoku"),
+        reading: Some("じょうとく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27517568400,
+        ended_at: Some(-27463395600),
+    },
+    Era {
+        kanji: Some("康和"),
+        romaji: Some("kouwa"),
+        reading: Some("こうわ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27463395600,
+        ended_at: Some(-27322045200),
+    },
+    Era {
+        kanji: Some("長治"),
+        romaji: Some("chouji"),
+        reading: Some("ちょうじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27322045200,
+        ended_at: Some(-27253270800),
+    },
+    Era {
+        kanji: Some("嘉承"),
+        romaji: Some("kashou"),
+        reading: Some("かしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27253270800,
+        ended_at: Some(-27179830800),
+    },
+    Era {
+        kanji: Some("天仁"),
+        romaji: Some("tennin"),
+        reading: Some("てんにん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27179830800,
+        ended_at: Some(-27120214800),
+    },
+    Era {
+        kanji: Some("天永"),
+        romaji: Some("tennei"),
+        reading: Some("てんえい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27120214800,
+        ended_at: Some(-27023360400),
+    },
+    Era {
+        kanji: Some("永久"),
+        romaji: Some("eikyuu"),
+        reading: Some("えいきゅう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -27023360400,
+        ended_at: Some(-26876134800),
+    },
+    Era {
+        kanji: Some("元永"),
+        romaji: Some("gennei"),
+        reading: Some("げんえい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26876134800,
+        ended_at: Some(-26811766800),
+    },
+    Era {
+        kanji: Some("保安"),
+        romaji: Some("houan"),
+        reading: Some("ほうあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26811766800,
+        ended_at: Some(-26684758800),
+    },
+    Era {
+        kanji: Some("天治"),
+        romaji: Some("tenji"),
+        reading: Some("てんじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26684758800,
+        ended_at: Some(-26629635600),
+    },
+    Era {
+        kanji: Some("大治"),
+        romaji: Some("daiji"),
+        reading: Some("だいじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26629635600,
+        ended_at: Some(-26470746000),
+    },
+    Era {
+        kanji: Some("天承"),
+        romaji: Some("tenshou"),
+        reading: Some("てんしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26470746000,
+        ended_at: Some(-26421411600),
+    },
+    Era {
+        kanji: Some("長承"),
+        romaji: Some("choushou"),
+        reading: Some("ちょうしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26421411600,
+        ended_at: Some(-26335702800),
+    },
+    Era {
+        kanji: Some("保延"),
+        romaji: Some("houen"),
+        reading: Some("ほうえん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26335702800,
+        ended_at: Some(-26140784400),
+    },
+    Era {
+        kanji: Some("永治"),
+        romaji: Some("eiji"),
+        reading: Some("えいじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26140784400,
+        ended_at: Some(-26116160400),
+    },
+    Era {
+        kanji: Some("康治"),
+        romaji: Some("kouji"),
+        reading: Some("こうじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26116160400,
+        ended_at: Some(-26058013200),
+    },
+    Era {
+        kanji: Some("天養"),
+        romaji: Some("tennyou"),
+        reading: Some("てんよう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26058013200,
+        ended_at: Some(-26014640400),
+    },
+    Era {
+        kanji: Some("久安"),
+        romaji: Some("kyuuan"),
+        reading: Some("きゅうあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -26014640400,
+        ended_at: Some(-25840803600),
+    },
+    Era {
+        kanji: Some("仁平"),
+        romaji: Some("ninmpei"),
+        reading: Some("にんぺい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25840803600,
+        ended_at: Some(-25720794000),
+    },
+    Era {
+        kanji: Some("久寿"),
+        romaji: Some("kyuuju"),
+        reading: Some("きゅうじゅ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25720794000,
+        ended_at: Some(-25674915600),
+    },
+    Era {
+        kanji: Some("保元"),
+        romaji: Some("hougen"),
+        reading: Some("ほうげん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25674915600,
+        ended_at: Some(-25581085200),
+    },
+    Era {
+        kanji: Some("平治"),
+        romaji: Some("heiji"),
+        reading: Some("へいじ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25581085200,
+        ended_at: Some(-25556461200),
+    },
+    Era {
+        kanji: Some("永暦"),
+        romaji: Some("eiryaku"),
+        reading: Some("えいりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25556461200,
+        ended_at: Some(-25506003600),
+    },
+    Era {
+        kanji: Some("応保"),
+        romaji: Some("ouhou"),
+        reading: Some("おうほう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25506003600,
+        ended_at: Some(-25455286800),
+    },
+    Era {
+        kanji: Some("長寛"),
+        romaji: Some("choukan"),
+        reading: Some("ちょうかん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25455286800,
+        ended_at: Some(-25385994000),
+    },
+    Era {
+        kanji: Some("永万"),
+        romaji: Some("eiman"),
+        reading: Some("えいまん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25385994000,
+        ended_at: Some(-25348323600),
+    },
+    Era {
+        kanji: Some("仁安"),
+        romaji: Some("ninnan"),
+        reading: Some("にんあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25348323600,
+        ended_at: Some(-25265725200),
+    },
+    Era {
+        kanji: Some("嘉応"),
+        romaji: Some("kaou"),
+        reading: Some("かおう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25265725200,
+        ended_at: Some(-25200838800),
+    },
+    Era {
+        kanji: Some("承安"),
+        romaji: Some("shouan"),
+        reading: Some("しょうあん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25200838800,
+        ended_at: Some(-25067610000),
+    },
+    Era {
+        kanji: Some("安元"),
+        romaji: Some("angen"),
+        reading: Some("あんげん"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25067610000,
+        ended_at: Some(-25003328400),
+    },
+    Era {
+        kanji: Some("治承"),
+        romaji: Some("jishou"),
+        reading: Some("じしょう"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -25003328400,
+        ended_at: Some(-24877443600),
+    },
+    Era {
+        kanji: Some("養和"),
+        romaji: Some("youwa"),
+        reading: Some("ようわ"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -24877443600,
+        ended_at: Some(-24850832400),
+    },
+    Era {
+        kanji: Some("寿永"),
+        romaji: Some("juei"),
+        reading: Some("じゅえい"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -24850832400,
+        ended_at: Some(-24790525200),
+    },
+    Era {
+        kanji: Some("元暦"),
+        romaji: Some("genryaku"),
+        reading: Some("げんりゃく"),
+        gloss: None,
+        jidai: Jidai::Heian,
+        started_at: -24790525200,
+        ended_at: Some(-24749917200),
+    },
+    Era {
+        kanji: Some("文治"),
+        romaji: Some("bunji"),
+        reading: Some("ぶんじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24749917200,
+        ended_at: Some(-24602173200),
+    },
+    Era {
+        kanji: Some("建久"),
+        romaji: Some("kenkyuu"),
+        reading: Some("けんきゅう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24602173200,
+        ended_at: Some(-24317571600),
+    },
+    Era {
+        kanji: Some("正治"),
+        romaji: Some("shouji"),
+        reading: Some("しょうじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24317571600,
+        ended_at: Some(-24260029200),
+    },
+    Era {
+        kanji: Some("建仁"),
+        romaji: Some("kennin"),
+        reading: Some("けんにん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24260029200,
+        ended_at: Some(-24164989200),
+    },
+    Era {
+        kanji: Some("元久"),
+        romaji: Some("genkyuu"),
+        reading: Some("げんきゅう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24164989200,
+        ended_at: Some(-24095523600),
+    },
+    Era {
+        kanji: Some("建永"),
+        romaji: Some("kennei"),
+        reading: Some("けんえい"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24095523600,
+        ended_at: Some(-24049818000),
+    },
+    Era {
+        kanji: Some("承元"),
+        romaji: Some("jougen"),
+        reading: Some("じょうげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -24049818000,
+        ended_at: Some(-23941472400),
+    },
+    Era {
+        kanji: Some("建暦"),
+        romaji: Some("kenryaku"),
+        reading: Some("けんりゃく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23941472400,
+        ended_at: Some(-23854986000),
+    },
+    Era {
+        kanji: Some("建保"),
+        romaji: Some("kempou"),
+        reading: Some("けんぽう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23854986000,
+        ended_at: Some(-23686074000),
+    },
+    Era {
+        kanji: Some("承久"),
+        romaji: Some("joukyuu"),
+        reading: Some("じょうきゅう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23686074000,
+        ended_at: Some(-23591552400),
+    },
+    Era {
+        kanji: Some("貞応"),
+        romaji: Some("jouou"),
+        reading: Some("じょうおう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23591552400,
+        ended_at: Some(-23509386000),
+    },
+    Era {
+        kanji: Some("元仁"),
+        romaji: Some("gennin"),
+        reading: Some("げんにん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23509386000,
+        ended_at: Some(-23496598800),
+    },
+    Era {
+        kanji: Some("嘉禄"),
+        romaji: Some("karoku"),
+        reading: Some("かろく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23496598800,
+        ended_at: Some(-23413222800),
+    },
+    Era {
+        kanji: Some("安貞"),
+        romaji: Some("antei"),
+        reading: Some("あんてい"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23413222800,
+        ended_at: Some(-23375379600),
+    },
+    Era {
+        kanji: Some("寛喜"),
+        romaji: Some("kanki"),
+        reading: Some("かんき"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23375379600,
+        ended_at: Some(-23278698000),
+    },
+    Era {
+        kanji: Some("貞永"),
+        romaji: Some("jouei"),
+        reading: Some("じょうえい"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23278698000,
+        ended_at: Some(-23244397200),
+    },
+    Era {
+        kanji: Some("天福"),
+        romaji: Some("tempuku"),
+        reading: Some("てんぷく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23244397200,
+        ended_at: Some(-23196790800),
+    },
+    Era {
+        kanji: Some("文暦"),
+        romaji: Some("bunryaku"),
+        reading: Some("ぶんりゃく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23196790800,
+        ended_at: Some(-23167501200),
+    },
+    Era {
+        kanji: Some("嘉禎"),
+        romaji: Some("katei"),
+        reading: Some("かてい"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23167501200,
+        ended_at: Some(-23067709200),
+    },
+    Era {
+        kanji: Some("暦仁"),
+        romaji: Some("ryakunin"),
+        reading: Some("りゃくにん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23067709200,
+        ended_at: Some(-23061402000),
+    },
+    Era {
+        kanji: Some("延応"),
+        romaji: Some("ennou"),
+        reading: Some("えんおう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23061402000,
+        ended_at: Some(-23017251600),
+    },
+    Era {
+        kanji: Some("仁治"),
+        romaji: Some("ninji"),
+        reading: Some("にんじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -23017251600,
+        ended_at: Some(-22934739600),
+    },
+    Era {
+        kanji: Some("寛元"),
+        romaji: Some("kangen"),
+        reading: Some("かんげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22934739600,
+        ended_at: Some(-22806954000),
+    },
+    Era {
+        kanji: Some("宝治"),
+        romaji: Some("houji"),
+        reading: Some("ほうじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22806954000,
+        ended_at: Some(-22741462800),
+    },
+    Era {
+        kanji: Some("建長"),
+        romaji: Some("kenchou"),
+        reading: Some("けんちょう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22741462800,
+        ended_at: Some(-22505418000),
+    },
+    Era {
+        kanji: Some("康元"),
+        romaji: Some("kougen"),
+        reading: Some("こうげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22505418000,
+        ended_at: Some(-22491766800),
+    },
+    Era {
+        kanji: Some("正嘉"),
+        romaji: Some("shouka"),
+        reading: Some("しょうか"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22491766800,
+        ended_at: Some(-22426966800),
+    },
+    Era {
+        kanji: Some("正元"),
+        romaji: Some("shougen"),
+        reading: Some("しょうげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22426966800,
+        ended_at: Some(-22392406800),
+    },
+    Era {
+        kanji: Some("文応"),
+        romaji: Some("bunnou"),
+        reading: Some("ぶんおう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22392406800,
+        ended_at: Some(-22366314000),
+    },
+    Era {
+        kanji: Some("弘長"),
+        romaji: Some("kouchou"),
+        reading: Some("こうちょう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22366314000,
+        ended_at: Some(-22271187600),
+    },
+    Era {
+        kanji: Some("文永"),
+        romaji: Some("bunnei"),
+        reading: Some("ぶんえい"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -22271187600,
+        ended_at: Some(-21919280400),
+    },
+    Era {
+        kanji: Some("建治"),
+        romaji: Some("kenji"),
+        reading: Some("けんじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21919280400,
+        ended_at: Some(-21829770000),
+    },
+    Era {
+        kanji: Some("弘安"),
+        romaji: Some("kouan"),
+        reading: Some("こうあん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21829770000,
+        ended_at: Some(-21508362000),
+    },
+    Era {
+        kanji: Some("正応"),
+        romaji: Some("shouou"),
+        reading: Some("しょうおう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21508362000,
+        ended_at: Some(-21341955600),
+    },
+    Era {
+        kanji: Some("永仁"),
+        romaji: Some("einin"),
+        reading: Some("えいにん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21341955600,
+        ended_at: Some(-21161638800),
+    },
+    Era {
+        kanji: Some("正安"),
+        romaji: Some("shouan"),
+        reading: Some("しょうあん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21161638800,
+        ended_at: Some(-21049750800),
+    },
+    Era {
+        kanji: Some("乾元"),
+        romaji: Some("kengen"),
+        reading: Some("けんげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21049750800,
+        ended_at: Some(-21025558800),
+    },
+    Era {
+        kanji: Some("嘉元"),
+        romaji: Some("kagen"),
+        reading: Some("かげん"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -21025558800,
+        ended_at: Some(-20920150800),
+    },
+    Era {
+        kanji: Some("徳治"),
+        romaji: Some("tokuji"),
+        reading: Some("とくじ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20920150800,
+        ended_at: Some(-20861917200),
+    },
+    Era {
+        kanji: Some("延慶"),
+        romaji: Some("enkyou"),
+        reading: Some("えんきょう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20861917200,
+        ended_at: Some(-20783638800),
+    },
+    Era {
+        kanji: Some("応長"),
+        romaji: Some("ouchou"),
+        reading: Some("おうちょう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20783638800,
+        ended_at: Some(-20753744400),
+    },
+    Era {
+        kanji: Some("正和"),
+        romaji: Some("shouwa"),
+        reading: Some("しょうわ"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20753744400,
+        ended_at: Some(-20599606800),
+    },
+    Era {
+        kanji: Some("文保"),
+        romaji: Some("bumpou"),
+        reading: Some("ぶんぽう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20599606800,
+        ended_at: Some(-20531091600),
+    },
+    Era {
+        kanji: Some("元応"),
+        romaji: Some("gennou"),
+        reading: Some("げんおう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20531091600,
+        ended_at: Some(-20472858000),
+    },
+    Era {
+        kanji: Some("元亨"),
+        romaji: Some("gennkou"),
+        reading: Some("げんこう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20472858000,
+        ended_at: Some(-20354144400),
+    },
+    Era {
+        kanji: Some("正中"),
+        romaji: Some("shouchuu"),
+        reading: Some("しょうちゅう"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20354144400,
+        ended_at: Some(-20309302800),
+    },
+    Era {
+        kanji: Some("嘉暦"),
+        romaji: Some("karyaku"),
+        reading: Some("かりゃく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20309302800,
+        ended_at: Some(-20204499600),
+    },
+    Era {
+        kanji: Some("元徳"),
+        romaji: Some("gentoku"),
+        reading: Some("げんとく"),
+        gloss: None,
+        jidai: Jidai::Kamakura,
+        started_at: -20204499600,
+        ended_at: Some(-20120346000),
+    },
+    Era {
+        kanji: Some("正慶"),
+        romaji: Some("shoukyou"),
+        reading: Some("しょうきょう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -20120346000,
+        ended_at: Some(-20084922000),
+    },
+    // Small gap here
+    Era {
+        kanji: Some("建武"),
+        romaji: Some("kenmu"),
+        reading: Some("けんむ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -20064099600,
+        ended_at: Some(-19918861200),
+    },
+    Era {
+        kanji: Some("暦応"),
+        romaji: Some("ryakuou"),
+        reading: Some("りゃくおう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19918861200,
+        ended_at: Some(-19804035600),
+    },
+    Era {
+        kanji: Some("康永"),
+        romaji: Some("kouei"),
+        reading: Some("こうえい"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19804035600,
+        ended_at: Some(-19694912400),
+    },
+    Era {
+        kanji: Some("貞和"),
+        romaji: Some("jouwa"),
+        reading: Some("じょうわ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19694912400,
+        ended_at: Some(-19556586000),
+    },
+    Era {
+        kanji: Some("観応"),
+        romaji: Some("kannou"),
+        reading: Some("かんのう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19556586000,
+        ended_at: Some(-19474938000),
+    },
+    Era {
+        kanji: Some("文和"),
+        romaji: Some("bunna"),
+        reading: Some("ぶんな"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19474938000,
+        ended_at: Some(-19365037200),
+    },
+    Era {
+        kanji: Some("延文"),
+        romaji: Some("enbun"),
+        reading: Some("えんぶん"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19365037200,
+        ended_at: Some(-19206838800),
+    },
+    Era {
+        kanji: Some("康安"),
+        romaji: Some("kouan"),
+        reading: Some("こうあん"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19206838800,
+        ended_at: Some(-19161478800),
+    },
+    Era {
+        kanji: Some("貞治"),
+        romaji: Some("jouji"),
+        reading: Some("じょうじ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19161478800,
+        ended_at: Some(-18990925200),
+    },
+    Era {
+        kanji: Some("応安"),
+        romaji: Some("ouan"),
+        reading: Some("おうあん"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18990925200,
+        ended_at: Some(-18768186000),
+    },
+    Era {
+        kanji: Some("永和"),
+        romaji: Some("eiwa"),
+        reading: Some("えいわ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18768186000,
+        ended_at: Some(-18641005200),
+    },
+    Era {
+        kanji: Some("康暦"),
+        romaji: Some("kouryaku"),
+        reading: Some("こうりゃく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18641005200,
+        ended_at: Some(-18579574800),
+    },
+    Era {
+        kanji: Some("永徳"),
+        romaji: Some("eitoku"),
+        reading: Some("えいとく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18579574800,
+        ended_at: Some(-18484966800),
+    },
+    Era {
+        kanji: Some("至徳"),
+        romaji: Some("shitoku"),
+        reading: Some("しとく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18484966800,
+        ended_at: Some(-18373078800),
+    },
+    Era {
+        kanji: Some("嘉慶"),
+        romaji: Some("kakyou"),
+        reading: Some("かきょう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18373078800,
+        ended_at: Some(-18328928400),
+    },
+    Era {
+        kanji: Some("康応"),
+        romaji: Some("kouou"),
+        reading: Some("こうおう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18328928400,
+        ended_at: Some(-18294282000),
+    },
+    Era {
+        kanji: Some("明徳"),
+        romaji: Some("meitoku"),
+        reading: Some("めいとく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18294282000,
+        ended_at: Some(-18157683600),
+    },
+    Era {
+        kanji: Some("応永"),
+        romaji: Some("ouei"),
+        reading: Some("おうえい"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -18157683600,
+        ended_at: Some(-17089261200),
+    },
+    Era {
+        kanji: Some("正長"),
+        romaji: Some("shouchou"),
+        reading: Some("しょうちょう"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -17089261200,
+        ended_at: Some(-17047789200),
+    },
+    Era {
+        kanji: Some("永享"),
+        romaji: Some("eikyou"),
+        reading: Some("えいきょう"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -17047789200,
+        ended_at: Some(-16686982800),
+    },
+    Era {
+        kanji: Some("嘉吉"),
+        romaji: Some("kakitsu"),
+        reading: Some("かきつ"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16686982800,
+        ended_at: Some(-16593670800),
+    },
+    Era {
+        kanji: Some("文安"),
+        romaji: Some("bunnann"),
+        reading: Some("ぶんあん"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16593670800,
+        ended_at: Some(-16420784400),
+    },
+    Era {
+        kanji: Some("宝徳"),
+        romaji: Some("houtoku"),
+        reading: Some("ほうとく"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16420784400,
+        ended_at: Some(-16326608400),
+    },
+    Era {
+        kanji: Some("享徳"),
+        romaji: Some("kyoutoku"),
+        reading: Some("きょうとく"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16326608400,
+        ended_at: Some(-16229667600),
+    },
+    Era {
+        kanji: Some("康正"),
+        romaji: Some("koushou"),
+        reading: Some("こうしょう"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16229667600,
+        ended_at: Some(-16163053200),
+    },
+    Era {
+        kanji: Some("長禄"),
+        romaji: Some("chouroku"),
+        reading: Some("ちょうろく"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16163053200,
+        ended_at: Some(-16059027600),
+    },
+    Era {
+        kanji: Some("寛正"),
+        romaji: Some("kannshou"),
+        reading: Some("かんしょう"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -16059027600,
+        ended_at: Some(-15897718800),
+    },
+    Era {
+        kanji: Some("文正"),
+        romaji: Some("bunnshou"),
+        reading: Some("ぶんしょう"),
+        gloss: None,
+        jidai: Jidai::Muromachi,
+        started_at: -15897718800,
+        ended_at: Some(-15863936400),
+    },
+    Era {
+        kanji: Some("応仁"),
+        romaji: Some("ouninn"),
+        reading: Some("おうにん"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15863936400,
+        ended_at: Some(-15795594000),
+    },
+    Era {
+        kanji: Some("文明"),
+        romaji: Some("bunnmei"),
+        reading: Some("ぶんめい"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15795594000,
+        ended_at: Some(-15222243600),
+    },
+    Era {
+        kanji: Some("長享"),
+        romaji: Some("choukyou"),
+        reading: Some("ちょうきょう"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15222243600,
+        ended_at: Some(-15155802000),
+    },
+    Era {
+        kanji: Some("延徳"),
+        romaji: Some("entoku"),
+        reading: Some("えんとく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15155802000,
+        ended_at: Some(-15064131600),
+    },
+    Era {
+        kanji: Some("明応"),
+        romaji: Some("meiou"),
+        reading: Some("めいおう"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15064131600,
+        ended_at: Some(-14792835600),
+    },
+    Era {
+        kanji: Some("文亀"),
+        romaji: Some("bunnki"),
+        reading: Some("ぶんき"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -14792835600,
+        ended_at: Some(-14698314000),
+    },
+    Era {
+        kanji: Some("永正"),
+        romaji: Some("eishou"),
+        reading: Some("えいしょう"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -14698314000,
+        ended_at: Some(-14145354000),
+    },
+    Era {
+        kanji: Some("大永"),
+        romaji: Some("daiei"),
+        reading: Some("だいえい"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -14145354000,
+        ended_at: Some(-13926157200),
+    },
+    Era {
+        kanji: Some("享禄"),
+        romaji: Some("kyouroku"),
+        reading: Some("きょうろく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -13926157200,
+        ended_at: Some(-13800358800),
+    },
+    Era {
+        kanji: Some("天文"),
+        romaji: Some("tennbunn"),
+        reading: Some("てんぶん"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -13800358800,
+        ended_at: Some(-13068550800),
+    },
+    Era {
+        kanji: Some("弘治"),
+        romaji: Some("kouji"),
+        reading: Some("こうじ"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -13068550800,
+        ended_at: Some(-12994074000),
+    },
+    Era {
+        kanji: Some("永禄"),
+        romaji: Some("eiroku"),
+        reading: Some("えいろく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -12994074000,
+        ended_at: Some(-12609334800),
+    },
+    Era {
+        kanji: Some("元亀"),
+        romaji: Some("gennki"),
+        reading: Some("げんき"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -12609334800,
+        ended_at: Some(-12506864400),
+    },
+    Era {
+        kanji: Some("天正"),
+        romaji: Some("tennshou"),
+        reading: Some("てんしょう"),
+        gloss: None,
+        jidai: Jidai::AzuchiMomoyama,
+        started_at: -12506864400,
+        ended_at: Some(-11896189200),
+    },
+    Era {
+        kanji: Some("文禄"),
+        romaji: Some("bunnroku"),
+        reading: Some("ぶんろく"),
+        gloss: None,
+        jidai: Jidai::AzuchiMomoyama,
+        started_at: -11896189200,
+        ended_at: Some(-11772118800),
+    },
+    Era {
+        kanji: Some("慶長"),
+        romaji: Some("keichou"),
+        reading: Some("けいちょう"),
+        gloss: None,
+        jidai: Jidai::AzuchiMomoyama,
+        started_at: -11772118800,
+        ended_at: Some(-11181402000),
+    },
+    Era {
+        kanji: Some("元和"),
+        romaji: Some("genna"),
+        reading: Some("げんな"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -11181402000,
+        ended_at: Some(-10909501200),
+    },
+    Era {
+        kanji: Some("寛永"),
+        romaji: Some("kannei"),
+        reading: Some("かんえい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -10909501200,
+        ended_at: Some(-10254934800),
+    },
+    Era {
+        kanji: Some("正保"),
+        romaji: Some("shouhou"),
+        reading: Some("しょうほう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -10254934800,
+        ended_at: Some(-10152982800),
+    },
+    Era {
+        kanji: Some("慶安"),
+        romaji: Some("keian"),
+        reading: Some("けいあん"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -10152982800,
+        ended_at: Some(-10009818000),
+    },
+    Era {
+        kanji: Some("承応"),
+        romaji: Some("jouou"),
+        reading: Some("じょうおう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -10009818000,
+        ended_at: Some(-9928602000),
+    },
+    Era {
+        kanji: Some("明暦"),
+        romaji: Some("meireki"),
+        reading: Some("めいれき"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9928602000,
+        ended_at: Some(-9825699600),
+    },
+    Era {
+        kanji: Some("万治"),
+        romaji: Some("manji"),
+        reading: Some("まんじ"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9825699600,
+        ended_at: Some(-9738781200),
+    },
+    Era {
+        kanji: Some("寛文"),
+        romaji: Some("kannbunn"),
+        reading: Some("かんぶん"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9738781200,
+        ended_at: Some(-9346266000),
+    },
+    Era {
+        kanji: Some("延宝"),
+        romaji: Some("empou"),
+        reading: Some("えんぽう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9346266000,
+        ended_at: Some(-9092941200),
+    },
+    Era {
+        kanji: Some("天和"),
+        romaji: Some("tenna"),
+        reading: Some("てんな"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9092941200,
+        ended_at: Some(-9017082000),
+    },
+    Era {
+        kanji: Some("貞享"),
+        romaji: Some("joukyou"),
+        reading: Some("じょうきょう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -9017082000,
+        ended_at: Some(-8873485200),
+    },
+    Era {
+        kanji: Some("元禄"),
+        romaji: Some("genroku"),
+        reading: Some("げんろく"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -8873485200,
+        ended_at: Some(-8385066000),
+    },
+    Era {
+        kanji: Some("宝永"),
+        romaji: Some("houei"),
+        reading: Some("ほうえい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -8385066000,
+        ended_at: Some(-8159389200),
+    },
+    Era {
+        kanji: Some("正徳"),
+        romaji: Some("shoutoku"),
+        reading: Some("しょうとく"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -8159389200,
+        ended_at: Some(-7996438800),
+    },
+    Era {
+        kanji: Some("享保"),
+        romaji: Some("kyouhou"),
+        reading: Some("きょうほう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -7996438800,
+        ended_at: Some(-7370730000),
+    },
+    Era {
+        kanji: Some("元文"),
+        romaji: Some("gennbunn"),
+        reading: Some("げんぶん"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -7370730000,
+        ended_at: Some(-7217802000),
+    },
+    Era {
+        kanji: Some("寛保"),
+        romaji: Some("kampou"),
+        reading: Some("かんぽう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -7217802000,
+        ended_at: Some(-7123885200),
+    },
+    Era {
+        kanji: Some("延享"),
+        romaji: Some("enkyou"),
+        reading: Some("えんきょう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -7123885200,
+        ended_at: Some(-6986941200),
+    },
+    Era {
+        kanji: Some("寛延"),
+        romaji: Some("kannenn"),
+        reading: Some("かんえん"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -6986941200,
+        ended_at: Some(-6881014800),
+    },
+    Era {
+        kanji: Some("宝暦"),
+        romaji: Some("houreki"),
+        reading: Some("ほうれき"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -6881014800,
+        ended_at: Some(-6485130000),
+    },
+    Era {
+        kanji: Some("明和"),
+        romaji: Some("meiwa"),
+        reading: Some("めいわ"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -6485130000,
+        ended_at: Some(-6218586000),
+    },
+    Era {
+        kanji: Some("安永"),
+        romaji: Some("annei"),
+        reading: Some("あんえい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -6218586000,
+        ended_at: Some(-5954374800),
+    },
+    Era {
+        kanji: Some("天明"),
+        romaji: Some("tennmei"),
+        reading: Some("てんめい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -5954374800,
+        ended_at: Some(-5707530000),
+    },
+    Era {
+        kanji: Some("寛政"),
+        romaji: Some("kannsei"),
+        reading: Some("かんせい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -5707530000,
+        ended_at: Some(-5326506000),
+    },
+    Era {
+        kanji: Some("享和"),
+        romaji: Some("kyouwa"),
+        reading: Some("きょうわ"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -5326506000,
+        ended_at: Some(-5231552400),
+    },
+    Era {
+        kanji: Some("文化"),
+        romaji: Some("bunnka"),
+        reading: Some("ぶんか"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -5231552400,
+        ended_at: Some(-4784173200),
+    },
+    Era {
+        kanji: Some("文政"),
+        romaji: Some("bunnsei"),
+        reading: Some("ぶんせい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -4784173200,
+        ended_at: Some(-4384573200),
+    },
+    Era {
+        kanji: Some("天保"),
+        romaji: Some("tenmpou"),
+        reading: Some("てんぽう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -4384573200,
+        ended_at: Some(-3943933200),
+    },
+    Era {
+        kanji: Some("弘化"),
+        romaji: Some("kouka"),
+        reading: Some("こうか"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3943933200,
+        ended_at: Some(-3842154000),
+    },
+    Era {
+        kanji: Some("嘉永"),
+        romaji: Some("kaei"),
+        reading: Some("かえい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3842154000,
+        ended_at: Some(-3627882000),
+    },
+    Era {
+        kanji: Some("安政"),
+        romaji: Some("ansei"),
+        reading: Some("あんせい"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3627882000,
+        ended_at: Some(-3462858000),
+    },
+    Era {
+        kanji: Some("万延"),
+        romaji: Some("mannei"),
+        reading: Some("まんえん"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3462858000,
+        ended_at: Some(-3432186000),
+    },
+    Era {
+        kanji: Some("文久"),
+        romaji: Some("bunnkyuu"),
+        reading: Some("ぶんきゅう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3432186000,
+        ended_at: Some(-3337664400),
+    },
+    Era {
+        kanji: Some("元治"),
+        romaji: Some("genji"),
+        reading: Some("げんじ"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3337664400,
+        ended_at: Some(-3303104400),
+    },
+    Era {
+        kanji: Some("慶応"),
+        romaji: Some("keiou"),
+        reading: Some("けいおう"),
+        gloss: None,
+        jidai: Jidai::Edo,
+        started_at: -3303104400,
+        ended_at: Some(-3193290000),
+    },
+    Era {
+        kanji: Some("明治"),
+        romaji: Some("meiji"),
+        reading: Some("めいじ"),
+        gloss: Some("enlightened rule"),
+        jidai: Jidai::Modern,
+        started_at: -3193290000,
+        ended_at: Some(-1812186000),
+    },
+    Era {
+        kanji: Some("大正"),
+        romaji: Some("taishou"),
+        reading: Some("たいしょう"),
+        gloss: Some("great righteousness"),
+        jidai: Jidai::Modern,
+        started_at: -1812186000,
+        ended_at: Some(-1357635600),
+    },
+    Era {
+        kanji: Some("昭和"),
+        romaji: Some("shouwa"),
+        reading: Some("しょうわ"),
+        gloss: Some("enlightened peace"),
+        jidai: Jidai::Modern,
+        started_at: -1357635600,
+        ended_at: Some(600188400),
+    },
+    Era {
+        kanji: Some("平成"),
+        romaji: Some("heisei"),
+        reading: Some("へいせい"),
+        gloss: Some("peace everywhere"),
+        jidai: Jidai::Modern,
+        started_at: 600188400,
+        ended_at: Some(1556636400),
+    },
+    Era {
+        kanji: Some("令和"),
+        romaji: Some("reiwa"),
+        reading: Some("れいわ"),
+        gloss: Some("beautiful harmony"),
+        jidai: Jidai::Modern,
+        started_at: 1556636400,
+        ended_at: None,
+    },
+];
+
+/// The eras of the Southern Court (南朝) during the Nanboku-chō period, from
+/// Genkō, which the Southern Court kept after the Northern Court moved on to
+/// Shōkyō, to the reunification in 1392. Kenmu, which both courts used at
+/// first, is only in `SORTED_ERAS`.
+pub const SOUTHERN_COURT_ERAS: &[Era] = &[
+    Era {
+        kanji: Some("元弘"),
+        romaji: Some("genkou"),
+        reading: Some("げんこう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -20142378000,
+        ended_at: Some(-20064099600),
+    },
+    Era {
+        kanji: Some("延元"),
+        romaji: Some("engen"),
+        reading: Some("えんげん"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19997744400,
+        ended_at: Some(-19867712400),
+    },
+    Era {
+        kanji: Some("興国"),
+        romaji: Some("koukoku"),
+        reading: Some("こうこく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19867712400,
+        ended_at: Some(-19657674000),
+    },
+    Era {
+        kanji: Some("正平"),
+        romaji: Some("shouhei"),
+        reading: Some("しょうへい"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -19657674000,
+        ended_at: Some(-18913856400),
+    },
+    Era {
+        kanji: Some("建徳"),
+        romaji: Some("kentoku"),
+        reading: Some("けんとく"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18913856400,
+        ended_at: Some(-18859683600),
+    },
+    Era {
+        kanji: Some("文中"),
+        romaji: Some("bunchuu"),
+        reading: Some("ぶんちゅう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18859683600,
+        ended_at: Some(-18760496400),
+    },
+    Era {
+        kanji: Some("天授"),
+        romaji: Some("tenju"),
+        reading: Some("てんじゅ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18760496400,
+        ended_at: Some(-18580784400),
+    },
+    Era {
+        kanji: Some("弘和"),
+        romaji: Some("kouwa"),
+        reading: Some("こうわ"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18580784400,
+        ended_at: Some(-18479782800),
+    },
+    Era {
+        kanji: Some("元中"),
+        romaji: Some("genchuu"),
+        reading: Some("げんちゅう"),
+        gloss: None,
+        jidai: Jidai::Nanbokuchou,
+        started_at: -18479782800,
+        ended_at: Some(-18211338000),
+    },
+];
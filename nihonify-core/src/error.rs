@@ -0,0 +1,136 @@
+use chrono::{Datelike, NaiveDate};
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// The errors returned by the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NihonifyError {
+    /// The date falls before Taika, the first era in the era table.
+    BeforeEraSystem {
+        /// The earliest date the era table covers.
+        earliest: NaiveDate,
+    },
+    /// The date is outside of the supported range, e.g. past the year 9999,
+    /// or outside of the era it was given in.
+    DateOutOfRange,
+    /// The era name doesn't match any era in the era table.
+    UnknownEra,
+    /// The date falls in one of the unnamed gaps between eras of the Asuka
+    /// period, or in the gap between Shōkyō and Kenmu in 1333, so it has no
+    /// era name to render.
+    UnnamedEra,
+    /// The input couldn't be parsed as a date.
+    ParseError,
+}
+
+/// The language that error messages are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Japanese,
+    English,
+}
+
+static DEFAULT_LANG: AtomicU8 = AtomicU8::new(Lang::Japanese as u8);
+
+/// Sets the process-wide language used by `NihonifyError`'s `Display`.
+pub fn set_default_lang(lang: Lang) {
+    DEFAULT_LANG.store(lang as u8, Ordering::Relaxed);
+}
+
+/// The process-wide language used by `NihonifyError`'s `Display`. Japanese
+/// unless changed with `set_default_lang`.
+pub fn default_lang() -> Lang {
+    match DEFAULT_LANG.load(Ordering::Relaxed) {
+        x if x == Lang::English as u8 => Lang::English,
+        _ => Lang::Japanese,
+    }
+}
+
+impl NihonifyError {
+    /// Renders the error in `lang`, regardless of the process-wide default.
+    pub fn localized(&self, lang: Lang) -> Localized {
+        Localized { error: *self, lang }
+    }
+
+    fn write(&self, lang: Lang, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self, lang) {
+            (NihonifyError::BeforeEraSystem { earliest }, Lang::Japanese) => write!(
+                f,
+                "元号が始まる前の日付です（対応する最も古い日付は{}年{}月{}日です）",
+                earliest.year(),
+                earliest.month(),
+                earliest.day()
+            ),
+            (NihonifyError::BeforeEraSystem { earliest }, Lang::English) => write!(
+                f,
+                "date is before the era system (the earliest supported date is {})",
+                earliest
+            ),
+            (NihonifyError::DateOutOfRange, Lang::Japanese) => f.write_str("日付が対応範囲外です"),
+            (NihonifyError::DateOutOfRange, Lang::English) => f.write_str("date out of range"),
+            (NihonifyError::UnknownEra, Lang::Japanese) => f.write_str("不明な元号です"),
+            (NihonifyError::UnknownEra, Lang::English) => f.write_str("unknown era"),
+            (NihonifyError::UnnamedEra, Lang::Japanese) => f.write_str("元号のない期間の日付です"),
+            (NihonifyError::UnnamedEra, Lang::English) => {
+                f.write_str("date falls in a period without an era name")
+            }
+            (NihonifyError::ParseError, Lang::Japanese) => f.write_str("日付を解析できません"),
+            (NihonifyError::ParseError, Lang::English) => f.write_str("could not parse the date"),
+        }
+    }
+}
+
+impl fmt::Display for NihonifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(default_lang(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NihonifyError {}
+
+/// A `NihonifyError` rendered in a specific language.
+#[derive(Debug, Clone, Copy)]
+pub struct Localized {
+    error: NihonifyError,
+    lang: Lang,
+}
+
+impl fmt::Display for Localized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.write(self.lang, f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localized() {
+        assert_eq!(
+            NihonifyError::UnknownEra
+                .localized(Lang::English)
+                .to_string(),
+            "unknown era"
+        );
+        assert_eq!(
+            NihonifyError::UnknownEra
+                .localized(Lang::Japanese)
+                .to_string(),
+            "不明な元号です"
+        );
+
+        let error = NihonifyError::BeforeEraSystem {
+            earliest: NaiveDate::from_ymd_opt(645, 7, 20).unwrap(),
+        };
+        assert_eq!(
+            error.localized(Lang::Japanese).to_string(),
+            "元号が始まる前の日付です（対応する最も古い日付は645年7月20日です）"
+        );
+        assert_eq!(
+            error.localized(Lang::English).to_string(),
+            "date is before the era system (the earliest supported date is 0645-07-20)"
+        );
+    }
+}
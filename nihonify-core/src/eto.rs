@@ -0,0 +1,259 @@
+//! The eto (干支), the sexagenary cycle that names years by pairing one of the
+//! ten heavenly stems (十干) with one of the twelve zodiac animals (十二支),
+//! as on nengajō: 2021 was 辛丑, the year of the ox.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+/// One of the ten heavenly stems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jikkan {
+    /// 甲, wood elder brother.
+    Kinoe,
+    /// 乙, wood younger brother.
+    Kinoto,
+    /// 丙, fire elder brother.
+    Hinoe,
+    /// 丁, fire younger brother.
+    Hinoto,
+    /// 戊, earth elder brother.
+    Tsuchinoe,
+    /// 己, earth younger brother.
+    Tsuchinoto,
+    /// 庚, metal elder brother.
+    Kanoe,
+    /// 辛, metal younger brother.
+    Kanoto,
+    /// 壬, water elder brother.
+    Mizunoe,
+    /// 癸, water younger brother.
+    Mizunoto,
+}
+
+const JIKKAN: [Jikkan; 10] = [
+    Jikkan::Kinoe,
+    Jikkan::Kinoto,
+    Jikkan::Hinoe,
+    Jikkan::Hinoto,
+    Jikkan::Tsuchinoe,
+    Jikkan::Tsuchinoto,
+    Jikkan::Kanoe,
+    Jikkan::Kanoto,
+    Jikkan::Mizunoe,
+    Jikkan::Mizunoto,
+];
+
+impl Jikkan {
+    /// The stem's kanji, e.g. 辛.
+    pub fn kanji(&self) -> char {
+        ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'][*self as usize]
+    }
+
+    /// The stem's name in hiragana, e.g. かのと.
+    pub fn reading(&self) -> &'static str {
+        [
+            "きのえ",
+            "きのと",
+            "ひのえ",
+            "ひのと",
+            "つちのえ",
+            "つちのと",
+            "かのえ",
+            "かのと",
+            "みずのえ",
+            "みずのと",
+        ][*self as usize]
+    }
+
+    /// The stem's name in romaji, e.g. kanoto.
+    pub fn romaji(&self) -> &'static str {
+        [
+            "kinoe",
+            "kinoto",
+            "hinoe",
+            "hinoto",
+            "tsuchinoe",
+            "tsuchinoto",
+            "kanoe",
+            "kanoto",
+            "mizunoe",
+            "mizunoto",
+        ][*self as usize]
+    }
+}
+
+/// One of the twelve zodiac animals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Juunishi {
+    /// 子, the rat.
+    Ne,
+    /// 丑, the ox.
+    Ushi,
+    /// 寅, the tiger.
+    Tora,
+    /// 卯, the rabbit.
+    U,
+    /// 辰, the dragon.
+    Tatsu,
+    /// 巳, the snake.
+    Mi,
+    /// 午, the horse.
+    Uma,
+    /// 未, the sheep.
+    Hitsuji,
+    /// 申, the monkey.
+    Saru,
+    /// 酉, the rooster.
+    Tori,
+    /// 戌, the dog.
+    Inu,
+    /// 亥, the boar.
+    I,
+}
+
+const JUUNISHI: [Juunishi; 12] = [
+    Juunishi::Ne,
+    Juunishi::Ushi,
+    Juunishi::Tora,
+    Juunishi::U,
+    Juunishi::Tatsu,
+    Juunishi::Mi,
+    Juunishi::Uma,
+    Juunishi::Hitsuji,
+    Juunishi::Saru,
+    Juunishi::Tori,
+    Juunishi::Inu,
+    Juunishi::I,
+];
+
+impl Juunishi {
+    /// The animal's kanji in the cycle, e.g. 丑.
+    pub fn kanji(&self) -> char {
+        [
+            '子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥',
+        ][*self as usize]
+    }
+
+    /// The animal's name in hiragana, e.g. うし.
+    pub fn reading(&self) -> &'static str {
+        [
+            "ね",
+            "うし",
+            "とら",
+            "う",
+            "たつ",
+            "み",
+            "うま",
+            "ひつじ",
+            "さる",
+            "とり",
+            "いぬ",
+            "い",
+        ][*self as usize]
+    }
+
+    /// The animal's name in romaji, e.g. ushi.
+    pub fn romaji(&self) -> &'static str {
+        [
+            "ne", "ushi", "tora", "u", "tatsu", "mi", "uma", "hitsuji", "saru", "tori", "inu", "i",
+        ][*self as usize]
+    }
+
+    /// The animal in English, e.g. ox.
+    pub fn animal(&self) -> &'static str {
+        [
+            "rat", "ox", "tiger", "rabbit", "dragon", "snake", "horse", "sheep", "monkey",
+            "rooster", "dog", "boar",
+        ][*self as usize]
+    }
+}
+
+/// A year's place in the sexagenary cycle. Displays as its kanji, e.g. 辛丑.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eto {
+    pub stem: Jikkan,
+    pub branch: Juunishi,
+}
+
+impl Eto {
+    /// The position in the cycle, from 1 for 甲子 to 60 for 癸亥.
+    pub fn number(&self) -> u8 {
+        let (stem, branch) = (self.stem as i8, self.branch as i8);
+        // The n with n ≡ stem (mod 10) and n ≡ branch (mod 12).
+        (6 * stem - 5 * branch).rem_euclid(60) as u8 + 1
+    }
+
+    /// The pair in kanji, e.g. 辛丑.
+    #[cfg(feature = "alloc")]
+    pub fn kanji(&self) -> String {
+        [self.stem.kanji(), self.branch.kanji()].iter().collect()
+    }
+
+    /// The pair in hiragana, e.g. かのとうし.
+    #[cfg(feature = "alloc")]
+    pub fn reading(&self) -> String {
+        [self.stem.reading(), self.branch.reading()].concat()
+    }
+
+    /// The pair in romaji, e.g. kanoto-ushi.
+    #[cfg(feature = "alloc")]
+    pub fn romaji(&self) -> String {
+        [self.stem.romaji(), "-", self.branch.romaji()].concat()
+    }
+}
+
+impl fmt::Display for Eto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.stem.kanji(), self.branch.kanji())
+    }
+}
+
+/// The eto of a Gregorian year, as used for New Year: 4 CE was 甲子, the
+/// start of a cycle. Historically the eto changed at the lunisolar new year
+/// or at 立春, so early-year dates before that belong to the previous one.
+pub fn eto(year: i32) -> Eto {
+    let n = (i64::from(year) - 4).rem_euclid(60) as usize;
+    Eto {
+        stem: JIKKAN[n % 10],
+        branch: JUUNISHI[n % 12],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eto() {
+        let year = eto(2021);
+        assert_eq!(year.stem, Jikkan::Kanoto);
+        assert_eq!(year.branch, Juunishi::Ushi);
+        assert_eq!(year.branch.animal(), "ox");
+        assert_eq!(year.number(), 38);
+        assert_eq!(eto(1984).number(), 1);
+        assert_eq!(eto(2043).number(), 60);
+        assert_eq!(eto(4), eto(-56));
+        // 甲子園 was named for its year.
+        assert_eq!(eto(1924), eto(1984));
+        assert_eq!(eto(1868).branch, Juunishi::Tatsu);
+        // The extremes don't overflow: i32::MIN is -8 and i32::MAX is 7, mod 60.
+        assert_eq!(eto(i32::MIN), eto(-8));
+        assert_eq!(eto(i32::MAX), eto(7));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_renderings() {
+        use alloc::string::ToString;
+        let year = eto(2021);
+        assert_eq!(year.kanji(), "辛丑");
+        assert_eq!(year.to_string(), "辛丑");
+        assert_eq!(year.reading(), "かのとうし");
+        assert_eq!(year.romaji(), "kanoto-ushi");
+        assert_eq!(eto(2024).kanji(), "甲辰");
+        assert_eq!(eto(2024).reading(), "きのえたつ");
+        // 戊辰戦争 began in 1868.
+        assert_eq!(eto(1868).kanji(), "戊辰");
+    }
+}
@@ -0,0 +1,172 @@
+//! Excel (and Lotus 1-2-3) serial dates: the number of days since the
+//! spreadsheet's epoch, with the time of day as the fraction.
+
+use crate::calendar::Era;
+use crate::error::NihonifyError;
+use crate::parse::parse_wareki_prefix;
+use alloc::string::String;
+use chrono::prelude::*;
+use chrono::Duration;
+
+/// The date systems spreadsheets count serials in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExcelDateSystem {
+    /// Serial 1 is 1900-01-01. Like Lotus 1-2-3, it counts a 1900-02-29 that
+    /// never was as serial 60, so later serials are one day ahead.
+    Excel1900,
+    /// Serial 0 is 1904-01-01, as in older Mac versions of Excel.
+    Excel1904,
+}
+
+/// The datetime of a serial, or `None` for serials before the system's first
+/// day and for the phantom 1900-02-29.
+pub fn from_excel_serial(serial: f64, system: ExcelDateSystem) -> Option<NaiveDateTime> {
+    if !serial.is_finite() || serial < 0.0 {
+        return None;
+    }
+
+    // Without std there's no `f64::trunc`, but the serial isn't negative.
+    let days = serial as i64;
+    let day_zero = match system {
+        ExcelDateSystem::Excel1900 => match days {
+            0 | 60 => return None,
+            1..=59 => NaiveDate::from_ymd_opt(1899, 12, 31)?,
+            _ => NaiveDate::from_ymd_opt(1899, 12, 30)?,
+        },
+        ExcelDateSystem::Excel1904 => NaiveDate::from_ymd_opt(1904, 1, 1)?,
+    };
+    let seconds = ((serial - days as f64) * 86400.0 + 0.5) as i64;
+
+    day_zero
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(Duration::try_days(days)? + Duration::try_seconds(seconds)?)
+}
+
+/// The serial of a datetime, or `None` if it's before the system's first day.
+pub fn to_excel_serial(datetime: NaiveDateTime, system: ExcelDateSystem) -> Option<f64> {
+    let (day_zero, skip_from) = match system {
+        ExcelDateSystem::Excel1900 => (NaiveDate::from_ymd_opt(1899, 12, 31)?, Some(60)),
+        ExcelDateSystem::Excel1904 => (NaiveDate::from_ymd_opt(1904, 1, 1)?, None),
+    };
+
+    let mut days = (datetime.date() - day_zero).num_days();
+    if days < 0 || (days == 0 && skip_from.is_some()) {
+        return None;
+    }
+    if skip_from.is_some_and(|skip_from| days >= skip_from) {
+        days += 1;
+    }
+
+    let seconds = datetime.num_seconds_from_midnight() as f64;
+    Some(days as f64 + seconds / 86400.0)
+}
+
+/// Renders the date of a serial as a nenkou string, e.g. 令和３年１１月１２日
+/// for 44512 in the 1900 system.
+pub fn excel_serial_to_jp_nenkou_string(serial: f64, system: ExcelDateSystem) -> Option<String> {
+    // Spreadsheets hold local times, so only the date is kept.
+    let date = from_excel_serial(serial, system)?.date();
+    Era::to_jp_nenkou_string(date.and_time(NaiveTime::MIN).and_utc())
+}
+
+/// The serial of a wareki date, e.g. 44512 for 令和3年11月12日 in the 1900
+/// system.
+pub fn wareki_to_excel_serial(s: &str, system: ExcelDateSystem) -> Result<f64, NihonifyError> {
+    let date = match parse_wareki_prefix(s.trim()) {
+        Some((date, len)) if len == s.trim().len() => date,
+        _ => return Err(NihonifyError::ParseError),
+    };
+
+    to_excel_serial(date.and_hms_opt(0, 0, 0).unwrap(), system).ok_or(NihonifyError::DateOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(y: i32, m: u32, d: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_from_excel_serial() {
+        use ExcelDateSystem::*;
+        assert_eq!(
+            from_excel_serial(1.0, Excel1900),
+            Some(datetime(1900, 1, 1, 0))
+        );
+        assert_eq!(
+            from_excel_serial(59.0, Excel1900),
+            Some(datetime(1900, 2, 28, 0))
+        );
+        assert_eq!(from_excel_serial(60.0, Excel1900), None);
+        assert_eq!(
+            from_excel_serial(61.0, Excel1900),
+            Some(datetime(1900, 3, 1, 0))
+        );
+        assert_eq!(
+            from_excel_serial(44512.5, Excel1900),
+            Some(datetime(2021, 11, 12, 12))
+        );
+        assert_eq!(
+            from_excel_serial(0.0, Excel1904),
+            Some(datetime(1904, 1, 1, 0))
+        );
+        assert_eq!(
+            from_excel_serial(43050.0, Excel1904),
+            Some(datetime(2021, 11, 12, 0))
+        );
+        assert_eq!(from_excel_serial(-1.0, Excel1900), None);
+        assert_eq!(from_excel_serial(f64::NAN, Excel1900), None);
+    }
+
+    #[test]
+    fn test_to_excel_serial() {
+        use ExcelDateSystem::*;
+        assert_eq!(
+            to_excel_serial(datetime(1900, 2, 28, 0), Excel1900),
+            Some(59.0)
+        );
+        assert_eq!(
+            to_excel_serial(datetime(1900, 3, 1, 0), Excel1900),
+            Some(61.0)
+        );
+        assert_eq!(
+            to_excel_serial(datetime(2021, 11, 12, 18), Excel1900),
+            Some(44512.75)
+        );
+        assert_eq!(
+            to_excel_serial(datetime(2021, 11, 12, 0), Excel1904),
+            Some(43050.0)
+        );
+        assert_eq!(to_excel_serial(datetime(1899, 12, 31, 0), Excel1900), None);
+    }
+
+    #[test]
+    fn test_wareki() {
+        use ExcelDateSystem::*;
+        assert_eq!(
+            excel_serial_to_jp_nenkou_string(44512.0, Excel1900),
+            Some(String::from("令和３年１１月１２日"))
+        );
+        assert_eq!(
+            wareki_to_excel_serial("令和3年11月12日", Excel1900),
+            Ok(44512.0)
+        );
+        assert_eq!(
+            wareki_to_excel_serial("令和3年11月12日", Excel1904),
+            Ok(43050.0)
+        );
+        assert_eq!(
+            wareki_to_excel_serial("明治3年1月1日", Excel1900),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            wareki_to_excel_serial("2021-11-12", Excel1900),
+            Err(NihonifyError::ParseError)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! The `Nihonify` extension trait, for calling the era lookups and wareki
+//! formatting as methods on chrono's types:
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use nihonify_core::prelude::*;
+//!
+//! let date = NaiveDate::from_ymd_opt(2021, 11, 12).unwrap();
+//! assert_eq!(date.era().unwrap().kanji, Some("令和"));
+//! assert_eq!(date.wareki_year(), Some(3));
+//! assert_eq!(date.to_wareki_string().unwrap(), "令和３年１１月１２日");
+//! ```
+
+use crate::calendar::{Era, WarekiDate};
+use crate::error::NihonifyError;
+use alloc::string::String;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+
+/// Era lookups and wareki formatting for dates. A `NaiveDate` is taken as
+/// midnight UTC, like everywhere else in the crate.
+pub trait Nihonify {
+    /// The era in use at the time.
+    fn era(&self) -> Option<&'static Era>;
+
+    /// The year of the era, counting from 1.
+    fn wareki_year(&self) -> Option<u32>;
+
+    /// The wareki date of the day.
+    fn to_wareki(&self) -> Result<WarekiDate, NihonifyError>;
+
+    /// The nenkou datestring, as `Era::to_jp_nenkou_string` renders it.
+    fn to_wareki_string(&self) -> Option<String>;
+}
+
+impl Nihonify for DateTime<Utc> {
+    fn era(&self) -> Option<&'static Era> {
+        Era::from_datetime(*self)
+    }
+
+    fn wareki_year(&self) -> Option<u32> {
+        self.era().map(|era| era.year_of(*self))
+    }
+
+    fn to_wareki(&self) -> Result<WarekiDate, NihonifyError> {
+        WarekiDate::try_from(*self)
+    }
+
+    fn to_wareki_string(&self) -> Option<String> {
+        Era::to_jp_nenkou_string(*self)
+    }
+}
+
+impl Nihonify for NaiveDate {
+    fn era(&self) -> Option<&'static Era> {
+        midnight_utc(*self).era()
+    }
+
+    fn wareki_year(&self) -> Option<u32> {
+        midnight_utc(*self).wareki_year()
+    }
+
+    fn to_wareki(&self) -> Result<WarekiDate, NihonifyError> {
+        WarekiDate::try_from(*self)
+    }
+
+    fn to_wareki_string(&self) -> Option<String> {
+        midnight_utc(*self).to_wareki_string()
+    }
+}
+
+fn midnight_utc(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::utc_dt;
+
+    #[test]
+    fn test_datetime() {
+        let date = utc_dt("2019-05-01");
+        assert_eq!(date.era().unwrap().romaji, Some("reiwa"));
+        assert_eq!(date.wareki_year(), Some(1));
+        assert_eq!(date.to_wareki_string().unwrap(), "令和元年５月１日");
+        assert_eq!(date.to_wareki().unwrap().year(), 1);
+        assert_eq!(utc_dt("0600-01-01").wareki_year(), None);
+    }
+
+    #[test]
+    fn test_naive_date() {
+        let date = NaiveDate::from_ymd_opt(1989, 1, 7).unwrap();
+        assert_eq!(date.era().unwrap().kanji, Some("昭和"));
+        assert_eq!(date.wareki_year(), Some(64));
+        assert_eq!(date.to_wareki_string().unwrap(), "昭和６４年１月７日");
+        assert_eq!(
+            NaiveDate::from_ymd_opt(600, 1, 1).unwrap().to_wareki(),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+}
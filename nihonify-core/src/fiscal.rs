@@ -0,0 +1,83 @@
+//! The Japanese fiscal year (年度), which runs from April 1st to March 31st
+//! and is named for the calendar year it starts in: 2021年度 ends on
+//! 2022-03-31. Government, company accounts and the school year all go by it.
+
+use crate::calendar::DateRange;
+#[cfg(feature = "alloc")]
+use crate::{error::NihonifyError, format::format_wareki};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use chrono::prelude::*;
+
+/// The fiscal year the date falls in, e.g. 2021 for 2022-03-31.
+pub fn fiscal_year(date: NaiveDate) -> i32 {
+    if date.month() < 4 {
+        date.year() - 1
+    } else {
+        date.year()
+    }
+}
+
+/// The fiscal year the date falls in, by its wareki name, e.g. 令和３年度.
+/// A year the era changed in is named as the calendar year it starts in
+/// ended, so 2019年度 is 令和元年度 and 1988年度 is 昭和６３年度.
+#[cfg(feature = "alloc")]
+pub fn fiscal_year_wareki(date: NaiveDate) -> Result<String, NihonifyError> {
+    let year_end =
+        NaiveDate::from_ymd_opt(fiscal_year(date), 12, 31).ok_or(NihonifyError::DateOutOfRange)?;
+    format_wareki(year_end.and_time(NaiveTime::MIN).and_utc(), "%JE%JG年度")
+}
+
+/// The days of the fiscal year, from April 1st to the next March 31st.
+pub fn fiscal_year_range(year: i32) -> Option<DateRange> {
+    DateRange::new(
+        NaiveDate::from_ymd_opt(year, 4, 1)?,
+        NaiveDate::from_ymd_opt(year + 1, 3, 31)?,
+    )
+}
+
+/// The school year (学校年度) the date falls in. Schools follow the fiscal
+/// year, so this is the same as `fiscal_year`.
+pub fn school_year(date: NaiveDate) -> i32 {
+    fiscal_year(date)
+}
+
+/// The days of the school year, from April 1st to the next March 31st.
+pub fn school_year_range(year: i32) -> Option<DateRange> {
+    fiscal_year_range(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_fiscal_year() {
+        assert_eq!(fiscal_year(ymd(2022, 3, 31)), 2021);
+        assert_eq!(fiscal_year(ymd(2022, 4, 1)), 2022);
+        assert_eq!(fiscal_year(ymd(2021, 12, 31)), 2021);
+        assert_eq!(school_year(ymd(2022, 1, 10)), 2021);
+
+        let range = fiscal_year_range(2021).unwrap();
+        assert_eq!(range.first, ymd(2021, 4, 1));
+        assert_eq!(range.last, ymd(2022, 3, 31));
+        assert_eq!(range.days(), 365);
+        assert_eq!(fiscal_year_range(2023).unwrap().days(), 366);
+        assert_eq!(school_year_range(2021), Some(range));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_fiscal_year_wareki() {
+        let wareki = |y, m, d| fiscal_year_wareki(ymd(y, m, d)).unwrap();
+        assert_eq!(wareki(2022, 3, 31), "令和３年度");
+        assert_eq!(wareki(2019, 4, 1), "令和元年度");
+        assert_eq!(wareki(2020, 3, 31), "令和元年度");
+        assert_eq!(wareki(1989, 3, 31), "昭和６３年度");
+        assert_eq!(wareki(1989, 4, 1), "平成元年度");
+    }
+}
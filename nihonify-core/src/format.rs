@@ -0,0 +1,1114 @@
+#[cfg(feature = "std")]
+use crate::calendar::SystemClock;
+use crate::calendar::{jst_date, Clock, Era, Jidai, JST_OFFSET};
+use crate::error::NihonifyError;
+use crate::lunisolar::{self, LunisolarDate};
+use crate::numbers::{to_daiji_numeral, to_full_width_digits, to_jp_intstring, to_kanji_numeral};
+use crate::weeks::weekday_kanji;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::fmt::Write;
+
+impl Era {
+    /// Given a datetime, returns the nenkou datestring, e.g.
+    /// 令和３年１１月１２日. The first year of an era is written 元年.
+    pub fn to_jp_nenkou_string(date: DateTime<Utc>) -> Option<String> {
+        NenkouFormatter::new().format(date)
+    }
+
+    /// Today's date as a nenkou datestring, e.g. 令和３年１１月１２日, as
+    /// `try_to_jp_nenkou_string`.
+    #[cfg(feature = "std")]
+    pub fn to_jp_nenkou_string_now() -> Result<String, NihonifyError> {
+        Era::to_jp_nenkou_string_now_with(&SystemClock)
+    }
+
+    /// Like `to_jp_nenkou_string_now`, for the date `clock` reports.
+    pub fn to_jp_nenkou_string_now_with<C: Clock>(clock: &C) -> Result<String, NihonifyError> {
+        Era::try_to_jp_nenkou_string(clock.now())
+    }
+
+    /// Like `to_jp_nenkou_string`, but explains why there's no nenkou string.
+    pub fn try_to_jp_nenkou_string(date: DateTime<Utc>) -> Result<String, NihonifyError> {
+        NenkouFormatter::new().try_format(date)
+    }
+
+    /// Like `to_jp_nenkou_string`, but dates before the calendar reform of
+    /// 1873 are written in the lunisolar calendar then in use, e.g.
+    /// 慶応３年１０月１４日 for 1867-11-09. Dates before 1590 aren't covered.
+    pub fn to_jp_nenkou_string_lunisolar(date: DateTime<Utc>) -> Option<String> {
+        NenkouFormatter::new().lunisolar(true).format(date)
+    }
+
+    /// Like `to_jp_nenkou_string`, but in ASCII digits as the web and most
+    /// databases write it, e.g. 令和3年11月12日.
+    pub fn to_jp_nenkou_string_ascii(date: DateTime<Utc>) -> Option<String> {
+        NenkouFormatter::new()
+            .numerals(Numerals::Ascii)
+            .format(date)
+    }
+
+    /// Like `to_jp_nenkou_string`, but in kanji numerals as official
+    /// documents write it, e.g. 令和三年十一月十二日.
+    pub fn to_jp_nenkou_string_kanji(date: DateTime<Utc>) -> Option<String> {
+        NenkouFormatter::new()
+            .numerals(Numerals::Kanji)
+            .format(date)
+    }
+
+    /// Like `to_jp_nenkou_string`, but dates that no named era covers (those
+    /// before Taika or past the year 9999, and the unnamed gaps of the Asuka
+    /// period) are rendered according to `fallback` instead.
+    pub fn to_jp_nenkou_string_or(
+        date: DateTime<Utc>,
+        fallback: Fallback,
+    ) -> Result<String, NihonifyError> {
+        let era = match Era::try_from_datetime(date) {
+            Ok(era) => Some(era),
+            Err(NihonifyError::BeforeEraSystem { .. }) | Err(NihonifyError::UnnamedEra) => None,
+            Err(e) if fallback != Fallback::Gregorian => return Err(e),
+            Err(_) => None,
+        };
+        if let Some(nenkou) = era.and_then(|_| Era::to_jp_nenkou_string(date)) {
+            return Ok(nenkou);
+        }
+
+        match (fallback, era) {
+            (Fallback::BeforeTaika, _) if jst_date(date) < Era::earliest_supported_date() => {
+                Ok(format!(
+                    "西暦{}年以前",
+                    to_jp_intstring(Era::earliest_supported_date().year() as u32)
+                ))
+            }
+            (Fallback::Gregorian, _) | (Fallback::BeforeTaika, _) => {
+                Ok(to_jp_seireki_string(jst_date(date)))
+            }
+            (Fallback::Jidai, Some(era)) => Ok(era.jidai.kanji().to_owned()),
+            // The gap between Shōkyō and Kenmu takes the period of the era
+            // before it.
+            (Fallback::Jidai, None) => match Era::last_started_by(date.timestamp()) {
+                Some(era) => Ok(era.jidai.kanji().to_owned()),
+                None if jst_date(date).year() >= ASUKA_STARTED_IN => {
+                    Ok(Jidai::Asuka.kanji().to_owned())
+                }
+                None => Err(NihonifyError::BeforeEraSystem {
+                    earliest: Era::earliest_supported_date(),
+                }),
+            },
+        }
+    }
+
+    /// Given a datetime, returns the nenkou datestring in one of the English
+    /// presets, e.g. "Reiwa 3, November 12, 2021" or "12 Nov R3".
+    pub fn to_en_nenkou_string(
+        date: DateTime<Utc>,
+        preset: EnglishPreset,
+        style: RomanizationStyle,
+    ) -> Option<String> {
+        let era = Era::from_datetime(date)?;
+        let name = era.romaji_with(style)?;
+        let year = era.year_of(date);
+        let date = jst_date(date);
+
+        Some(match preset {
+            EnglishPreset::Long => format!(
+                "{} {}, {} {}, {}",
+                name,
+                year,
+                EN_MONTHS[date.month0() as usize],
+                date.day(),
+                date.year()
+            ),
+            EnglishPreset::Short => format!(
+                "{} {} {}{}",
+                date.day(),
+                &EN_MONTHS[date.month0() as usize][..3],
+                name.chars().next()?,
+                year
+            ),
+        })
+    }
+
+    /// Given a datetime, returns the date in the JIS X 0301 representation,
+    /// e.g. R03.11.12, or 5031112 with the era's number. Only the modern eras,
+    /// Meiji through Reiwa, are covered.
+    pub fn to_jis_x0301_string(date: DateTime<Utc>, code: JisEraCode) -> Option<String> {
+        let era = Era::from_datetime(date)?;
+        let year = era.year_of(date);
+        let date = jst_date(date);
+        Some(match code {
+            JisEraCode::Letter => format!(
+                "{}{:02}.{:02}.{:02}",
+                era.initial()?,
+                year,
+                date.month(),
+                date.day()
+            ),
+            JisEraCode::Number => format!(
+                "{}{:02}{:02}{:02}",
+                era.jis_code()?,
+                year,
+                date.month(),
+                date.day()
+            ),
+        })
+    }
+
+    /// Given a datetime, returns the decade of the era it falls in, e.g.
+    /// 昭和５０年代. The first nine years are rendered as 平成一桁.
+    pub fn to_jp_era_decade_string(date: DateTime<Utc>) -> Option<String> {
+        let era = Era::from_datetime(date)?;
+        let kanji = era.kanji?;
+        let decade = era.year_of(date) / 10 * 10;
+        Some(if decade == 0 {
+            format!("{}一桁", kanji)
+        } else {
+            format!("{}{}年代", kanji, to_jp_intstring(decade))
+        })
+    }
+
+    /// Describes the eras the span between two datetimes runs across, e.g.
+    /// 昭和・平成・令和の3時代にわたる, or just 令和 when it stays within one
+    /// era. Returns `None` if either end has no named era.
+    pub fn describe_span(a: DateTime<Utc>, b: DateTime<Utc>) -> Option<String> {
+        Era::from_datetime(a)?.kanji?;
+        Era::from_datetime(b)?.kanji?;
+
+        let names: Vec<&str> = Era::between(a, b).filter_map(|era| era.kanji).collect();
+        Some(match names.len() {
+            1 => names[0].to_owned(),
+            count => format!("{}の{}時代にわたる", names.join("・"), count),
+        })
+    }
+
+    /// Returns the era's romaji name, capitalized and rendered in the given
+    /// romanization style.
+    pub fn romaji_with(&self, style: RomanizationStyle) -> Option<String> {
+        let romaji = self.romaji?;
+        let rendered = match style {
+            RomanizationStyle::Wapuro => romaji.to_owned(),
+            RomanizationStyle::Hepburn => wapuro_to_hepburn(romaji, true),
+            RomanizationStyle::Plain => wapuro_to_hepburn(romaji, false),
+        };
+
+        let mut chars = rendered.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+    }
+}
+
+/// What `to_jp_nenkou_string_or` renders for dates no named era covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    /// The historical period, e.g. 飛鳥時代. Dates before 592, the
+    /// conventional start of the Asuka period, are still an error.
+    Jidai,
+    /// The Gregorian date, e.g. 西暦６００年１月１日.
+    Gregorian,
+    /// 西暦６４５年以前 for any date before Taika. Later dates without an era
+    /// name, in the unnamed gaps, are rendered as for `Gregorian`.
+    BeforeTaika,
+}
+
+/// How `to_jis_x0301_string` writes the era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JisEraCode {
+    /// The era's Latin initial, with the fields separated by '.', e.g.
+    /// R03.11.12.
+    Letter,
+    /// The era's number, 1 for Meiji through 5 for Reiwa, with no
+    /// separators, e.g. 5031112.
+    Number,
+}
+
+/// The conventional first year of the Asuka period, which predates Taika.
+const ASUKA_STARTED_IN: i32 = 592;
+
+/// Renders a Gregorian date the Japanese way, e.g. 西暦６００年１月１日.
+fn to_jp_seireki_string(date: NaiveDate) -> String {
+    let year = if date.year() > 0 {
+        format!("西暦{}", to_jp_intstring(date.year() as u32))
+    } else {
+        // There's no year zero: 1 BCE is the proleptic year 0.
+        format!("紀元前{}", to_jp_intstring((1 - date.year()) as u32))
+    };
+
+    format!(
+        "{}年{}月{}日",
+        year,
+        to_jp_intstring(date.month()),
+        to_jp_intstring(date.day())
+    )
+}
+
+/// Renders the century a year falls in, e.g. ２１世紀 for 2021 or 紀元前１世紀
+/// for the proleptic year 0 (1 BCE).
+pub fn to_jp_century_string(year: i32) -> String {
+    if year > 0 {
+        format!("{}世紀", to_jp_intstring((year as u32 - 1) / 100 + 1))
+    } else {
+        format!("紀元前{}世紀", to_jp_intstring((-year) as u32 / 100 + 1))
+    }
+}
+
+/// Renders the decade a year falls in, e.g. １９８０年代 for 1985.
+pub fn to_jp_decade_string(year: u32) -> String {
+    format!("{}年代", to_jp_intstring(year / 10 * 10))
+}
+
+/// Formats the datetime with a chrono format string extended with era
+/// specifiers, so that output templates can live in configuration:
+///
+/// - `%JE`: the era's kanji name, e.g. 令和
+/// - `%JR`, `%Jr`: its romaji name, with or without macrons, e.g. Shōwa
+/// - `%Jy`, `%JY`: the era year, in ASCII or full-width digits
+/// - `%Jg`, `%JG`: the same, but 元 for the first year of an era
+/// - `%Jk`: the era year in kanji numerals, e.g. 三
+/// - `%Jm`, `%Jd`: the month and day in full-width digits
+///
+/// Other specifiers are chrono's, applied to the datetime in JST. Unknown
+/// era specifiers and invalid format strings are a `ParseError`.
+///
+/// ```
+/// # use nihonify_core::{format::format_wareki, parse::utc_dt};
+/// let date = utc_dt("2019-05-01");
+/// assert_eq!(format_wareki(date, "%JE%Jg年%-m月%-d日").unwrap(), "令和元年5月1日");
+/// assert_eq!(format_wareki(date, "%JR %Jy (%Y)").unwrap(), "Reiwa 1 (2019)");
+/// ```
+pub fn format_wareki(date: DateTime<Utc>, pattern: &str) -> Result<String, NihonifyError> {
+    let jst = date.with_timezone(&FixedOffset::east_opt(JST_OFFSET).unwrap());
+    let era_and_year = || Era::try_from_datetime(date).map(|era| (era, era.year_of(date)));
+
+    let mut chrono_pattern = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            chrono_pattern.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('J') => (),
+            Some(next) => {
+                chrono_pattern.push('%');
+                chrono_pattern.push(next);
+                continue;
+            }
+            None => {
+                chrono_pattern.push('%');
+                continue;
+            }
+        }
+        let text = match chars.next() {
+            Some('E') => String::from(era_and_year()?.0.kanji.ok_or(NihonifyError::UnnamedEra)?),
+            Some('R') => era_and_year()?
+                .0
+                .romaji_with(RomanizationStyle::Hepburn)
+                .ok_or(NihonifyError::UnnamedEra)?,
+            Some('r') => era_and_year()?
+                .0
+                .romaji_with(RomanizationStyle::Plain)
+                .ok_or(NihonifyError::UnnamedEra)?,
+            Some('g') | Some('G') if era_and_year()?.1 == 1 => String::from("元"),
+            Some('y') | Some('g') => era_and_year()?.1.to_string(),
+            Some('Y') | Some('G') => to_jp_intstring(era_and_year()?.1),
+            Some('k') => to_kanji_numeral(era_and_year()?.1.into()),
+            Some('m') => to_jp_intstring(jst.month()),
+            Some('d') => to_jp_intstring(jst.day()),
+            _ => return Err(NihonifyError::ParseError),
+        };
+        chrono_pattern.push_str(&text);
+    }
+
+    let mut out = String::new();
+    write!(out, "{}", jst.format(&chrono_pattern)).map_err(|_| NihonifyError::ParseError)?;
+    Ok(out)
+}
+
+/// Renders datetimes as nenkou datestrings, with the numerals, the way of
+/// writing an era's first year, the separators and the era name
+/// configurable. The defaults are those of `Era::to_jp_nenkou_string`:
+///
+/// ```
+/// # use nihonify_core::{parse::utc_dt, EraStyle, NenkouFormatter, Numerals, RomanizationStyle};
+/// let formatter = NenkouFormatter::new()
+///     .numerals(Numerals::Ascii)
+///     .separator('.')
+///     .era(EraStyle::Romaji(RomanizationStyle::Plain));
+/// assert_eq!(formatter.format(utc_dt("2021-11-12")).unwrap(), "Reiwa 3.11.12");
+///
+/// // The compact form used on forms and in filenames.
+/// let compact = formatter.era(EraStyle::Initial);
+/// assert_eq!(compact.format(utc_dt("2021-11-12")).unwrap(), "R3.11.12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NenkouFormatter {
+    numerals: Numerals,
+    gannen: bool,
+    day: bool,
+    separators: Separators,
+    era: EraStyle,
+    lunisolar: bool,
+    weekday: WeekdayStyle,
+}
+
+impl Default for NenkouFormatter {
+    fn default() -> Self {
+        NenkouFormatter {
+            numerals: Numerals::FullWidth,
+            gannen: true,
+            day: true,
+            separators: Separators::Kanji,
+            era: EraStyle::Kanji,
+            lunisolar: false,
+            weekday: WeekdayStyle::Omitted,
+        }
+    }
+}
+
+impl NenkouFormatter {
+    pub fn new() -> Self {
+        NenkouFormatter::default()
+    }
+
+    /// The numerals to write the year, month and day in.
+    pub fn numerals(mut self, numerals: Numerals) -> Self {
+        self.numerals = numerals;
+        self
+    }
+
+    /// Whether to write the first year of an era as 元年, as is conventional,
+    /// rather than as year one. Only applies with kanji separators.
+    pub fn gannen(mut self, gannen: bool) -> Self {
+        self.gannen = gannen;
+        self
+    }
+
+    /// Whether to include the day, or stop at the month, e.g. 令和３年１１月.
+    pub fn day(mut self, day: bool) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Separates the year, month and day with the char instead of 年, 月 and
+    /// 日, e.g. 令和３.１１.１２ for '.'.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separators = Separators::Char(separator);
+        self
+    }
+
+    /// How the year, month and day are separated.
+    pub fn separators(mut self, separators: Separators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// How to write the era's name.
+    pub fn era(mut self, era: EraStyle) -> Self {
+        self.era = era;
+        self
+    }
+
+    /// Whether to write dates before the calendar reform of 1873 in the
+    /// lunisolar calendar then in use, e.g. 慶応３年１０月１４日 for
+    /// 1867-11-09, with 閏 before leap months. Dates before 1590, which the
+    /// tables don't cover, are then an error.
+    pub fn lunisolar(mut self, lunisolar: bool) -> Self {
+        self.lunisolar = lunisolar;
+        self
+    }
+
+    /// Whether and how to write the day of the week after the day, e.g.
+    /// 令和３年１１月１２日（金）.
+    pub fn weekday(mut self, weekday: WeekdayStyle) -> Self {
+        self.weekday = weekday;
+        self
+    }
+
+    /// Renders the datetime, or returns `None` if no named era covers it.
+    pub fn format(&self, date: DateTime<Utc>) -> Option<String> {
+        self.try_format(date).ok()
+    }
+
+    /// Like `format`, but explains why the datetime can't be rendered.
+    pub fn try_format(&self, date: DateTime<Utc>) -> Result<String, NihonifyError> {
+        let era = Era::try_from_datetime(date)?;
+        let mut out = match self.era {
+            EraStyle::Kanji => String::from(era.kanji.ok_or(NihonifyError::UnnamedEra)?),
+            EraStyle::Romaji(style) => {
+                era.romaji_with(style).ok_or(NihonifyError::UnnamedEra)? + " "
+            }
+            EraStyle::Initial => String::from(era.initial().ok_or(NihonifyError::DateOutOfRange)?),
+            EraStyle::Abbreviation => {
+                String::from(era.abbreviation().ok_or(NihonifyError::DateOutOfRange)?)
+            }
+        };
+
+        let mut year = era.year_of(date);
+        let date = jst_date(date);
+        let mut month = self.numerals.render(date.month());
+        let mut day = self.numerals.render(date.day());
+        if self.lunisolar && date <= lunisolar::last_day() {
+            let lunisolar = LunisolarDate::from_gregorian(date)?;
+            // Era years rolled over at New Year. Eras before the tables
+            // started after New Year, so they share their Gregorian year.
+            let start = era.start_date_jst();
+            let start_year =
+                LunisolarDate::from_gregorian(start).map_or(start.year(), |start| start.year());
+            year = (lunisolar.year() - start_year + 1) as u32;
+            month = self.numerals.render(lunisolar.month());
+            if lunisolar.is_leap_month() {
+                month.insert(0, '閏');
+            }
+            day = self.numerals.render(lunisolar.day());
+        }
+        match self.separators {
+            Separators::Kanji => {
+                match year {
+                    1 if self.gannen => out.push('元'),
+                    year => out.push_str(&self.numerals.render(year)),
+                }
+                out.push('年');
+                out.push_str(&month);
+                out.push('月');
+                if self.day {
+                    out.push_str(&day);
+                    out.push('日');
+                }
+            }
+            Separators::Char(separator) => {
+                out.push_str(&self.numerals.render(year));
+                out.push(separator);
+                out.push_str(&month);
+                if self.day {
+                    out.push(separator);
+                    out.push_str(&day);
+                }
+            }
+        }
+        if self.day {
+            match self.weekday {
+                WeekdayStyle::Omitted => (),
+                WeekdayStyle::Parenthesized => {
+                    out.push('（');
+                    out.push(weekday_kanji(date));
+                    out.push('）');
+                }
+                WeekdayStyle::Full => {
+                    out.push(weekday_kanji(date));
+                    out.push_str("曜日");
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// How `NenkouFormatter` separates the year, month and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separators {
+    /// With 年, 月 and 日, e.g. 令和３年１１月１２日.
+    Kanji,
+    /// With the char, e.g. 令和３.１１.１２.
+    Char(char),
+}
+
+/// How `NenkouFormatter` writes the day of the week. It's only written when
+/// the day is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekdayStyle {
+    /// No day of the week, as by default.
+    Omitted,
+    /// In full-width parentheses, e.g. 令和３年１１月１２日（金）.
+    Parenthesized,
+    /// In full, e.g. 令和３年１１月１２日金曜日.
+    Full,
+}
+
+/// How `NenkouFormatter` writes an era's name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraStyle {
+    /// e.g. 令和.
+    Kanji,
+    /// Capitalized and romanized in the given style, e.g. Reiwa.
+    Romaji(RomanizationStyle),
+    /// By its Latin initial, e.g. R, as in R3.11.12. Only the modern eras,
+    /// Meiji through Reiwa, have one, so earlier dates are out of range.
+    Initial,
+    /// By its first kanji, e.g. 令, as in 令3.11.12. Only the modern eras,
+    /// Meiji through Reiwa, are abbreviated so.
+    Abbreviation,
+}
+
+/// The numerals numbers are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Numerals {
+    /// Full-width digits, e.g. ３１.
+    FullWidth,
+    /// ASCII digits, e.g. 31.
+    Ascii,
+    /// Positional kanji numerals, e.g. 三十一.
+    Kanji,
+    /// Daiji, the formal numerals of legal and financial documents, e.g.
+    /// 参拾壱.
+    Daiji,
+}
+
+impl Numerals {
+    pub(crate) fn render(self, num: impl Into<u64>) -> String {
+        let num = num.into();
+        match self {
+            Numerals::FullWidth => to_full_width_digits(&num.to_string()),
+            Numerals::Ascii => num.to_string(),
+            Numerals::Kanji => to_kanji_numeral(num),
+            Numerals::Daiji => to_daiji_numeral(num),
+        }
+    }
+}
+
+/// The English-language presets for rendering a nenkou date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnglishPreset {
+    /// "Reiwa 3, November 12, 2021"
+    Long,
+    /// "12 Nov R3"
+    Short,
+}
+
+/// How era names are romanized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanizationStyle {
+    /// Hepburn with macrons for long vowels, e.g. "Taishō".
+    Hepburn,
+    /// Wāpuro (keyboard) style, as stored in the era table, e.g. "Taishou".
+    Wapuro,
+    /// Hepburn without macrons, e.g. "Taisho".
+    Plain,
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Converts the wapuro spellings used in the era table to Hepburn, optionally
+/// marking long vowels with macrons.
+pub(crate) fn wapuro_to_hepburn(romaji: &str, macrons: bool) -> String {
+    let chars: Vec<char> = romaji.chars().collect();
+    let mut out = String::with_capacity(romaji.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let next = chars.get(i + 1).copied();
+        match (chars[i], next) {
+            // Long vowels.
+            ('o', Some('u')) | ('o', Some('o')) => {
+                out.push(if macrons { 'ō' } else { 'o' });
+                i += 2;
+            }
+            ('u', Some('u')) => {
+                out.push(if macrons { 'ū' } else { 'u' });
+                i += 2;
+            }
+            // A doubled n is a syllabic ん, which takes an apostrophe when
+            // followed by a vowel or y.
+            ('n', Some('n')) => {
+                out.push('n');
+                if let Some('a' | 'i' | 'u' | 'e' | 'o' | 'y') = chars.get(i + 2) {
+                    out.push('\'');
+                }
+                i += 2;
+            }
+            (c, _) => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::utc_dt;
+
+    #[test]
+    fn test_to_jp_nenkou_string() {
+        // November 2021 should be Reiwa 3, on the date it is in Japan.
+        assert_eq!(
+            Era::to_jp_nenkou_string(
+                Utc.from_utc_datetime(
+                    &DateTime::parse_from_rfc3339("2021-11-12T22:10:57Z")
+                        .unwrap()
+                        .naive_utc()
+                ),
+            ),
+            Some("令和３年１１月１３日".to_owned())
+        );
+
+        // Summer 2019 should be Reiwa 1
+        assert_eq!(
+            Era::to_jp_nenkou_string(utc_dt("2019-06-13")),
+            Some("令和元年６月１３日".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_jp_nenkou_string_now() {
+        use crate::calendar::FixedClock;
+
+        assert_eq!(
+            Era::to_jp_nenkou_string_now_with(&FixedClock(utc_dt("2019-05-01"))),
+            Ok("令和元年５月１日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_now_with(&FixedClock(utc_dt("0600-01-01"))),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_to_jp_nenkou_string() {
+        assert_eq!(
+            Era::try_to_jp_nenkou_string(utc_dt("2021-11-12")),
+            Ok("令和３年１１月１２日".to_owned())
+        );
+        assert_eq!(
+            Era::try_to_jp_nenkou_string(utc_dt("0600-01-01")),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+        assert_eq!(
+            Era::try_to_jp_nenkou_string(utc_dt("0660-01-01")),
+            Err(NihonifyError::UnnamedEra)
+        );
+        assert_eq!(
+            Era::try_to_jp_nenkou_string(Utc.with_ymd_and_hms(10000, 1, 1, 0, 0, 0).unwrap()),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_nenkou_formatter() {
+        let date = utc_dt("2019-06-13");
+        assert_eq!(
+            NenkouFormatter::new().gannen(false).format(date),
+            Some("令和１年６月１３日".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Kanji)
+                .format(date),
+            Some("令和元年六月十三日".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Kanji)
+                .gannen(false)
+                .format(date),
+            Some("令和一年六月十三日".to_owned())
+        );
+        assert_eq!(NenkouFormatter::new().format(utc_dt("0600-01-01")), None);
+
+        let date = utc_dt("2021-11-12");
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Daiji)
+                .format(date),
+            Some("令和参年壱拾壱月壱拾弐日".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Ascii)
+                .format(date),
+            Some("令和3年11月12日".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new().day(false).format(date),
+            Some("令和３年１１月".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Ascii)
+                .separator('/')
+                .day(false)
+                .format(date),
+            Some("令和3/11".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .era(EraStyle::Romaji(RomanizationStyle::Hepburn))
+                .numerals(Numerals::Ascii)
+                .format(utc_dt("1926-12-25")),
+            Some("Shōwa 元年12月25日".to_owned())
+        );
+        // 元年 is only written with kanji separators.
+        assert_eq!(
+            NenkouFormatter::new()
+                .numerals(Numerals::Ascii)
+                .separator('.')
+                .format(utc_dt("2019-06-13")),
+            Some("令和1.6.13".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_weekday_styles() {
+        let date = utc_dt("2021-11-12");
+        assert_eq!(
+            NenkouFormatter::new()
+                .weekday(WeekdayStyle::Parenthesized)
+                .format(date),
+            Some("令和３年１１月１２日（金）".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .weekday(WeekdayStyle::Full)
+                .format(date),
+            Some("令和３年１１月１２日金曜日".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .weekday(WeekdayStyle::Full)
+                .day(false)
+                .format(date),
+            Some("令和３年１１月".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_compact_era_styles() {
+        let compact = NenkouFormatter::new()
+            .numerals(Numerals::Ascii)
+            .separator('.');
+        let date = utc_dt("2021-11-12");
+        assert_eq!(
+            compact.era(EraStyle::Initial).format(date),
+            Some("R3.11.12".to_owned())
+        );
+        assert_eq!(
+            compact.era(EraStyle::Abbreviation).format(date),
+            Some("令3.11.12".to_owned())
+        );
+        assert_eq!(
+            compact.era(EraStyle::Initial).format(utc_dt("1989-01-07")),
+            Some("S64.1.7".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new()
+                .era(EraStyle::Abbreviation)
+                .format(utc_dt("2019-05-01")),
+            Some("令元年５月１日".to_owned())
+        );
+        assert_eq!(
+            compact
+                .era(EraStyle::Initial)
+                .try_format(utc_dt("1867-11-09")),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_era_years_roll_over_on_january_1st() {
+        let nenkou = |date| Era::to_jp_nenkou_string(utc_dt(date)).unwrap();
+        // Heisei to Reiwa.
+        assert_eq!(nenkou("2019-04-30"), "平成３１年４月３０日");
+        assert_eq!(nenkou("2019-05-01"), "令和元年５月１日");
+        assert_eq!(nenkou("2019-12-31"), "令和元年１２月３１日");
+        assert_eq!(nenkou("2020-01-01"), "令和２年１月１日");
+        // Shōwa to Heisei.
+        assert_eq!(nenkou("1988-12-31"), "昭和６３年１２月３１日");
+        assert_eq!(nenkou("1989-01-07"), "昭和６４年１月７日");
+        assert_eq!(nenkou("1989-01-08"), "平成元年１月８日");
+        assert_eq!(nenkou("1990-01-01"), "平成２年１月１日");
+        // Far from the start of an era, leap days used to push the year ahead.
+        assert_eq!(nenkou("1988-01-05"), "昭和６３年１月５日");
+        assert_eq!(nenkou("2018-12-30"), "平成３０年１２月３０日");
+        assert_eq!(nenkou("1970-01-19"), "昭和４５年１月１９日");
+    }
+
+    #[test]
+    fn test_to_jp_nenkou_string_ascii() {
+        assert_eq!(
+            Era::to_jp_nenkou_string_ascii(utc_dt("2021-11-12")),
+            Some("令和3年11月12日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_ascii(utc_dt("2019-05-01")),
+            Some("令和元年5月1日".to_owned())
+        );
+        assert_eq!(Era::to_jp_nenkou_string_ascii(utc_dt("0600-01-01")), None);
+    }
+
+    #[test]
+    fn test_to_jp_nenkou_string_kanji() {
+        assert_eq!(
+            Era::to_jp_nenkou_string_kanji(utc_dt("2021-11-12")),
+            Some("令和三年十一月十二日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_kanji(utc_dt("1988-12-31")),
+            Some("昭和六十三年十二月三十一日".to_owned())
+        );
+        assert_eq!(Era::to_jp_nenkou_string_kanji(utc_dt("0600-01-01")), None);
+    }
+
+    #[test]
+    fn test_format_wareki() {
+        let date = utc_dt("2021-11-12");
+        assert_eq!(
+            format_wareki(date, "%JE%Jy年%m月%d日"),
+            Ok("令和3年11月12日".to_owned())
+        );
+        assert_eq!(
+            format_wareki(date, "%JE%JY年%Jm月%Jd日"),
+            Ok("令和３年１１月１２日".to_owned())
+        );
+        assert_eq!(
+            format_wareki(date, "%JE%Jk年 (%Jr %Jg) 100%%"),
+            Ok("令和三年 (Reiwa 3) 100%".to_owned())
+        );
+        assert_eq!(
+            format_wareki(utc_dt("1926-12-25"), "%JE%JG年, %JR %Jg"),
+            Ok("昭和元年, Shōwa 元".to_owned())
+        );
+        // Chrono's specifiers see the time in Japan.
+        let evening = Utc.with_ymd_and_hms(2019, 4, 30, 20, 0, 0).unwrap();
+        assert_eq!(
+            format_wareki(evening, "%JE%Jg年%-m月%-d日 %H:%M"),
+            Ok("令和元年5月1日 05:00".to_owned())
+        );
+        assert_eq!(format_wareki(date, "%Y"), Ok("2021".to_owned()));
+        assert_eq!(format_wareki(date, "%Jx"), Err(NihonifyError::ParseError));
+        assert_eq!(format_wareki(date, "%J"), Err(NihonifyError::ParseError));
+        assert_eq!(format_wareki(date, "%Q"), Err(NihonifyError::ParseError));
+        assert_eq!(
+            format_wareki(utc_dt("0660-01-01"), "%JE"),
+            Err(NihonifyError::UnnamedEra)
+        );
+        assert!(format_wareki(utc_dt("0600-01-01"), "%Jy").is_err());
+        assert_eq!(
+            format_wareki(utc_dt("0600-01-01"), "%Y"),
+            Ok("0600".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_jp_nenkou_string_lunisolar() {
+        let nenkou = |date| Era::to_jp_nenkou_string_lunisolar(utc_dt(date));
+        assert_eq!(
+            nenkou("1867-11-09"),
+            Some("慶応３年１０月１４日".to_owned())
+        );
+        assert_eq!(nenkou("1868-01-01"), Some("慶応３年１２月７日".to_owned()));
+        assert_eq!(
+            nenkou("1868-06-01"),
+            Some("慶応４年閏４月１１日".to_owned())
+        );
+        assert_eq!(nenkou("1868-10-23"), Some("明治元年９月８日".to_owned()));
+        assert_eq!(nenkou("1872-12-31"), Some("明治５年１２月２日".to_owned()));
+        assert_eq!(nenkou("1873-01-01"), Some("明治６年１月１日".to_owned()));
+        assert_eq!(nenkou("1600-10-21"), Some("慶長５年９月１５日".to_owned()));
+        // Tenshō started before the tables do.
+        assert_eq!(
+            nenkou("1590-06-01"),
+            Some("天正１８年４月２９日".to_owned())
+        );
+        assert_eq!(nenkou("1500-01-01"), None);
+        assert_eq!(
+            nenkou("2021-11-12"),
+            Some("令和３年１１月１２日".to_owned())
+        );
+
+        let formatter = NenkouFormatter::new()
+            .lunisolar(true)
+            .numerals(Numerals::Ascii)
+            .separator('.');
+        assert_eq!(
+            formatter.format(utc_dt("1868-06-01")),
+            Some("慶応4.閏4.11".to_owned())
+        );
+        assert_eq!(
+            NenkouFormatter::new().format(utc_dt("1867-11-09")),
+            Some("慶応３年１１月９日".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_jp_nenkou_string_or() {
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("2021-11-12"), Fallback::Gregorian),
+            Ok("令和３年１１月１２日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0600-01-01"), Fallback::Gregorian),
+            Ok("西暦６００年１月１日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0600-01-01"), Fallback::Jidai),
+            Ok("飛鳥時代".to_owned())
+        );
+        // 660 falls in an unnamed gap between Hakuchi and Shuchou.
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0660-01-01"), Fallback::Jidai),
+            Ok("飛鳥時代".to_owned())
+        );
+        // 1333-10-01 falls in the gap between Shōkyō and Kenmu.
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("1333-10-01"), Fallback::Jidai),
+            Ok("南北朝時代".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0500-01-01"), Fallback::Jidai),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(
+                Utc.with_ymd_and_hms(12000, 1, 1, 0, 0, 0).unwrap(),
+                Fallback::Gregorian
+            ),
+            Ok("西暦１２０００年１月１日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0500-01-01"), Fallback::BeforeTaika),
+            Ok("西暦６４５年以前".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("1990-01-01"), Fallback::BeforeTaika),
+            Ok("平成２年１月１日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("0660-01-01"), Fallback::BeforeTaika),
+            Ok("西暦６６０年１月１日".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_nenkou_string_or(utc_dt("1333-10-01"), Fallback::BeforeTaika),
+            Ok("西暦１３３３年１０月１日".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_en_nenkou_string() {
+        assert_eq!(
+            Era::to_en_nenkou_string(
+                utc_dt("2021-11-12"),
+                EnglishPreset::Long,
+                RomanizationStyle::Hepburn
+            ),
+            Some("Reiwa 3, November 12, 2021".to_owned())
+        );
+        assert_eq!(
+            Era::to_en_nenkou_string(
+                utc_dt("2021-11-12"),
+                EnglishPreset::Short,
+                RomanizationStyle::Hepburn
+            ),
+            Some("12 Nov R3".to_owned())
+        );
+        assert_eq!(
+            Era::to_en_nenkou_string(
+                utc_dt("2000-06-01"),
+                EnglishPreset::Long,
+                RomanizationStyle::Plain
+            ),
+            Some("Heisei 12, June 1, 2000".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_jis_x0301_string() {
+        let jis = |date, code| Era::to_jis_x0301_string(utc_dt(date), code);
+        assert_eq!(
+            jis("2021-11-12", JisEraCode::Letter),
+            Some("R03.11.12".to_owned())
+        );
+        assert_eq!(
+            jis("2021-11-12", JisEraCode::Number),
+            Some("5031112".to_owned())
+        );
+        assert_eq!(
+            jis("2019-05-01", JisEraCode::Letter),
+            Some("R01.05.01".to_owned())
+        );
+        assert_eq!(
+            jis("1989-01-07", JisEraCode::Number),
+            Some("3640107".to_owned())
+        );
+        assert_eq!(jis("1867-11-09", JisEraCode::Letter), None);
+    }
+
+    #[test]
+    fn test_century_and_decade_strings() {
+        assert_eq!(to_jp_century_string(2001), "２１世紀");
+        assert_eq!(to_jp_century_string(2000), "２０世紀");
+        assert_eq!(to_jp_century_string(1), "１世紀");
+        assert_eq!(to_jp_century_string(0), "紀元前１世紀");
+        assert_eq!(to_jp_century_string(-99), "紀元前１世紀");
+        assert_eq!(to_jp_century_string(-100), "紀元前２世紀");
+        assert_eq!(to_jp_decade_string(1985), "１９８０年代");
+
+        assert_eq!(
+            Era::to_jp_era_decade_string(utc_dt("1977-06-01")),
+            Some("昭和５０年代".to_owned())
+        );
+        assert_eq!(
+            Era::to_jp_era_decade_string(utc_dt("1995-06-01")),
+            Some("平成一桁".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_describe_span() {
+        assert_eq!(
+            Era::describe_span(utc_dt("1988-04-01"), utc_dt("2021-11-12")),
+            Some("昭和・平成・令和の3時代にわたる".to_owned())
+        );
+        assert_eq!(
+            Era::describe_span(utc_dt("2021-11-12"), utc_dt("2019-05-01")),
+            Some("令和".to_owned())
+        );
+        assert_eq!(
+            Era::describe_span(utc_dt("0600-01-01"), utc_dt("2021-11-12")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_romaji_with() {
+        let taishou = Era::from_unix_epoch(-1556668810).unwrap();
+        assert_eq!(
+            taishou.romaji_with(RomanizationStyle::Hepburn),
+            Some("Taishō".to_owned())
+        );
+        assert_eq!(
+            taishou.romaji_with(RomanizationStyle::Wapuro),
+            Some("Taishou".to_owned())
+        );
+        assert_eq!(
+            taishou.romaji_with(RomanizationStyle::Plain),
+            Some("Taisho".to_owned())
+        );
+    }
+}
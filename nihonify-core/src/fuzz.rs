@@ -0,0 +1,168 @@
+//! `Arbitrary` implementations, for generating valid structured inputs in fuzz
+//! targets and property tests.
+
+use crate::calendar::{Era, Jidai, WarekiDate};
+use crate::eras::SORTED_ERAS;
+use crate::format::{
+    EnglishPreset, EraStyle, Fallback, NenkouFormatter, Numerals, RomanizationStyle, Separators,
+    WeekdayStyle,
+};
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+use chrono::{Days, NaiveDate};
+use core::convert::TryFrom;
+
+/// Any era from the era table.
+impl<'a> Arbitrary<'a> for &'static Era {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(SORTED_ERAS)
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Jidai {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            Jidai::Asuka,
+            Jidai::Nara,
+            Jidai::Heian,
+            Jidai::Kamakura,
+            Jidai::Nanbokuchou,
+            Jidai::Sengoku,
+            Jidai::Muromachi,
+            Jidai::AzuchiMomoyama,
+            Jidai::Edo,
+            Jidai::Modern,
+        ])
+        .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for EnglishPreset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[EnglishPreset::Long, EnglishPreset::Short])
+            .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for RomanizationStyle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            RomanizationStyle::Hepburn,
+            RomanizationStyle::Wapuro,
+            RomanizationStyle::Plain,
+        ])
+        .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Fallback {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[Fallback::Jidai, Fallback::Gregorian, Fallback::BeforeTaika])
+            .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Numerals {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            Numerals::FullWidth,
+            Numerals::Ascii,
+            Numerals::Kanji,
+            Numerals::Daiji,
+        ])
+        .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for WeekdayStyle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            WeekdayStyle::Omitted,
+            WeekdayStyle::Parenthesized,
+            WeekdayStyle::Full,
+        ])
+        .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Separators {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Separators::Kanji
+        } else {
+            Separators::Char(u.arbitrary()?)
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EraStyle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => EraStyle::Kanji,
+            1 => EraStyle::Romaji(u.arbitrary()?),
+            2 => EraStyle::Initial,
+            _ => EraStyle::Abbreviation,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for NenkouFormatter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(NenkouFormatter::new()
+            .numerals(u.arbitrary()?)
+            .gannen(u.arbitrary()?)
+            .day(u.arbitrary()?)
+            .separators(u.arbitrary()?)
+            .era(u.arbitrary()?)
+            .lunisolar(u.arbitrary()?)
+            .weekday(u.arbitrary()?))
+    }
+}
+
+/// Any day of a named era, up to the end of the year 9999.
+impl<'a> Arbitrary<'a> for WarekiDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let named: Vec<&Era> = SORTED_ERAS
+            .iter()
+            .filter(|era| era.kanji.is_some())
+            .collect();
+        let range = u.choose(&named)?.date_range();
+        let last = range
+            .last
+            .min(NaiveDate::from_ymd_opt(9999, 12, 31).unwrap());
+        let days = u.int_in_range(0..=(last - range.first).num_days() as u64)?;
+        let date = range.first + Days::new(days);
+        WarekiDate::try_from(date).map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_era() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..64 {
+            let era = <&'static Era>::arbitrary(&mut u).unwrap();
+            assert!(SORTED_ERAS.contains(era));
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_wareki_date() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..64 {
+            let date = WarekiDate::arbitrary(&mut u).unwrap();
+            assert_eq!(
+                WarekiDate::new(date.era(), date.year(), date.month(), date.day()),
+                Ok(date)
+            );
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! Japanese national holidays (国民の祝日) under the Public Holiday Act of
+//! 1948, as the law stood in each year: holidays are only listed from the
+//! year they were introduced, under the name and on the day they had then.
+//! The days off the act adds, substitute holidays (振替休日) and days between
+//! two holidays (国民の休日), are listed with them.
+
+use crate::weeks::nth_weekday;
+use alloc::vec::Vec;
+use chrono::prelude::*;
+
+/// A national holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: &'static str,
+}
+
+/// How a holiday's day is set.
+#[derive(Debug, Clone, Copy)]
+enum Rule {
+    /// The month and day.
+    Fixed(u32, u32),
+    /// The month and which of its Mondays, under the Happy Monday system.
+    Monday(u32, u8),
+    VernalEquinox,
+    AutumnalEquinox,
+}
+
+/// A holiday's name and day over the years, both inclusive, that the law set
+/// them.
+struct Definition {
+    name: &'static str,
+    rule: Rule,
+    from: i32,
+    until: i32,
+}
+
+const fn definition(name: &'static str, rule: Rule, from: i32, until: i32) -> Definition {
+    Definition {
+        name,
+        rule,
+        from,
+        until,
+    }
+}
+
+/// The act took effect on July 20th, 1948, so 1948 only has the autumn
+/// holidays.
+const DEFINITIONS: &[Definition] = &[
+    definition("元日", Rule::Fixed(1, 1), 1949, i32::MAX),
+    definition("成人の日", Rule::Fixed(1, 15), 1949, 1999),
+    definition("成人の日", Rule::Monday(1, 2), 2000, i32::MAX),
+    definition("建国記念の日", Rule::Fixed(2, 11), 1967, i32::MAX),
+    definition("天皇誕生日", Rule::Fixed(2, 23), 2020, i32::MAX),
+    definition("春分の日", Rule::VernalEquinox, 1949, i32::MAX),
+    definition("天皇誕生日", Rule::Fixed(4, 29), 1949, 1988),
+    definition("みどりの日", Rule::Fixed(4, 29), 1989, 2006),
+    definition("昭和の日", Rule::Fixed(4, 29), 2007, i32::MAX),
+    definition("憲法記念日", Rule::Fixed(5, 3), 1949, i32::MAX),
+    definition("みどりの日", Rule::Fixed(5, 4), 2007, i32::MAX),
+    definition("こどもの日", Rule::Fixed(5, 5), 1949, i32::MAX),
+    definition("海の日", Rule::Fixed(7, 20), 1996, 2002),
+    definition("海の日", Rule::Monday(7, 3), 2003, i32::MAX),
+    definition("山の日", Rule::Fixed(8, 11), 2016, i32::MAX),
+    definition("敬老の日", Rule::Fixed(9, 15), 1966, 2002),
+    definition("敬老の日", Rule::Monday(9, 3), 2003, i32::MAX),
+    definition("秋分の日", Rule::AutumnalEquinox, 1948, i32::MAX),
+    definition("体育の日", Rule::Fixed(10, 10), 1966, 1999),
+    definition("体育の日", Rule::Monday(10, 2), 2000, 2019),
+    definition("スポーツの日", Rule::Monday(10, 2), 2020, i32::MAX),
+    definition("文化の日", Rule::Fixed(11, 3), 1948, i32::MAX),
+    definition("勤労感謝の日", Rule::Fixed(11, 23), 1948, i32::MAX),
+    definition("天皇誕生日", Rule::Fixed(12, 23), 1989, 2018),
+];
+
+/// Holidays moved for a single year, for the Tokyo Olympics in 2020 and, when
+/// they were postponed, 2021.
+const MOVED: &[(i32, &str, u32, u32)] = &[
+    (2020, "海の日", 7, 23),
+    (2020, "スポーツの日", 7, 24),
+    (2020, "山の日", 8, 10),
+    (2021, "海の日", 7, 22),
+    (2021, "スポーツの日", 7, 23),
+    (2021, "山の日", 8, 8),
+];
+
+/// Holidays declared for a single day, for imperial ceremonies.
+const ONE_OFF: &[(i32, u32, u32, &str)] = &[
+    (1959, 4, 10, "皇太子明仁親王の結婚の儀"),
+    (1989, 2, 24, "昭和天皇の大喪の礼"),
+    (1990, 11, 12, "即位礼正殿の儀"),
+    (1993, 6, 9, "皇太子徳仁親王の結婚の儀"),
+    (2019, 5, 1, "天皇の即位の日"),
+    (2019, 10, 22, "即位礼正殿の儀"),
+];
+
+/// The national holidays of the year and the days off they bring, in order.
+/// Equinox days are only known from 1900 to 2150.
+pub fn holidays_in_year(year: i32) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> =
+        DEFINITIONS
+            .iter()
+            .filter(|definition| definition.from <= year && year <= definition.until)
+            .filter_map(|definition| {
+                let date = match MOVED
+                    .iter()
+                    .find(|&&(y, name, _, _)| y == year && name == definition.name)
+                {
+                    Some(&(_, _, month, day)) => NaiveDate::from_ymd_opt(year, month, day),
+                    None => date_of(definition.rule, year),
+                }?;
+                Some(Holiday {
+                    date,
+                    name: definition.name,
+                })
+            })
+            .chain(ONE_OFF.iter().filter(|&&(y, _, _, _)| y == year).map(
+                |&(y, month, day, name)| Holiday {
+                    date: NaiveDate::from_ymd_opt(y, month, day).unwrap(),
+                    name,
+                },
+            ))
+            .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+
+    let days_off = days_off(&holidays);
+    holidays.extend(days_off);
+    holidays.sort_by_key(|holiday| holiday.date);
+
+    holidays
+}
+
+/// Whether the date is a national holiday or a day off one brings.
+pub fn is_holiday(date: NaiveDate) -> bool {
+    holiday_name(date).is_some()
+}
+
+/// The name of the holiday on the date, e.g. 文化の日, or 振替休日 or 国民の休日
+/// for the days off the act adds.
+pub fn holiday_name(date: NaiveDate) -> Option<&'static str> {
+    holidays_in_year(date.year())
+        .into_iter()
+        .find(|holiday| holiday.date == date)
+        .map(|holiday| holiday.name)
+}
+
+/// Substitute holidays were introduced on April 12th, 1973, for holidays
+/// falling on a Sunday, and days between two holidays on December 27th, 1985.
+const SUBSTITUTES_FROM: NaiveDate = NaiveDate::from_ymd_opt(1973, 4, 12).unwrap();
+const BETWEEN_FROM: NaiveDate = NaiveDate::from_ymd_opt(1985, 12, 27).unwrap();
+
+/// The substitute holidays and days between two holidays that the year's
+/// holidays, in order, bring.
+fn days_off(holidays: &[Holiday]) -> Vec<Holiday> {
+    let is_holiday = |date| holidays.iter().any(|holiday| holiday.date == date);
+
+    let mut days_off = Vec::new();
+    for holiday in holidays {
+        if holiday.date.weekday() != Weekday::Sun || holiday.date < SUBSTITUTES_FROM {
+            continue;
+        }
+        // Until 2007, only the Monday after could be a substitute holiday;
+        // since, the first day after that isn't a holiday is.
+        let mut date = holiday.date.succ_opt().unwrap();
+        while holiday.date.year() >= 2007 && is_holiday(date) {
+            date = date.succ_opt().unwrap();
+        }
+        if !is_holiday(date) {
+            days_off.push(Holiday {
+                date,
+                name: "振替休日",
+            });
+        }
+    }
+
+    for pair in holidays.windows(2) {
+        let date = match pair[0].date.succ_opt() {
+            Some(date) if date.succ_opt() == Some(pair[1].date) => date,
+            _ => continue,
+        };
+        // Until 2007, Sundays stayed as they were.
+        let excluded = days_off.iter().any(|day| day.date == date)
+            || (date.year() < 2007 && date.weekday() == Weekday::Sun);
+        if date >= BETWEEN_FROM && !excluded {
+            days_off.push(Holiday {
+                date,
+                name: "国民の休日",
+            });
+        }
+    }
+
+    days_off
+}
+
+fn date_of(rule: Rule, year: i32) -> Option<NaiveDate> {
+    match rule {
+        Rule::Fixed(month, day) => NaiveDate::from_ymd_opt(year, month, day),
+        Rule::Monday(month, n) => nth_weekday(year, month, Weekday::Mon, n),
+        Rule::VernalEquinox => NaiveDate::from_ymd_opt(year, 3, equinox_day(year, VERNAL)?),
+        Rule::AutumnalEquinox => NaiveDate::from_ymd_opt(year, 9, equinox_day(year, AUTUMNAL)?),
+    }
+}
+
+/// The constants of the equinox approximation for 1900–1979, 1980–2099 and
+/// 2100–2150.
+const VERNAL: [f64; 3] = [20.8357, 20.8431, 21.8510];
+const AUTUMNAL: [f64; 3] = [23.2588, 23.2488, 24.2488];
+
+/// The day of the month of an equinox, by the approximation the National
+/// Astronomical Observatory's announcements agree with.
+fn equinox_day(year: i32, constants: [f64; 3]) -> Option<u32> {
+    let (constant, leap_base) = match year {
+        1900..=1979 => (constants[0], 1983),
+        1980..=2099 => (constants[1], 1980),
+        2100..=2150 => (constants[2], 1980),
+        _ => return None,
+    };
+    // The leap-year correction truncates towards zero.
+    let day = constant + 0.242194 * f64::from(year - 1980) - f64::from((year - leap_base) / 4);
+    Some(day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn names(year: i32) -> Vec<(u32, u32, &'static str)> {
+        holidays_in_year(year)
+            .iter()
+            .map(|holiday| (holiday.date.month(), holiday.date.day(), holiday.name))
+            .collect()
+    }
+
+    #[test]
+    fn test_holidays_in_2019() {
+        assert_eq!(
+            names(2019),
+            vec![
+                (1, 1, "元日"),
+                (1, 14, "成人の日"),
+                (2, 11, "建国記念の日"),
+                (3, 21, "春分の日"),
+                (4, 29, "昭和の日"),
+                (4, 30, "国民の休日"),
+                (5, 1, "天皇の即位の日"),
+                (5, 2, "国民の休日"),
+                (5, 3, "憲法記念日"),
+                (5, 4, "みどりの日"),
+                (5, 5, "こどもの日"),
+                (5, 6, "振替休日"),
+                (7, 15, "海の日"),
+                (8, 11, "山の日"),
+                (8, 12, "振替休日"),
+                (9, 16, "敬老の日"),
+                (9, 23, "秋分の日"),
+                (10, 14, "体育の日"),
+                (10, 22, "即位礼正殿の儀"),
+                (11, 3, "文化の日"),
+                (11, 4, "振替休日"),
+                (11, 23, "勤労感謝の日"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_olympic_moves() {
+        let holidays = names(2020);
+        assert!(holidays.contains(&(7, 23, "海の日")));
+        assert!(holidays.contains(&(7, 24, "スポーツの日")));
+        assert!(holidays.contains(&(8, 10, "山の日")));
+        assert!(holidays.contains(&(2, 23, "天皇誕生日")));
+        assert!(!holidays.iter().any(|&(month, _, _)| month == 10));
+
+        let holidays = names(2021);
+        assert!(holidays.contains(&(7, 22, "海の日")));
+        assert!(holidays.contains(&(7, 23, "スポーツの日")));
+        assert!(holidays.contains(&(8, 8, "山の日")));
+    }
+
+    #[test]
+    fn test_law_changes() {
+        assert!(names(1988).contains(&(4, 29, "天皇誕生日")));
+        assert!(names(1989).contains(&(4, 29, "みどりの日")));
+        assert!(names(1989).contains(&(12, 23, "天皇誕生日")));
+        assert!(names(1989).contains(&(2, 24, "昭和天皇の大喪の礼")));
+        assert!(names(1999).contains(&(10, 10, "体育の日")));
+        assert!(names(2000).contains(&(10, 9, "体育の日")));
+        assert!(names(2000).contains(&(1, 10, "成人の日")));
+        assert!(!names(1995).iter().any(|&(_, _, name)| name == "海の日"));
+        assert!(!names(2015).iter().any(|&(_, _, name)| name == "山の日"));
+        assert!(!names(1966)
+            .iter()
+            .any(|&(_, _, name)| name == "建国記念の日"));
+        assert!(!names(2019).iter().any(|&(_, _, name)| name == "天皇誕生日"));
+        assert_eq!(
+            names(1948),
+            vec![
+                (9, 23, "秋分の日"),
+                (11, 3, "文化の日"),
+                (11, 23, "勤労感謝の日")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_days_off() {
+        let name = |y, m, d| holiday_name(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        // The first substitute holiday, for 天皇誕生日 on a Sunday.
+        assert_eq!(name(1973, 4, 30), Some("振替休日"));
+        assert_eq!(name(1973, 1, 16), None);
+        // Until 2007, only the Monday after.
+        assert_eq!(name(1998, 5, 4), Some("振替休日"));
+        assert_eq!(name(2008, 5, 6), Some("振替休日"));
+        // The first day between two holidays.
+        assert_eq!(name(1988, 5, 4), Some("国民の休日"));
+        assert_eq!(name(1997, 5, 4), None);
+        assert_eq!(name(2009, 9, 22), Some("国民の休日"));
+        assert!(is_holiday(NaiveDate::from_ymd_opt(2021, 11, 3).unwrap()));
+        assert!(!is_holiday(NaiveDate::from_ymd_opt(2021, 11, 4).unwrap()));
+    }
+
+    #[test]
+    fn test_equinoxes() {
+        let equinoxes = |year| {
+            (
+                date_of(Rule::VernalEquinox, year).unwrap().day(),
+                date_of(Rule::AutumnalEquinox, year).unwrap().day(),
+            )
+        };
+        assert_eq!(equinoxes(1960), (20, 23));
+        assert_eq!(equinoxes(1979), (21, 24));
+        assert_eq!(equinoxes(2012), (20, 22));
+        assert_eq!(equinoxes(2020), (20, 22));
+        assert_eq!(equinoxes(2023), (21, 23));
+        assert_eq!(equinoxes(2024), (20, 22));
+        assert_eq!(date_of(Rule::VernalEquinox, 2200), None);
+    }
+}
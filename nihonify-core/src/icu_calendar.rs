@@ -0,0 +1,78 @@
+//! Conversions between wareki dates and ICU4X's `icu_calendar` dates in the
+//! `Japanese` calendar. That calendar only names the eras from Meiji on, so
+//! the conversions go through the ISO date, and earlier dates get their era
+//! from the era table.
+
+use crate::calendar::WarekiDate;
+use crate::error::NihonifyError;
+use core::convert::TryFrom;
+use icu_calendar::cal::{Iso, Japanese};
+use icu_calendar::Date;
+
+impl TryFrom<Date<Japanese>> for WarekiDate {
+    type Error = NihonifyError;
+
+    fn try_from(date: Date<Japanese>) -> Result<Self, Self::Error> {
+        let iso = date.to_calendar(Iso);
+        WarekiDate::from_ymd(
+            iso.year().extended_year(),
+            iso.month().ordinal.into(),
+            iso.day_of_month().0.into(),
+        )
+    }
+}
+
+impl From<WarekiDate> for Date<Japanese> {
+    fn from(date: WarekiDate) -> Self {
+        // Wareki dates are all between Taika and the year 9999, which ISO
+        // dates cover.
+        let (year, month, day) = date.to_ymd();
+        Date::try_new_iso(year, month as u8, day as u8)
+            .unwrap()
+            .to_calendar(Japanese::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Era;
+
+    #[test]
+    fn test_round_trip() {
+        let wareki = WarekiDate::from_ymd(2021, 11, 12).unwrap();
+        let date = Date::<Japanese>::from(wareki);
+        assert_eq!(date.era_year().era, "reiwa");
+        assert_eq!(date.era_year().year, 3);
+        assert_eq!(WarekiDate::try_from(date), Ok(wareki));
+
+        // Before Meiji, ICU's calendar counts Gregorian years, but the era
+        // comes back from the era table.
+        let wareki = WarekiDate::from_ymd(1850, 1, 1).unwrap();
+        let date = Date::<Japanese>::from(wareki);
+        assert_eq!(date.era_year().year, 1850);
+        assert_eq!(WarekiDate::try_from(date), Ok(wareki));
+        assert_eq!(wareki.era().kanji, Some("嘉永"));
+
+        for era in Era::all() {
+            let wareki = WarekiDate::try_from(era.start_date_jst()).unwrap();
+            assert_eq!(
+                WarekiDate::try_from(Date::<Japanese>::from(wareki)),
+                Ok(wareki)
+            );
+        }
+    }
+
+    #[test]
+    fn test_before_taika() {
+        let date = Date::try_new_iso(600, 1, 1)
+            .unwrap()
+            .to_calendar(Japanese::new());
+        assert_eq!(
+            WarekiDate::try_from(date),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Era lookup and wareki formatting for `jiff` dates. A `Zoned` is taken on
+//! the date it has in its own time zone, so 2019-05-01T08:00+09:00[Asia/Tokyo]
+//! is 令和元年５月１日 even though it's still April 30th in UTC.
+
+use crate::calendar::Era;
+use crate::error::NihonifyError;
+use alloc::string::String;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use jiff::civil;
+use jiff::Zoned;
+
+impl TryFrom<civil::Date> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(date: civil::Date) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(to_chrono(date))
+    }
+}
+
+impl TryFrom<&Zoned> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(zoned: &Zoned) -> Result<Self, Self::Error> {
+        <&Era>::try_from(zoned.date())
+    }
+}
+
+/// Like `Era::to_jp_nenkou_string`, for a `jiff` civil date.
+pub fn to_jp_nenkou_string(date: civil::Date) -> Option<String> {
+    Era::to_jp_nenkou_string(to_chrono(date))
+}
+
+/// Like `Era::to_jp_nenkou_string`, for the date a `Zoned` has in its own time
+/// zone.
+pub fn zoned_to_jp_nenkou_string(zoned: &Zoned) -> Option<String> {
+    to_jp_nenkou_string(zoned.date())
+}
+
+/// Midnight UTC on the civil date. jiff's years fit in chrono's.
+fn to_chrono(date: civil::Date) -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(date.year().into(), date.month() as u32, date.day() as u32)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryInto;
+    use jiff::civil::date;
+
+    #[test]
+    fn test_civil_date() {
+        let era: &Era = date(2021, 11, 12).try_into().unwrap();
+        assert_eq!(era.kanji, Some("令和"));
+        assert_eq!(
+            to_jp_nenkou_string(date(2021, 11, 12)),
+            Some(String::from("令和３年１１月１２日"))
+        );
+        assert_eq!(
+            <&Era>::try_from(date(600, 1, 1)),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+
+    #[test]
+    fn test_zoned() {
+        let zoned: Zoned = "2019-05-01T08:00+09:00[+09:00]".parse().unwrap();
+        let era: &Era = (&zoned).try_into().unwrap();
+        assert_eq!(era.kanji, Some("令和"));
+        assert_eq!(
+            zoned_to_jp_nenkou_string(&zoned),
+            Some(String::from("令和元年５月１日"))
+        );
+    }
+}
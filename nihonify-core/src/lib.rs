@@ -0,0 +1,121 @@
+//! The data and calendar logic behind `nihonify`. Usable in `no_std`
+//! environments; string formatting and parsing need the `alloc` feature.
+//! Without it, `Era::from_unix_epoch` and `Era::from_ymd` look up eras from
+//! raw epochs and dates, and `WarekiDate`'s `Display` writes dates into any
+//! `core::fmt::Write`. Without the default `chrono` feature either, only the
+//! era table and those two lookups are left, with no dependencies.
+//!
+//! With the `jiff`, `time` and `icu_calendar` features, those crates' dates
+//! convert to eras or wareki dates with `TryFrom`. Dates from other time
+//! libraries go through the same raw inputs.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "chrono")]
+pub mod age;
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod calendar;
+#[cfg(feature = "alloc")]
+pub mod counters;
+pub mod eras;
+#[cfg(feature = "chrono")]
+pub mod error;
+pub mod eto;
+#[cfg(feature = "alloc")]
+pub mod excel;
+#[cfg(feature = "alloc")]
+pub mod ext;
+#[cfg(feature = "chrono")]
+pub mod fiscal;
+#[cfg(feature = "alloc")]
+pub mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "alloc")]
+pub mod holidays;
+#[cfg(feature = "icu_calendar")]
+mod icu_calendar;
+#[cfg(feature = "jiff")]
+pub mod jiff;
+#[cfg(feature = "ja-locale")]
+pub mod locale;
+#[cfg(feature = "chrono")]
+pub mod lunisolar;
+#[cfg(feature = "chrono")]
+pub mod numbers;
+#[cfg(feature = "alloc")]
+pub mod parse;
+#[cfg(feature = "private-eras")]
+pub mod private_eras;
+#[cfg(feature = "chrono")]
+pub mod reigns;
+#[cfg(feature = "chrono")]
+pub mod rokuyou;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "chrono")]
+pub mod sekki;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+pub mod table;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+#[cfg(feature = "chrono")]
+pub mod weeks;
+
+#[cfg(feature = "std")]
+pub use crate::calendar::SystemClock;
+#[cfg(feature = "chrono")]
+pub use crate::calendar::{Clock, DateRange, FixedClock, WarekiDate};
+pub use crate::calendar::{Court, Era, Jidai};
+#[cfg(feature = "chrono")]
+pub use crate::error::{Lang, NihonifyError};
+pub use crate::eto::{eto, Eto};
+#[cfg(feature = "alloc")]
+pub use crate::ext::Nihonify;
+#[cfg(feature = "alloc")]
+pub use crate::format::{
+    format_wareki, EnglishPreset, EraStyle, Fallback, JisEraCode, NenkouFormatter, Numerals,
+    RomanizationStyle, Separators, WeekdayStyle,
+};
+#[cfg(feature = "chrono")]
+pub use crate::numbers::parse_jp_number;
+#[cfg(feature = "std")]
+pub use crate::parse::parse_lenient;
+#[cfg(feature = "alloc")]
+pub use crate::parse::{
+    parse_century, parse_compact_wareki, parse_decade, parse_jidai_period, parse_jis_x0301,
+    parse_jun, parse_lenient_with, parse_romaji_wareki, parse_wareki_period_prefix,
+    parse_wareki_prefix, try_utc_dt, utc_dt,
+};
+#[cfg(feature = "chrono")]
+pub use crate::reigns::Reign;
+#[cfg(feature = "chrono")]
+pub use crate::rokuyou::Rokuyou;
+#[cfg(feature = "chrono")]
+pub use crate::sekki::Sekki;
+#[cfg(feature = "alloc")]
+pub use crate::table::ConversionTable;
+
+/// The commonly used items, for glob importing.
+pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use crate::calendar::SystemClock;
+    #[cfg(feature = "chrono")]
+    pub use crate::calendar::{Clock, FixedClock, WarekiDate};
+    pub use crate::calendar::{Era, Jidai};
+    #[cfg(feature = "chrono")]
+    pub use crate::error::NihonifyError;
+    #[cfg(feature = "alloc")]
+    pub use crate::ext::Nihonify;
+    #[cfg(feature = "alloc")]
+    pub use crate::format::{
+        EnglishPreset, EraStyle, Fallback, NenkouFormatter, Numerals, RomanizationStyle, Separators,
+    };
+}
@@ -0,0 +1,78 @@
+//! Japanese month and weekday names for chrono format strings, as chrono's
+//! `unstable-locales` would render them for ja_JP, without the locale tables.
+
+use alloc::string::{String, ToString};
+use chrono::prelude::*;
+use core::fmt;
+
+/// The month names, as `%B` renders them.
+pub const JA_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+/// The weekday names from Sunday, as `%A` renders them.
+pub const JA_WEEKDAYS: [&str; 7] = [
+    "日曜日",
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+];
+
+/// Formats the datetime with the chrono format string, rendering `%A`, `%a`,
+/// `%B`, `%b`, `%h` and `%p` in Japanese, e.g. 金曜日, 金, 11月 and 午後.
+/// Other specifiers are left to chrono.
+///
+/// Panics if `pattern` isn't a valid chrono format string.
+pub fn format_ja<Tz: TimeZone>(date: &DateTime<Tz>, pattern: &str) -> String
+where
+    Tz::Offset: fmt::Display,
+{
+    date.format(&localize_pattern(date, pattern)).to_string()
+}
+
+/// Replaces the locale-dependent specifiers in the pattern with the
+/// Japanese names for the datetime, keeping everything else as is.
+fn localize_pattern<Tz: TimeZone>(date: &DateTime<Tz>, pattern: &str) -> String {
+    let weekday = date.weekday().num_days_from_sunday() as usize;
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('A') => out.push_str(JA_WEEKDAYS[weekday]),
+            Some('a') => out.push_str(&JA_WEEKDAYS[weekday][..3]),
+            Some('B') | Some('b') | Some('h') => out.push_str(JA_MONTHS[date.month0() as usize]),
+            Some('p') => out.push_str(if date.hour() < 12 { "午前" } else { "午後" }),
+            Some(next) => {
+                out.push('%');
+                out.push(next);
+            }
+            None => out.push('%'),
+        }
+    }
+
+    out
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ja() {
+        let date = Utc.with_ymd_and_hms(2021, 11, 12, 22, 10, 57).unwrap();
+        assert_eq!(
+            format_ja(&date, "%Y年%B%-d日（%a） %p%I時"),
+            "2021年11月12日（金） 午後10時"
+        );
+        assert_eq!(format_ja(&date, "%A, %b %e"), "金曜日, 11月 12");
+        assert_eq!(format_ja(&date, "100%% %B"), "100% 11月");
+    }
+}
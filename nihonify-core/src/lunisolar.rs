@@ -0,0 +1,726 @@
+//! The lunisolar calendar (旧暦) Japan used until the end of 1872, when the
+//! Meiji government switched to the Gregorian calendar: 明治5年12月3日 became
+//! 1873-01-01.
+//!
+//! Only the years from 1590 are covered, which takes in the whole Edo period.
+//! The tables follow the rules of the calendars in use (Senmyō until 1684,
+//! then Jōkyō, Hōryaku, Kansei and, from 1844, Tenpō), worked out with modern
+//! astronomy for the meridian of Kyoto, so a month may start a day off from
+//! the almanacs of the time.
+//!
+//! From 1873 to 2100 the tables carry on the Tenpō rules for the meridian of
+//! Japan Standard Time, as almanacs still print the old calendar for 旧正月
+//! and the rokuyō. Where the rules can't all be met, in 2033, the leap month
+//! is 閏11月, as the Japan Calendar Association recommends.
+
+use crate::error::NihonifyError;
+use chrono::prelude::*;
+use chrono::Days;
+
+/// A day in the lunisolar calendar: the year, counted in the Gregorian years
+/// its New Year falls in, the month, whether it's the leap month (閏月) that
+/// repeats the month, and the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LunisolarDate {
+    year: i32,
+    month: u8,
+    leap: bool,
+    day: u8,
+}
+
+/// The lunisolar years, each with the day of the Gregorian year its New Year
+/// fell on, the lengths of its months, bit n set if the (n + 1)th month,
+/// counting the leap month, has 30 days rather than 29, and which month it
+/// repeats as a leap month, if any.
+const YEARS: &[(i32, u16, u16, u8)] = &[
+    (1590, 36, 0b010111010010, 0),
+    (1591, 25, 0b0110110100101, 3),
+    (1592, 44, 0b111010100101, 0),
+    (1593, 33, 0b0111001001010, 12),
+    (1594, 51, 0b011001001011, 0),
+    (1595, 40, 0b110010010111, 0),
+    (1596, 30, 0b1010101010110, 9),
+    (1597, 48, 0b010101011010, 0),
+    (1598, 37, 0b101011010101, 0),
+    (1599, 27, 0b1011011010010, 5),
+    (1600, 46, 0b011101010010, 0),
+    (1601, 34, 0b011100100101, 0),
+    (1602, 23, 0b1011001001011, 2),
+    (1603, 42, 0b011001001011, 0),
+    (1604, 31, 0b1010010011011, 9),
+    (1605, 49, 0b001010101101, 0),
+    (1606, 38, 0b010101101011, 0),
+    (1607, 28, 0b0101101101001, 7),
+    (1608, 47, 0b101110101001, 0),
+    (1609, 36, 0b101101010010, 0),
+    (1610, 25, 0b1101100100101, 3),
+    (1611, 44, 0b110100100101, 0),
+    (1612, 33, 0b1101001001101, 11),
+    (1613, 51, 0b101001010101, 0),
+    (1614, 40, 0b010010101101, 0),
+    (1615, 29, 0b0010110101101, 9),
+    (1616, 48, 0b010110110101, 0),
+    (1617, 37, 0b110110101001, 0),
+    (1618, 27, 0b1110110010010, 5),
+    (1619, 46, 0b111010010010, 0),
+    (1620, 35, 0b110100100101, 0),
+    (1621, 23, 0b1101001010101, 2),
+    (1622, 42, 0b101001010110, 0),
+    (1623, 31, 0b1010010110110, 10),
+    (1624, 50, 0b001011011010, 0),
+    (1625, 38, 0b011011010101, 0),
+    (1626, 28, 0b0111011001001, 6),
+    (1627, 47, 0b111101001001, 0),
+    (1628, 37, 0b111010010010, 0),
+    (1629, 25, 0b0110100100110, 4),
+    (1630, 43, 0b010100101011, 0),
+    (1631, 32, 0b0101001010111, 12),
+    (1632, 51, 0b101010101011, 0),
+    (1633, 40, 0b010101101010, 0),
+    (1634, 29, 0b0101011010101, 8),
+    (1635, 48, 0b101101100101, 0),
+    (1636, 38, 0b011101001001, 0),
+    (1637, 26, 0b1011010010011, 5),
+    (1638, 45, 0b101010010101, 0),
+    (1639, 34, 0b010100101011, 0),
+    (1640, 23, 0b0101001011011, 2),
+    (1641, 41, 0b101010101101, 0),
+    (1642, 31, 0b1010101101010, 10),
+    (1643, 50, 0b010110110010, 0),
+    (1644, 39, 0b101110100101, 0),
+    (1645, 28, 0b1110101001010, 6),
+    (1646, 47, 0b110101001010, 0),
+    (1647, 36, 0b101010010101, 0),
+    (1648, 25, 0b1010100101101, 4),
+    (1649, 43, 0b010101010110, 0),
+    (1650, 32, 0b0101010110101, 12),
+    (1651, 51, 0b101011010101, 0),
+    (1652, 41, 0b010111010010, 0),
+    (1653, 29, 0b0110110100101, 9),
+    (1654, 48, 0b111010100101, 0),
+    (1655, 38, 0b111001001010, 0),
+    (1656, 27, 0b0110010010110, 5),
+    (1657, 44, 0b110010010111, 0),
+    (1658, 34, 0b010101010110, 0),
+    (1659, 23, 0b0101010110101, 1),
+    (1660, 42, 0b101011011001, 0),
+    (1661, 31, 0b1011011010010, 11),
+    (1662, 50, 0b011101010010, 0),
+    (1663, 39, 0b011100100101, 0),
+    (1664, 28, 0b1011001001011, 7),
+    (1665, 46, 0b011001001011, 0),
+    (1666, 35, 0b010010101011, 0),
+    (1667, 24, 0b0100101011011, 3),
+    (1668, 43, 0b010101101011, 0),
+    (1669, 32, 0b0101101101010, 12),
+    (1670, 51, 0b101110101001, 0),
+    (1671, 41, 0b101110010010, 0),
+    (1672, 30, 0b1101101000101, 8),
+    (1673, 48, 0b110100100101, 0),
+    (1674, 37, 0b101001001101, 0),
+    (1675, 26, 0b1010010101011, 6),
+    (1676, 45, 0b010010101101, 0),
+    (1677, 33, 0b010110101101, 0),
+    (1678, 23, 0b0101110101010, 2),
+    (1679, 42, 0b110110101010, 0),
+    (1680, 32, 0b1110110010010, 10),
+    (1681, 50, 0b111010100010, 0),
+    (1682, 39, 0b110100100101, 0),
+    (1683, 28, 0b1101001010101, 7),
+    (1684, 47, 0b101001010110, 0),
+    (1685, 35, 0b010101011010, 0),
+    (1686, 24, 0b0101011010101, 3),
+    (1687, 43, 0b101101101001, 0),
+    (1688, 33, 0b1011101010010, 12),
+    (1689, 51, 0b011101010010, 0),
+    (1690, 40, 0b101100100101, 0),
+    (1691, 29, 0b1011001001011, 8),
+    (1692, 48, 0b101001001011, 0),
+    (1693, 36, 0b010010101011, 0),
+    (1694, 25, 0b0010101011011, 5),
+    (1695, 44, 0b010110101101, 0),
+    (1696, 34, 0b101101101001, 0),
+    (1697, 23, 0b1101101010010, 2),
+    (1698, 42, 0b110110010010, 0),
+    (1699, 31, 0b1110100100101, 9),
+    (1700, 50, 0b110100100101, 0),
+    (1701, 39, 0b101001010101, 0),
+    (1702, 28, 0b1010010101101, 7),
+    (1703, 47, 0b001010110110, 0),
+    (1704, 36, 0b010110110101, 0),
+    (1705, 25, 0b0110110101001, 3),
+    (1706, 44, 0b111011001001, 0),
+    (1707, 34, 0b111010010010, 0),
+    (1708, 23, 0b1110100100101, 1),
+    (1709, 41, 0b110100100110, 0),
+    (1710, 30, 0b0101001010110, 8),
+    (1711, 48, 0b101001010111, 0),
+    (1712, 38, 0b010011010110, 0),
+    (1713, 26, 0b0011011010101, 5),
+    (1714, 45, 0b011011010101, 0),
+    (1715, 35, 0b111011001001, 0),
+    (1716, 25, 0b0111010010010, 2),
+    (1717, 42, 0b011010010011, 0),
+    (1718, 31, 0b1010100101011, 9),
+    (1719, 50, 0b010100101011, 0),
+    (1720, 39, 0b101001011011, 0),
+    (1721, 28, 0b1010101011010, 7),
+    (1722, 47, 0b010101101010, 0),
+    (1723, 36, 0b101101100101, 0),
+    (1724, 26, 0b1011101001001, 4),
+    (1725, 44, 0b101101001001, 0),
+    (1726, 33, 0b1101010010101, 12),
+    (1727, 52, 0b101010010101, 0),
+    (1728, 41, 0b010100101101, 0),
+    (1729, 29, 0b0101010101101, 8),
+    (1730, 48, 0b101010110101, 0),
+    (1731, 38, 0b010110101010, 0),
+    (1732, 27, 0b0101110100101, 4),
+    (1733, 45, 0b110110100101, 0),
+    (1734, 35, 0b110101001010, 0),
+    (1735, 24, 0b1101010010101, 2),
+    (1736, 43, 0b110010010110, 0),
+    (1737, 31, 0b1010101001110, 10),
+    (1738, 50, 0b010101010110, 0),
+    (1739, 39, 0b101010110101, 0),
+    (1740, 29, 0b1010110110010, 6),
+    (1741, 47, 0b011011010010, 0),
+    (1742, 36, 0b111010100101, 0),
+    (1743, 26, 0b1111001001010, 4),
+    (1744, 45, 0b011001001010, 0),
+    (1745, 32, 0b0110010010111, 12),
+    (1746, 51, 0b010010101011, 0),
+    (1747, 40, 0b010101011011, 0),
+    (1748, 30, 0b0101011010110, 9),
+    (1749, 48, 0b101101101001, 0),
+    (1750, 38, 0b011101010010, 0),
+    (1751, 27, 0b0111100100101, 5),
+    (1752, 46, 0b101100100101, 0),
+    (1753, 34, 0b011001001011, 0),
+    (1754, 23, 0b1010010011011, 2),
+    (1755, 42, 0b010010101011, 0),
+    (1756, 31, 0b0010101101011, 11),
+    (1757, 49, 0b010110101101, 0),
+    (1758, 39, 0b101110101010, 0),
+    (1759, 29, 0b1101101010010, 7),
+    (1760, 48, 0b110110010010, 0),
+    (1761, 36, 0b110100100101, 0),
+    (1762, 25, 0b1101001001011, 4),
+    (1763, 44, 0b101001010101, 0),
+    (1764, 33, 0b1010010101101, 11),
+    (1765, 51, 0b010010110110, 0),
+    (1766, 40, 0b010110110101, 0),
+    (1767, 30, 0b0110110101010, 9),
+    (1768, 49, 0b111011001001, 0),
+    (1769, 38, 0b111010010010, 0),
+    (1770, 27, 0b1110100100101, 6),
+    (1771, 46, 0b110100100110, 0),
+    (1772, 35, 0b101001010110, 0),
+    (1773, 23, 0b1010010110110, 2),
+    (1774, 42, 0b010101010110, 0),
+    (1775, 31, 0b0011011010101, 11),
+    (1776, 50, 0b011101010101, 0),
+    (1777, 39, 0b111101001001, 0),
+    (1778, 29, 0b0111010010010, 6),
+    (1779, 47, 0b011010010011, 0),
+    (1780, 36, 0b010100101011, 0),
+    (1781, 24, 0b0101001010111, 4),
+    (1782, 43, 0b101010011011, 0),
+    (1783, 33, 0b1010101011010, 12),
+    (1784, 52, 0b010101101010, 0),
+    (1785, 40, 0b101101100101, 0),
+    (1786, 30, 0b1011101001010, 8),
+    (1787, 49, 0b101101001001, 0),
+    (1788, 38, 0b101010010101, 0),
+    (1789, 26, 0b1010100101011, 6),
+    (1790, 45, 0b010101001101, 0),
+    (1791, 34, 0b101010101101, 0),
+    (1792, 24, 0b1010101101010, 2),
+    (1793, 42, 0b010110101010, 0),
+    (1794, 31, 0b0101110100101, 11),
+    (1795, 50, 0b110110100101, 0),
+    (1796, 40, 0b110101001010, 0),
+    (1797, 28, 0b1110100010101, 7),
+    (1798, 47, 0b110010010110, 0),
+    (1799, 36, 0b100101001110, 0),
+    (1800, 25, 0b0101010101101, 4),
+    (1801, 44, 0b101011010101, 0),
+    (1802, 34, 0b010110110010, 0),
+    (1803, 23, 0b0110110100101, 1),
+    (1804, 42, 0b111010100101, 0),
+    (1805, 31, 0b1111010001010, 8),
+    (1806, 50, 0b011010001010, 0),
+    (1807, 38, 0b110010010111, 0),
+    (1808, 28, 0b0100101010110, 5),
+    (1809, 45, 0b010101011011, 0),
+    (1810, 35, 0b101011011010, 0),
+    (1811, 25, 0b1011011010100, 1),
+    (1812, 44, 0b011101010010, 0),
+    (1813, 32, 0b1011101000101, 11),
+    (1814, 51, 0b101101000101, 0),
+    (1815, 40, 0b101010001011, 0),
+    (1816, 29, 0b1010010101011, 8),
+    (1817, 47, 0b010010101101, 0),
+    (1818, 36, 0b100101101011, 0),
+    (1819, 26, 0b0101101011010, 4),
+    (1820, 45, 0b101110101010, 0),
+    (1821, 34, 0b101101010010, 0),
+    (1822, 23, 0b1101101000101, 1),
+    (1823, 42, 0b110101000101, 0),
+    (1824, 31, 0b1101010001011, 8),
+    (1825, 49, 0b101010010101, 0),
+    (1826, 38, 0b010010101101, 0),
+    (1827, 27, 0b0100110101101, 6),
+    (1828, 46, 0b011010110101, 0),
+    (1829, 35, 0b110110101010, 0),
+    (1830, 25, 0b1110110010100, 2),
+    (1831, 44, 0b111010100010, 0),
+    (1832, 33, 0b1110101000101, 10),
+    (1833, 51, 0b110101001010, 0),
+    (1834, 40, 0b101010010110, 0),
+    (1835, 29, 0b1010100110110, 7),
+    (1836, 48, 0b010101011010, 0),
+    (1837, 36, 0b101011010101, 0),
+    (1838, 26, 0b1011011001010, 4),
+    (1839, 45, 0b011101010010, 0),
+    (1840, 34, 0b111010100011, 0),
+    (1841, 23, 0b0110101001010, 1),
+    (1842, 41, 0b010101001011, 0),
+    (1843, 30, 0b0101010010111, 9),
+    (1844, 49, 0b101010101011, 0),
+    (1845, 38, 0b010101011010, 0),
+    (1846, 27, 0b0101011010101, 5),
+    (1847, 46, 0b101101100101, 0),
+    (1848, 36, 0b011101010010, 0),
+    (1849, 24, 0b1011010100101, 4),
+    (1850, 43, 0b101100100101, 0),
+    (1851, 32, 0b010101001011, 0),
+    (1852, 21, 0b0101010011011, 2),
+    (1853, 39, 0b101010101101, 0),
+    (1854, 29, 0b1010101101010, 7),
+    (1855, 48, 0b010110110010, 0),
+    (1856, 37, 0b101110101001, 0),
+    (1857, 26, 0b1101101010010, 5),
+    (1858, 45, 0b110110010010, 0),
+    (1859, 34, 0b110100100101, 0),
+    (1860, 23, 0b1101001001101, 3),
+    (1861, 41, 0b100101010110, 0),
+    (1862, 30, 0b0101010101101, 8),
+    (1863, 49, 0b101011010110, 0),
+    (1864, 39, 0b010111010100, 0),
+    (1865, 27, 0b0110110101001, 5),
+    (1866, 46, 0b111011000101, 0),
+    (1867, 36, 0b111010001010, 0),
+    (1868, 25, 0b0110100100110, 4),
+    (1869, 42, 0b110100100111, 0),
+    (1870, 32, 0b0100101010110, 10),
+    (1871, 50, 0b010101011011, 0),
+    (1872, 40, 0b101011011010, 0),
+    (1873, 29, 0b1011011010100, 6),
+    (1874, 48, 0b011101010100, 0),
+    (1875, 37, 0b011101000101, 0),
+    (1876, 26, 0b1011010001011, 5),
+    (1877, 44, 0b101010010011, 0),
+    (1878, 33, 0b010100101011, 0),
+    (1879, 22, 0b0100101011011, 3),
+    (1880, 41, 0b100101101011, 0),
+    (1881, 30, 0b0101101101010, 7),
+    (1882, 49, 0b101110101010, 0),
+    (1883, 39, 0b101110010100, 0),
+    (1884, 28, 0b1101101001001, 5),
+    (1885, 46, 0b110101000101, 0),
+    (1886, 35, 0b101010010101, 0),
+    (1887, 24, 0b1010100101011, 4),
+    (1888, 43, 0b010100101101, 0),
+    (1889, 31, 0b0101010101101, 12),
+    (1890, 50, 0b011010110101, 0),
+    (1891, 40, 0b110110101010, 0),
+    (1892, 30, 0b1110110100100, 6),
+    (1893, 48, 0b111010100100, 0),
+    (1894, 37, 0b110101001010, 0),
+    (1895, 26, 0b1101010010101, 5),
+    (1896, 45, 0b101010010110, 0),
+    (1897, 33, 0b010100110110, 0),
+    (1898, 22, 0b0101010110101, 3),
+    (1899, 41, 0b101011010101, 0),
+    (1900, 31, 0b1011011010010, 8),
+    (1901, 50, 0b011101010010, 0),
+    (1902, 39, 0b111010100101, 0),
+    (1903, 29, 0b0110101001010, 5),
+    (1904, 47, 0b010101001011, 0),
+    (1905, 35, 0b101010010111, 0),
+    (1906, 25, 0b1010101010110, 4),
+    (1907, 44, 0b010101011010, 0),
+    (1908, 33, 0b101101010101, 0),
+    (1909, 22, 0b1011011010010, 2),
+    (1910, 41, 0b011101010010, 0),
+    (1911, 30, 0b1011100100101, 6),
+    (1912, 49, 0b101100100101, 0),
+    (1913, 37, 0b101001001011, 0),
+    (1914, 26, 0b1001010011011, 5),
+    (1915, 45, 0b101010101101, 0),
+    (1916, 35, 0b010101101010, 0),
+    (1917, 23, 0b0101101101001, 2),
+    (1918, 42, 0b101110101001, 0),
+    (1919, 32, 0b1101101010010, 7),
+    (1920, 51, 0b110110010010, 0),
+    (1921, 39, 0b110100100101, 0),
+    (1922, 28, 0b1101001001101, 5),
+    (1923, 47, 0b100101010110, 0),
+    (1924, 36, 0b001010110101, 0),
+    (1925, 24, 0b1010110101101, 4),
+    (1926, 44, 0b011011010100, 0),
+    (1927, 33, 0b110110101001, 0),
+    (1928, 23, 0b1110110010010, 2),
+    (1929, 41, 0b111010010010, 0),
+    (1930, 30, 0b0110100100110, 6),
+    (1931, 48, 0b010100100111, 0),
+    (1932, 37, 0b101001010111, 0),
+    (1933, 26, 0b1001010110110, 5),
+    (1934, 45, 0b101011011010, 0),
+    (1935, 35, 0b011011010100, 0),
+    (1936, 24, 0b0111010101001, 3),
+    (1937, 42, 0b011101001001, 0),
+    (1938, 31, 0b1011010010011, 7),
+    (1939, 50, 0b101010010011, 0),
+    (1940, 39, 0b010100101011, 0),
+    (1941, 27, 0b0101001011011, 6),
+    (1942, 46, 0b100101101101, 0),
+    (1943, 36, 0b101101101010, 0),
+    (1944, 26, 0b1101101010100, 4),
+    (1945, 44, 0b101110100100, 0),
+    (1946, 33, 0b101101001001, 0),
+    (1947, 22, 0b1101010010011, 2),
+    (1948, 41, 0b101010010101, 0),
+    (1949, 29, 0b1010100101011, 7),
+    (1950, 48, 0b010100101101, 0),
+    (1951, 37, 0b101010101101, 0),
+    (1952, 27, 0b1010101101010, 5),
+    (1953, 45, 0b110110110010, 0),
+    (1954, 35, 0b110110100100, 0),
+    (1955, 24, 0b1110101001001, 3),
+    (1956, 43, 0b110101001010, 0),
+    (1957, 31, 0b1101010010101, 8),
+    (1958, 50, 0b101010010110, 0),
+    (1959, 39, 0b010101010110, 0),
+    (1960, 28, 0b0101010110101, 6),
+    (1961, 46, 0b101011010101, 0),
+    (1962, 36, 0b011011010010, 0),
+    (1963, 25, 0b0111010100101, 4),
+    (1964, 44, 0b111010100101, 0),
+    (1965, 33, 0b111001001010, 0),
+    (1966, 22, 0b0110010010110, 3),
+    (1967, 40, 0b101010011011, 0),
+    (1968, 30, 0b1010101010110, 7),
+    (1969, 48, 0b010101101010, 0),
+    (1970, 37, 0b101101011001, 0),
+    (1971, 27, 0b1011101010010, 5),
+    (1972, 46, 0b011101010010, 0),
+    (1973, 34, 0b011100100101, 0),
+    (1974, 23, 0b1011001001011, 4),
+    (1975, 42, 0b101001001011, 0),
+    (1976, 31, 0b1001010101011, 8),
+    (1977, 49, 0b001010101101, 0),
+    (1978, 38, 0b010101101011, 0),
+    (1979, 28, 0b0101101101001, 6),
+    (1980, 47, 0b110110101001, 0),
+    (1981, 36, 0b110110010010, 0),
+    (1982, 25, 0b1101100100101, 4),
+    (1983, 44, 0b110100100101, 0),
+    (1984, 33, 0b1101001001101, 10),
+    (1985, 51, 0b101001010110, 0),
+    (1986, 40, 0b001010110110, 0),
+    (1987, 29, 0b1010110101101, 6),
+    (1988, 49, 0b011011010100, 0),
+    (1989, 37, 0b110110101001, 0),
+    (1990, 27, 0b1110110010010, 5),
+    (1991, 46, 0b111010010010, 0),
+    (1992, 35, 0b110100100110, 0),
+    (1993, 23, 0b0101001010110, 3),
+    (1994, 41, 0b101001010111, 0),
+    (1995, 31, 0b1001010110110, 8),
+    (1996, 50, 0b101101011010, 0),
+    (1997, 39, 0b011011010100, 0),
+    (1998, 28, 0b0111011001001, 5),
+    (1999, 47, 0b011101001001, 0),
+    (2000, 36, 0b011010010011, 0),
+    (2001, 24, 0b1010100100111, 4),
+    (2002, 43, 0b010100101011, 0),
+    (2003, 32, 0b101001011011, 0),
+    (2004, 22, 0b1010101011010, 2),
+    (2005, 40, 0b001101101010, 0),
+    (2006, 29, 0b1101101010101, 7),
+    (2007, 49, 0b101110100100, 0),
+    (2008, 38, 0b101101001001, 0),
+    (2009, 26, 0b1101010010011, 5),
+    (2010, 45, 0b101010010101, 0),
+    (2011, 34, 0b010100101101, 0),
+    (2012, 23, 0b0101001011101, 3),
+    (2013, 41, 0b101010101101, 0),
+    (2014, 31, 0b1010110101010, 9),
+    (2015, 50, 0b010111010010, 0),
+    (2016, 39, 0b110110100101, 0),
+    (2017, 28, 0b1110101001010, 5),
+    (2018, 47, 0b110101001010, 0),
+    (2019, 36, 0b101010010101, 0),
+    (2020, 25, 0b1010100101101, 4),
+    (2021, 43, 0b010101010110, 0),
+    (2022, 32, 0b101010110101, 0),
+    (2023, 22, 0b1010110101010, 2),
+    (2024, 41, 0b011011010010, 0),
+    (2025, 29, 0b0111010100101, 6),
+    (2026, 48, 0b111010100101, 0),
+    (2027, 38, 0b111001001010, 0),
+    (2028, 27, 0b0110010010110, 5),
+    (2029, 44, 0b110010011011, 0),
+    (2030, 34, 0b010101011010, 0),
+    (2031, 23, 0b0101011010101, 3),
+    (2032, 42, 0b101101101001, 0),
+    (2033, 31, 0b1011101010010, 11),
+    (2034, 50, 0b011101010010, 0),
+    (2035, 39, 0b101100100101, 0),
+    (2036, 28, 0b1011001001011, 6),
+    (2037, 46, 0b101001001011, 0),
+    (2038, 35, 0b010010101011, 0),
+    (2039, 24, 0b0010101011011, 5),
+    (2040, 43, 0b010101101101, 0),
+    (2041, 32, 0b101101101001, 0),
+    (2042, 22, 0b1101101010010, 2),
+    (2043, 41, 0b110110010010, 0),
+    (2044, 30, 0b1110100100101, 7),
+    (2045, 48, 0b110100100101, 0),
+    (2046, 37, 0b101001001101, 0),
+    (2047, 26, 0b1010010101101, 5),
+    (2048, 45, 0b001010110110, 0),
+    (2049, 33, 0b010110110101, 0),
+    (2050, 23, 0b0110110101001, 3),
+    (2051, 42, 0b111010101001, 0),
+    (2052, 32, 0b1110110010010, 8),
+    (2053, 50, 0b111010010010, 0),
+    (2054, 39, 0b110100100110, 0),
+    (2055, 28, 0b0101001010110, 6),
+    (2056, 46, 0b101001010111, 0),
+    (2057, 35, 0b010011010110, 0),
+    (2058, 24, 0b0011010110101, 4),
+    (2059, 43, 0b011011010101, 0),
+    (2060, 33, 0b111011001001, 0),
+    (2061, 22, 0b0111010010010, 3),
+    (2062, 40, 0b011010010011, 0),
+    (2063, 29, 0b1010100101011, 7),
+    (2064, 48, 0b010100101011, 0),
+    (2065, 36, 0b101001011011, 0),
+    (2066, 26, 0b1010101011010, 5),
+    (2067, 45, 0b010101101010, 0),
+    (2068, 34, 0b101101010101, 0),
+    (2069, 23, 0b1011101001001, 4),
+    (2070, 42, 0b101101001001, 0),
+    (2071, 31, 0b1101010010011, 8),
+    (2072, 50, 0b101010010101, 0),
+    (2073, 38, 0b010100101101, 0),
+    (2074, 27, 0b0101010101101, 6),
+    (2075, 46, 0b101010110101, 0),
+    (2076, 36, 0b010110101010, 0),
+    (2077, 24, 0b0101110100101, 4),
+    (2078, 43, 0b110110100101, 0),
+    (2079, 33, 0b110101001010, 0),
+    (2080, 22, 0b1101010010101, 3),
+    (2081, 40, 0b110010010101, 0),
+    (2082, 29, 0b1010100101110, 7),
+    (2083, 48, 0b010101010110, 0),
+    (2084, 37, 0b101010110101, 0),
+    (2085, 26, 0b1010110110010, 5),
+    (2086, 45, 0b011011010010, 0),
+    (2087, 34, 0b111010100101, 0),
+    (2088, 24, 0b1111001001010, 4),
+    (2089, 42, 0b011001001010, 0),
+    (2090, 30, 0b0110010010111, 8),
+    (2091, 49, 0b110010101011, 0),
+    (2092, 39, 0b010101011010, 0),
+    (2093, 27, 0b0101011010101, 6),
+    (2094, 46, 0b101101101001, 0),
+    (2095, 36, 0b011101010010, 0),
+    (2096, 25, 0b1011010100101, 4),
+    (2097, 43, 0b101100100101, 0),
+    (2098, 32, 0b011001001011, 0),
+    (2099, 21, 0b1010010010111, 3),
+    (2100, 40, 0b010010101011, 0),
+];
+
+impl LunisolarDate {
+    /// The day, if it exists in the tables, e.g. not 閏1月 of a year without
+    /// one, or the 30th of a 29-day month.
+    pub fn new(year: i32, month: u8, leap: bool, day: u8) -> Result<LunisolarDate, NihonifyError> {
+        let date = LunisolarDate {
+            year,
+            month,
+            leap,
+            day,
+        };
+        date.to_gregorian()?;
+        Ok(date)
+    }
+
+    /// The lunisolar date of the Gregorian date, if the tables cover it.
+    pub fn from_gregorian(date: NaiveDate) -> Result<LunisolarDate, NihonifyError> {
+        let index =
+            YEARS.partition_point(|&(year, new_year, _, _)| new_year_of(year, new_year) <= date);
+        let &(year, new_year, lengths, leap_month) = index
+            .checked_sub(1)
+            .map(|index| &YEARS[index])
+            .ok_or(NihonifyError::DateOutOfRange)?;
+        let mut days = (date - new_year_of(year, new_year)).num_days() as u32;
+        let mut month = 1;
+        for (n, length) in month_lengths(lengths, leap_month).enumerate() {
+            if days < length {
+                let leap = leap_month != 0 && n == leap_month as usize;
+                return Ok(LunisolarDate {
+                    year,
+                    month,
+                    leap,
+                    day: days as u8 + 1,
+                });
+            }
+            days -= length;
+            if leap_month == 0 || n + 1 != leap_month as usize {
+                month += 1;
+            }
+        }
+
+        // Past the end of the last year in the tables.
+        Err(NihonifyError::DateOutOfRange)
+    }
+
+    /// The Gregorian date.
+    pub fn to_gregorian(&self) -> Result<NaiveDate, NihonifyError> {
+        let &(_, new_year, lengths, leap_month) = YEARS
+            .binary_search_by_key(&self.year, |&(year, _, _, _)| year)
+            .map(|index| &YEARS[index])
+            .map_err(|_| NihonifyError::DateOutOfRange)?;
+        if self.month == 0 || self.month > 12 || (self.leap && self.month != leap_month) {
+            return Err(NihonifyError::ParseError);
+        }
+
+        // The slot of the month among the year's months, counting the leap
+        // month.
+        let slot = self.month as usize - 1
+            + usize::from(leap_month != 0 && (self.month > leap_month || self.leap));
+        let length = month_lengths(lengths, leap_month).nth(slot).unwrap();
+        if self.day == 0 || u32::from(self.day) > length {
+            return Err(NihonifyError::ParseError);
+        }
+
+        let days: u32 = month_lengths(lengths, leap_month).take(slot).sum();
+        Ok(new_year_of(self.year, new_year) + Days::new(u64::from(days) + u64::from(self.day) - 1))
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Whether this is the leap month, which repeats `month`.
+    pub fn is_leap_month(&self) -> bool {
+        self.leap
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// The day the lunisolar calendar was last used, 明治5年12月2日.
+#[cfg(feature = "alloc")]
+pub(crate) fn last_day() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1872, 12, 31).unwrap()
+}
+
+fn new_year_of(year: i32, ordinal: u16) -> NaiveDate {
+    NaiveDate::from_yo_opt(year, ordinal.into()).unwrap()
+}
+
+/// The lengths of the year's months in days, counting the leap month.
+fn month_lengths(lengths: u16, leap_month: u8) -> impl Iterator<Item = u32> {
+    let months = if leap_month == 0 { 12 } else { 13 };
+    (0..months).map(move |n| if lengths & (1 << n) != 0 { 30 } else { 29 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn lunisolar(y: i32, m: u32, d: u32) -> (i32, u8, bool, u8) {
+        let date = LunisolarDate::from_gregorian(ymd(y, m, d)).unwrap();
+        (date.year(), date.month(), date.is_leap_month(), date.day())
+    }
+
+    #[test]
+    fn test_from_gregorian() {
+        // The Battle of Sekigahara, 慶長5年9月15日.
+        assert_eq!(lunisolar(1600, 10, 21), (1600, 9, false, 15));
+        // The Akō vendetta, 元禄15年12月14日, fell in January 1703.
+        assert_eq!(lunisolar(1703, 1, 30), (1702, 12, false, 14));
+        // The Sakuradamon Incident, 安政7年3月3日.
+        assert_eq!(lunisolar(1860, 3, 24), (1860, 3, false, 3));
+        // The return of power to the emperor, 慶応3年10月14日.
+        assert_eq!(lunisolar(1867, 11, 9), (1867, 10, false, 14));
+        // 明治元年閏4月.
+        assert_eq!(lunisolar(1868, 6, 1), (1868, 4, true, 11));
+        // The last day before the calendar reform, 明治5年12月2日, and the
+        // old calendar carried on past it.
+        assert_eq!(lunisolar(1872, 12, 31), (1872, 12, false, 2));
+        assert_eq!(lunisolar(1873, 1, 1), (1872, 12, false, 3));
+        // 旧正月 in recent years, and the leap months of 2023 and 2033.
+        assert_eq!(lunisolar(2021, 2, 12), (2021, 1, false, 1));
+        assert_eq!(lunisolar(2023, 3, 22), (2023, 2, true, 1));
+        assert_eq!(lunisolar(2033, 12, 22), (2033, 11, true, 1));
+        assert_eq!(
+            LunisolarDate::from_gregorian(ymd(2101, 12, 31)),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            LunisolarDate::from_gregorian(ymd(1500, 1, 1)),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        let gregorian = |y, m, leap, d| LunisolarDate::new(y, m, leap, d)?.to_gregorian();
+        assert_eq!(gregorian(1853, 6, false, 3), Ok(ymd(1853, 7, 8)));
+        assert_eq!(gregorian(1860, 3, true, 1), Ok(ymd(1860, 4, 21)));
+        assert_eq!(gregorian(1860, 4, true, 1), Err(NihonifyError::ParseError));
+        assert_eq!(
+            gregorian(1860, 13, false, 1),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            gregorian(1860, 1, false, 31),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(gregorian(1872, 12, false, 3), Ok(ymd(1873, 1, 1)));
+        assert_eq!(
+            gregorian(2101, 1, false, 1),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            gregorian(1500, 1, false, 1),
+            Err(NihonifyError::DateOutOfRange)
+        );
+
+        // Every covered day round-trips, and the tables run without a gap.
+        let mut date = new_year_of(YEARS[0].0, YEARS[0].1);
+        while LunisolarDate::from_gregorian(date).is_ok() {
+            let lunisolar = LunisolarDate::from_gregorian(date).unwrap();
+            assert_eq!(lunisolar.to_gregorian(), Ok(date));
+            date = date.succ_opt().unwrap();
+        }
+        assert_eq!(date.year(), 2101);
+    }
+}
@@ -0,0 +1,484 @@
+//! Japanese numbers: full-width digits, myriad grouping, kanji numerals and
+//! daiji, and parsing any of them back, independently of the era code.
+//!
+//! ```
+//! use nihonify_core::numbers::{parse_jp_number, to_jp_intstring, to_kanji_numeral};
+//!
+//! assert_eq!(to_jp_intstring(31), "３１");
+//! assert_eq!(to_kanji_numeral(31), "三十一");
+//! assert_eq!(parse_jp_number("三十一"), Ok(31));
+//! ```
+
+use crate::error::NihonifyError;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Full-width digits are shifted 65,248 slots away from ASCII digits in
+/// Unicode character space.
+#[cfg(feature = "alloc")]
+const FULL_WIDTH_SHIFT: u32 = 65248;
+
+/// Makes a string of the uint and converts the ASCII 0-9 to the Japanese ０−９.
+#[cfg(feature = "alloc")]
+pub fn to_jp_intstring(num: u32) -> String {
+    to_full_width_digits(&num.to_string())
+}
+
+/// Converts the ASCII digits in the string to full-width ones, leaving
+/// everything else as is, e.g. 令和３年 for 令和3年.
+#[cfg(feature = "alloc")]
+pub fn to_full_width_digits(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '0'..='9' => char::from_u32(c as u32 + FULL_WIDTH_SHIFT).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts the full-width digits in the string to ASCII ones, leaving
+/// everything else as is, e.g. 令和3年 for 令和３年.
+#[cfg(feature = "alloc")]
+pub fn to_ascii_digits(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - FULL_WIDTH_SHIFT).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// The characters a system of kanji numerals is written with.
+#[cfg(feature = "alloc")]
+struct NumeralSet {
+    /// The digits from zero to nine.
+    digits: [char; 10],
+    /// The units 千, 百 and 十 within a myriad group.
+    units: [char; 3],
+    /// Whether a digit of one is written before 十, 百 and 千.
+    explicit_one: bool,
+}
+
+#[cfg(feature = "alloc")]
+const KANJI: NumeralSet = NumeralSet {
+    digits: ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+    units: ['千', '百', '十'],
+    explicit_one: false,
+};
+
+/// The daiji the family register and commercial laws prescribe for 一, 二, 三
+/// and 十, as the plain numerals can be turned into larger ones with a stroke
+/// or two. Every one is written out so that none can be added in front.
+#[cfg(feature = "alloc")]
+const DAIJI: NumeralSet = NumeralSet {
+    digits: ['零', '壱', '弐', '参', '四', '五', '六', '七', '八', '九'],
+    units: ['千', '百', '拾'],
+    explicit_one: true,
+};
+
+/// The myriad units, each 10,000 times the next, down to the ones.
+#[cfg(feature = "alloc")]
+const MYRIADS: [(u64, &str); 5] = [
+    (10_000_000_000_000_000, "京"),
+    (1_000_000_000_000, "兆"),
+    (100_000_000, "億"),
+    (10_000, "万"),
+    (1, ""),
+];
+
+/// Writes the uint in positional kanji numerals, e.g. 十一, 三十一 or 二千二十一.
+/// Digits are grouped by myriads up to 京, e.g. 一兆二億 for 1,000,200,000,000.
+#[cfg(feature = "alloc")]
+pub fn to_kanji_numeral(num: u64) -> String {
+    write_numeral(num, &KANJI)
+}
+
+/// Writes the uint in ASCII digits grouped by myriads, as reports write large
+/// numbers, e.g. 1億2345万6789 for 123,456,789. Empty groups are left out,
+/// e.g. 1億6789. See `to_kanji_numeral` for kanji only.
+#[cfg(feature = "alloc")]
+pub fn to_myriad_string(num: u64) -> String {
+    if num == 0 {
+        return num.to_string();
+    }
+
+    let mut out = String::new();
+    for &(unit, name) in &MYRIADS {
+        let group = num / unit % 10_000;
+        if group > 0 {
+            out.push_str(&group.to_string());
+            out.push_str(name);
+        }
+    }
+
+    out
+}
+
+/// Writes the uint in daiji, the formal numerals legal and financial
+/// documents use to prevent tampering, e.g. 壱万弐千参拾 for 12,030.
+#[cfg(feature = "alloc")]
+pub fn to_daiji_numeral(num: u64) -> String {
+    write_numeral(num, &DAIJI)
+}
+
+#[cfg(feature = "alloc")]
+fn write_numeral(num: u64, set: &NumeralSet) -> String {
+    if num == 0 {
+        return set.digits[0].to_string();
+    }
+
+    let mut out = String::new();
+    for &(unit, name) in &MYRIADS {
+        let group = (num / unit % 10_000) as u32;
+        if group > 0 {
+            push_group(&mut out, group, set);
+            out.push_str(name);
+        }
+    }
+
+    out
+}
+
+/// Writes a group of up to four digits. Unless the set says otherwise, a digit
+/// of one before 十, 百 or 千 is left out, as in 十一 and 百.
+#[cfg(feature = "alloc")]
+fn push_group(out: &mut String, group: u32, set: &NumeralSet) {
+    for &(unit, name) in &[
+        (1000, Some(set.units[0])),
+        (100, Some(set.units[1])),
+        (10, Some(set.units[2])),
+        (1, None),
+    ] {
+        let digit = group / unit % 10;
+        if digit == 0 {
+            continue;
+        }
+        if digit > 1 || name.is_none() || set.explicit_one {
+            out.push(set.digits[digit as usize]);
+        }
+        out.extend(name);
+    }
+}
+
+/// The readings of the digits one to nine.
+#[cfg(feature = "alloc")]
+const DIGIT_READINGS: [&str; 10] = [
+    "",
+    "いち",
+    "に",
+    "さん",
+    "よん",
+    "ご",
+    "ろく",
+    "なな",
+    "はち",
+    "きゅう",
+];
+
+/// Reads the uint out in hiragana, e.g. さんびゃくろくじゅうご for 365, with
+/// the sound changes of 百, 千, 兆 and 京, as in ろっぴゃく and はっせん.
+#[cfg(feature = "alloc")]
+pub fn to_reading(num: u64) -> String {
+    reading_parts(num).concat()
+}
+
+/// The morphemes `to_reading` joins, so that counters can change the sound of
+/// the last one, e.g. いち becomes いっ in いっぽん.
+#[cfg(feature = "alloc")]
+pub(crate) fn reading_parts(num: u64) -> Vec<&'static str> {
+    if num == 0 {
+        return vec!["ゼロ"];
+    }
+
+    let mut parts = Vec::new();
+    for &(unit, name) in &MYRIADS {
+        let group = num / unit % 10_000;
+        if group == 0 {
+            continue;
+        }
+        push_group_reading(&mut parts, group, !name.is_empty());
+        let myriad = match name {
+            "万" => "まん",
+            "億" => "おく",
+            "兆" => "ちょう",
+            "京" => "けい",
+            _ => continue,
+        };
+        // 兆 and 京 geminate the numbers before them, as in いっちょう.
+        if myriad == "ちょう" || myriad == "けい" {
+            let last = parts.last_mut().unwrap();
+            if let Some(part) = geminated(last) {
+                *last = part;
+            }
+        }
+        parts.push(myriad);
+    }
+
+    parts
+}
+
+#[cfg(feature = "alloc")]
+fn push_group_reading(parts: &mut Vec<&'static str>, group: u64, before_myriad: bool) {
+    match group / 1000 {
+        0 => (),
+        // 一千 is read out right before a myriad, as in いっせんまん.
+        1 if before_myriad && group == 1000 => parts.extend(&["いっ", "せん"]),
+        1 => parts.push("せん"),
+        3 => parts.extend(&["さん", "ぜん"]),
+        8 => parts.extend(&["はっ", "せん"]),
+        digit => parts.extend(&[DIGIT_READINGS[digit as usize], "せん"]),
+    }
+    match group / 100 % 10 {
+        0 => (),
+        1 => parts.push("ひゃく"),
+        3 => parts.extend(&["さん", "びゃく"]),
+        6 => parts.extend(&["ろっ", "ぴゃく"]),
+        8 => parts.extend(&["はっ", "ぴゃく"]),
+        digit => parts.extend(&[DIGIT_READINGS[digit as usize], "ひゃく"]),
+    }
+    match group / 10 % 10 {
+        0 => (),
+        1 => parts.push("じゅう"),
+        digit => parts.extend(&[DIGIT_READINGS[digit as usize], "じゅう"]),
+    }
+    match group % 10 {
+        0 => (),
+        digit => parts.push(DIGIT_READINGS[digit as usize]),
+    }
+}
+
+/// The geminated form of a morpheme that has one, e.g. いっ for いち.
+#[cfg(feature = "alloc")]
+pub(crate) fn geminated(part: &str) -> Option<&'static str> {
+    match part {
+        "いち" => Some("いっ"),
+        "ろく" => Some("ろっ"),
+        "はち" => Some("はっ"),
+        "じゅう" => Some("じゅっ"),
+        "ひゃく" => Some("ひゃっ"),
+        "びゃく" => Some("びゃっ"),
+        "ぴゃく" => Some("ぴゃっ"),
+        _ => None,
+    }
+}
+
+/// Parses a number written in ASCII or full-width digits, in kanji numerals
+/// or daiji, or in a mix of them, e.g. 31, ３１, 三十一, 二〇二一, 参拾壱 or
+/// 1億2345万6789. Returns a `ParseError` for anything else, or if the number
+/// doesn't fit in a `u64`.
+pub fn parse_jp_number(s: &str) -> Result<u64, NihonifyError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(NihonifyError::ParseError);
+    }
+
+    let mut total: u64 = 0;
+    // The myriad group being read, the digits read since the last unit and
+    // how many there were, and the last units seen, which must come in
+    // descending order. Digits can run together, as in 2345万, but only one
+    // can come before or after a unit, so not 二三十 or 十二三.
+    let mut group: u64 = 0;
+    let mut digits: Option<u64> = None;
+    let mut digit_count = 0;
+    let mut last_unit = u64::MAX;
+    let mut last_myriad = u64::MAX;
+    for c in s.chars() {
+        if let Some(digit) = digit_value(c) {
+            let read = digits.unwrap_or(0).checked_mul(10);
+            digits = Some(checked(read.and_then(|read| read.checked_add(digit)))?);
+            digit_count += 1;
+            if digit_count > 1 && last_unit != u64::MAX {
+                return Err(NihonifyError::ParseError);
+            }
+        } else if let Some(unit) = unit_value(c) {
+            if unit >= last_unit || digit_count > 1 {
+                return Err(NihonifyError::ParseError);
+            }
+            let term = checked(digits.take().unwrap_or(1).checked_mul(unit))?;
+            digit_count = 0;
+            group = checked(group.checked_add(term))?;
+            last_unit = unit;
+        } else if let Some(myriad) = myriad_value(c) {
+            group = checked(group.checked_add(digits.take().unwrap_or(0)))?;
+            digit_count = 0;
+            if myriad >= last_myriad || group == 0 {
+                return Err(NihonifyError::ParseError);
+            }
+            total = checked(total.checked_add(checked(group.checked_mul(myriad))?))?;
+            group = 0;
+            last_unit = u64::MAX;
+            last_myriad = myriad;
+        } else {
+            return Err(NihonifyError::ParseError);
+        }
+    }
+
+    checked(total.checked_add(checked(group.checked_add(digits.unwrap_or(0)))?))
+}
+
+fn checked(n: Option<u64>) -> Result<u64, NihonifyError> {
+    n.ok_or(NihonifyError::ParseError)
+}
+
+/// Whether the char is a digit or a unit below 万 in any of the numerals
+/// `parse_jp_number` takes.
+#[cfg(feature = "alloc")]
+pub(crate) fn is_numeral_char(c: char) -> bool {
+    digit_value(c).is_some() || unit_value(c).is_some()
+}
+
+fn digit_value(c: char) -> Option<u64> {
+    match c {
+        '0'..='9' => Some(c as u64 - '0' as u64),
+        '０'..='９' => Some(c as u64 - '０' as u64),
+        '零' => Some(0),
+        '壱' => Some(1),
+        '弐' => Some(2),
+        '参' => Some(3),
+        '〇' => Some(0),
+        '一' => Some(1),
+        '二' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
+
+fn unit_value(c: char) -> Option<u64> {
+    match c {
+        '十' | '拾' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1000),
+        _ => None,
+    }
+}
+
+fn myriad_value(c: char) -> Option<u64> {
+    match c {
+        '万' | '萬' => Some(10_000),
+        '億' => Some(100_000_000),
+        '兆' => Some(1_000_000_000_000),
+        '京' => Some(10_000_000_000_000_000),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_width() {
+        assert_eq!(to_jp_intstring(2021), "２０２１");
+        assert_eq!(to_full_width_digits("令和3年11月"), "令和３年１１月");
+        assert_eq!(to_ascii_digits("令和３年１１月"), "令和3年11月");
+        assert_eq!(to_ascii_digits("R3.11.12"), "R3.11.12");
+    }
+
+    #[test]
+    fn test_to_kanji_numeral() {
+        assert_eq!(to_kanji_numeral(0), "〇");
+        assert_eq!(to_kanji_numeral(1), "一");
+        assert_eq!(to_kanji_numeral(10), "十");
+        assert_eq!(to_kanji_numeral(11), "十一");
+        assert_eq!(to_kanji_numeral(23), "二十三");
+        assert_eq!(to_kanji_numeral(31), "三十一");
+        assert_eq!(to_kanji_numeral(100), "百");
+        assert_eq!(to_kanji_numeral(2021), "二千二十一");
+        assert_eq!(to_kanji_numeral(10_000), "一万");
+        assert_eq!(
+            to_kanji_numeral(123_456_789),
+            "一億二千三百四十五万六千七百八十九"
+        );
+        assert_eq!(to_kanji_numeral(1_000_200_000_000), "一兆二億");
+        assert_eq!(
+            to_kanji_numeral(u64::MAX),
+            "千八百四十四京六千七百四十四兆七百三十七億九百五十五万千六百十五"
+        );
+    }
+
+    #[test]
+    fn test_to_myriad_string() {
+        assert_eq!(to_myriad_string(0), "0");
+        assert_eq!(to_myriad_string(6789), "6789");
+        assert_eq!(to_myriad_string(123_456_789), "1億2345万6789");
+        assert_eq!(to_myriad_string(100_006_789), "1億6789");
+        assert_eq!(to_myriad_string(1_000_000_000_000), "1兆");
+        assert_eq!(to_myriad_string(u64::MAX), "1844京6744兆737億955万1615");
+    }
+
+    #[test]
+    fn test_to_daiji_numeral() {
+        assert_eq!(to_daiji_numeral(0), "零");
+        assert_eq!(to_daiji_numeral(1), "壱");
+        assert_eq!(to_daiji_numeral(10), "壱拾");
+        assert_eq!(to_daiji_numeral(23), "弐拾参");
+        assert_eq!(to_daiji_numeral(100), "壱百");
+        assert_eq!(to_daiji_numeral(12_030), "壱万弐千参拾");
+        assert_eq!(to_daiji_numeral(1_000_000), "壱百万");
+    }
+
+    #[test]
+    fn test_to_reading() {
+        assert_eq!(to_reading(0), "ゼロ");
+        assert_eq!(to_reading(11), "じゅういち");
+        assert_eq!(to_reading(365), "さんびゃくろくじゅうご");
+        assert_eq!(to_reading(600), "ろっぴゃく");
+        assert_eq!(to_reading(1000), "せん");
+        assert_eq!(to_reading(3000), "さんぜん");
+        assert_eq!(to_reading(8_000), "はっせん");
+        assert_eq!(to_reading(10_000), "いちまん");
+        assert_eq!(to_reading(10_000_000), "いっせんまん");
+        assert_eq!(to_reading(11_000_000), "せんひゃくまん");
+        assert_eq!(
+            to_reading(123_456_789),
+            "いちおくにせんさんびゃくよんじゅうごまんろくせんななひゃくはちじゅうきゅう"
+        );
+        assert_eq!(to_reading(1_000_000_000_000), "いっちょう");
+        assert_eq!(to_reading(10_000_000_000_000_000), "いっけい");
+    }
+
+    #[test]
+    fn test_parse_jp_number() {
+        assert_eq!(parse_jp_number("31"), Ok(31));
+        assert_eq!(parse_jp_number("３１"), Ok(31));
+        assert_eq!(parse_jp_number("三十一"), Ok(31));
+        assert_eq!(parse_jp_number("十"), Ok(10));
+        assert_eq!(parse_jp_number("百二"), Ok(102));
+        assert_eq!(parse_jp_number("二〇二一"), Ok(2021));
+        assert_eq!(parse_jp_number("参拾壱"), Ok(31));
+        assert_eq!(parse_jp_number("壱萬弐千参拾"), Ok(12_030));
+        assert_eq!(parse_jp_number("一千万"), Ok(10_000_000));
+        assert_eq!(parse_jp_number("1億2345万6789"), Ok(123_456_789));
+        assert_eq!(parse_jp_number(&to_kanji_numeral(u64::MAX)), Ok(u64::MAX));
+        for bad in [
+            "",
+            "十十",
+            "百千",
+            "万億",
+            "万",
+            "三a",
+            "二十京京",
+            "十二三",
+            "二十三四",
+            "二三十",
+        ]
+        .iter()
+        {
+            assert_eq!(parse_jp_number(bad), Err(NihonifyError::ParseError));
+        }
+        assert_eq!(
+            parse_jp_number("18446744073709551616"),
+            Err(NihonifyError::ParseError)
+        );
+    }
+}
@@ -0,0 +1,1298 @@
+#[cfg(feature = "std")]
+use crate::calendar::SystemClock;
+use crate::calendar::{
+    civil_from_unix_epoch, era_date, named_eras, Clock, DateRange, Era, Jidai, WarekiDate,
+    JST_OFFSET,
+};
+use crate::eras::{SORTED_ERAS, SOUTHERN_COURT_ERAS};
+use crate::error::NihonifyError;
+use crate::format::wapuro_to_hepburn;
+use crate::numbers::{is_numeral_char, parse_jp_number};
+#[cfg(feature = "private-eras")]
+use crate::private_eras::PRIVATE_ERAS;
+use alloc::format;
+use alloc::string::String;
+use chrono::prelude::*;
+use chrono::{Days, Months};
+use core::convert::TryInto;
+use core::str::FromStr;
+
+/// Parses a YYYY-mm-dd date to midnight UTC on that day, which is the same
+/// day in Japan. Panics if the date can't be parsed; see `try_utc_dt`.
+pub fn utc_dt(date: &str) -> DateTime<Utc> {
+    try_utc_dt(date).unwrap()
+}
+
+/// Like `utc_dt`, but returns a `ParseError` if the date can't be parsed.
+pub fn try_utc_dt(date: &str) -> Result<DateTime<Utc>, NihonifyError> {
+    let datetime = DateTime::parse_from_rfc3339(format!("{}T00:00:00Z", date).as_str())
+        .map_err(|_| NihonifyError::ParseError)?;
+    Ok(Utc.from_utc_datetime(&datetime.naive_utc()))
+}
+
+/// A normalization or assumption the lenient parser made while parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assumption {
+    /// Full-width digits or separators, or kanji numerals, were normalized to
+    /// ASCII.
+    NumeralsNormalized,
+    /// A two-digit year was expanded to four digits, POSIX `%y` style:
+    /// 69–99 are 1969–1999 and 00–68 are 2000–2068.
+    TwoDigitYearExpanded { from: u32, to: i32 },
+    /// A year was given without an era name, e.g. 3年11月12日, so the era
+    /// current at the time of parsing was assumed.
+    EraInferred(&'static Era),
+}
+
+/// Leniently parses a Gregorian or wareki date, e.g. 2021-11-12, 21/11/12,
+/// ２０２１年１１月１２日, 令和3年11月12日, 令和元年5月1日 or R3.11.12.
+#[cfg(feature = "std")]
+pub fn parse_lenient(s: &str) -> Result<NaiveDate, NihonifyError> {
+    parse_lenient_with(s, &SystemClock, |_| ())
+}
+
+/// Like `parse_lenient`, but takes the clock used to infer a missing era, and
+/// calls `report` with every assumption made along the way.
+pub fn parse_lenient_with<C, F>(
+    s: &str,
+    clock: &C,
+    mut report: F,
+) -> Result<NaiveDate, NihonifyError>
+where
+    C: Clock,
+    F: FnMut(Assumption),
+{
+    let (normalized, changed) = normalize(s.trim());
+    let mut rest = normalized.as_str();
+    let mut era = match_era(rest);
+    if let Some(era) = era {
+        rest = &rest[era.kanji.unwrap().len()..];
+    } else if let Some((compact, len)) = match_compact_era(rest) {
+        era = Some(compact);
+        rest = &rest[len..];
+    }
+
+    // Past the era name, e.g. 万延, any kanji numerals are read as numbers.
+    if changed
+        || rest
+            .chars()
+            .any(|c| is_numeral_char(c) && !c.is_ascii_digit())
+    {
+        report(Assumption::NumeralsNormalized);
+    }
+
+    let (year, year_digits, rest) = take_year(rest)?;
+    let (year_delim, rest) = take_delim(rest)?;
+    let (month, rest) = take_number(rest)?;
+    let (_, rest) = take_delim(rest)?;
+    let (day, rest) = take_number(rest)?;
+    if !matches!(rest, "" | "日") {
+        return Err(NihonifyError::ParseError);
+    }
+
+    let era = match era {
+        Some(era) => Some(era),
+        // A bare year followed by 年 is read as a year of the current era.
+        None if year_delim == '年' && year_digits <= 2 => {
+            let era = Era::try_from_datetime(clock.now())?;
+            report(Assumption::EraInferred(era));
+            Some(era)
+        }
+        None => None,
+    };
+
+    match era {
+        Some(era) => era_date(era, year, month, day),
+        None => {
+            let year = if year_digits == 2 {
+                let expanded = if year >= 69 { 1900 } else { 2000 } + year as i32;
+                report(Assumption::TwoDigitYearExpanded {
+                    from: year,
+                    to: expanded,
+                });
+                expanded
+            } else {
+                year as i32
+            };
+            NaiveDate::from_ymd_opt(year, month, day).ok_or(NihonifyError::ParseError)
+        }
+    }
+}
+
+/// Parses the wareki date at the start of `s`, e.g. 令和3年11月12日 or
+/// 令和元年５月１日, returning it and the number of bytes it spans. Meant for
+/// finding dates in prose, so anything may follow the date.
+pub fn parse_wareki_prefix(s: &str) -> Option<(NaiveDate, usize)> {
+    let era = match_era(s)?;
+    let rest = &s[era.kanji.unwrap().len()..];
+    let (year, _, rest) = take_year(rest).ok()?;
+    let rest = rest.strip_prefix('年')?;
+    let (month, rest) = take_number(rest).ok()?;
+    let rest = rest.strip_prefix('月')?;
+    let (day, rest) = take_number(rest).ok()?;
+    let rest = rest.strip_prefix('日')?;
+
+    let date = era_date(era, year, month, day).ok()?;
+    Some((date, s.len() - rest.len()))
+}
+
+/// Parses a whole wareki date, e.g. 令和3年11月12日 or 令和元年５月１日.
+impl FromStr for WarekiDate {
+    type Err = NihonifyError;
+
+    fn from_str(s: &str) -> Result<WarekiDate, NihonifyError> {
+        let s = s.trim();
+        let era = match_era(s).ok_or(NihonifyError::UnknownEra)?;
+        let rest = &s[era.kanji.unwrap().len()..];
+        let (year, _, rest) = take_year(rest)?;
+        let rest = rest.strip_prefix('年').ok_or(NihonifyError::ParseError)?;
+        let (month, rest) = take_number(rest)?;
+        let rest = rest.strip_prefix('月').ok_or(NihonifyError::ParseError)?;
+        let (day, rest) = take_number(rest)?;
+        if rest != "日" {
+            return Err(NihonifyError::ParseError);
+        }
+
+        WarekiDate::new(
+            era,
+            year.try_into().map_err(|_| NihonifyError::DateOutOfRange)?,
+            month.try_into().map_err(|_| NihonifyError::ParseError)?,
+            day.try_into().map_err(|_| NihonifyError::ParseError)?,
+        )
+    }
+}
+
+/// Parses an era's kanji name, or its romaji name as `Era::from_romaji` does.
+impl FromStr for &'static Era {
+    type Err = NihonifyError;
+
+    fn from_str(s: &str) -> Result<&'static Era, NihonifyError> {
+        let s = s.trim();
+        Era::from_kanji(s)
+            .or_else(|| Era::from_romaji(s))
+            .ok_or(NihonifyError::UnknownEra)
+    }
+}
+
+impl Era {
+    /// Parses a wareki date, e.g. 令和3年11月12日 or 令和元年１１月１２日, to
+    /// midnight UTC on its Gregorian date. The inverse of
+    /// `to_jp_nenkou_string`.
+    pub fn parse_wareki(s: &str) -> Result<DateTime<Utc>, NihonifyError> {
+        Ok(s.parse::<WarekiDate>()?.to_datetime_utc())
+    }
+
+    /// The era with the given romaji name, ignoring case, in any
+    /// `RomanizationStyle`: the era table's wapuro spelling (shouwa), or
+    /// Hepburn with macrons or circumflexes (shōwa, shôwa) or without (showa).
+    /// Some names were used twice, e.g. 承和 and 昭和 are both shouwa; the
+    /// later era is returned.
+    pub fn from_romaji(romaji: &str) -> Option<&'static Era> {
+        let romaji = normalize_romaji(romaji.trim());
+        named_eras()
+            .filter(|era| {
+                era.romaji
+                    .is_some_and(|name| romaji_spellings(name).contains(&romaji))
+            })
+            .max_by_key(|era| era.started_at)
+    }
+}
+
+/// Like `parse_wareki_prefix`, but the day, or both the month and the day, may
+/// be left out, e.g. 昭和60年 or 令和3年11月. Returns the first day of the
+/// period, or the first day of the era if the period starts before it, e.g.
+/// 2019-05-01 for 令和元年.
+pub fn parse_wareki_period_prefix(s: &str) -> Option<(NaiveDate, usize)> {
+    let era = match_era(s)?;
+    let rest = &s[era.kanji.unwrap().len()..];
+    let (year, _, rest) = take_year(rest).ok()?;
+    let mut rest = rest.strip_prefix('年')?;
+
+    let (mut month, mut day) = (None, None);
+    if let Some((m, r)) = take_number(rest)
+        .ok()
+        .and_then(|(m, r)| Some((m, r.strip_prefix('月')?)))
+    {
+        month = Some(m);
+        rest = r;
+        if let Some((d, r)) = take_number(rest)
+            .ok()
+            .and_then(|(d, r)| Some((d, r.strip_prefix('日')?)))
+        {
+            day = Some(d);
+            rest = r;
+        }
+    }
+
+    let date = match era_date(era, year, month.unwrap_or(1), day.unwrap_or(1)) {
+        // Only the first year of an era can start before it, in which case
+        // the period is cut down to the part that falls within the era.
+        Err(NihonifyError::DateOutOfRange) if year == 1 && day.is_none() => {
+            let start = era.date_range().first;
+            if month.is_some_and(|month| month != start.month()) {
+                return None;
+            }
+            start
+        }
+        date => date.ok()?,
+    };
+    Some((date, s.len() - rest.len()))
+}
+
+/// Parses a month with a third of it given as 上旬 (or 初旬), 中旬 or 下旬,
+/// e.g. 令和3年11月上旬 or 2021年11月下旬, into the days it spans: the 1st to
+/// the 10th, the 11th to the 20th, or the 21st to the end of the month. The
+/// range is cut down to the part that falls within the given era.
+pub fn parse_jun(s: &str) -> Result<DateRange, NihonifyError> {
+    let s = s.trim();
+    let (rest, first_day) =
+        if let Some(rest) = s.strip_suffix("上旬").or_else(|| s.strip_suffix("初旬")) {
+            (rest, 1)
+        } else if let Some(rest) = s.strip_suffix("中旬") {
+            (rest, 11)
+        } else if let Some(rest) = s.strip_suffix("下旬") {
+            (rest, 21)
+        } else {
+            return Err(NihonifyError::ParseError);
+        };
+
+    let era = match_era(rest);
+    let rest = era.map_or(rest, |era| &rest[era.kanji.unwrap().len()..]);
+    let (year, _, rest) = take_year(rest)?;
+    let rest = rest.strip_prefix('年').ok_or(NihonifyError::ParseError)?;
+    let (month, rest) = take_number(rest)?;
+    if rest != "月" {
+        return Err(NihonifyError::ParseError);
+    }
+
+    let year: i32 = year.try_into().map_err(|_| NihonifyError::DateOutOfRange)?;
+    let year = match era {
+        Some(era) => era
+            .date_range()
+            .first
+            .year()
+            .checked_add(year - 1)
+            .ok_or(NihonifyError::DateOutOfRange)?,
+        None => year,
+    };
+    let first = NaiveDate::from_ymd_opt(year, month, first_day).ok_or(NihonifyError::ParseError)?;
+    let last = if first_day == 21 {
+        // The last day of the month.
+        first.with_day(1).unwrap() + Months::new(1) - Days::new(1)
+    } else {
+        first + Days::new(9)
+    };
+
+    let range = DateRange { first, last };
+    match era {
+        Some(era) => range
+            .intersection(&era.date_range())
+            .ok_or(NihonifyError::DateOutOfRange),
+        None => Ok(range),
+    }
+}
+
+/// Parses a period with an optional 前期, 中期, 後期, 初期 or 末期 qualifier,
+/// e.g. 江戸時代後期 or 鎌倉末期, into the approximate days it spans. 前期, 中期
+/// and 後期 are the thirds of the period, and 初期 and 末期 its first and last
+/// fifth. The modern period has no end, so it can't be qualified.
+pub fn parse_jidai_period(s: &str) -> Result<DateRange, NihonifyError> {
+    let s = s.trim();
+    let (rest, (from, to, parts)) = [
+        ("前期", (0, 1, 3)),
+        ("中期", (1, 2, 3)),
+        ("後期", (2, 3, 3)),
+        ("初期", (0, 1, 5)),
+        ("末期", (4, 5, 5)),
+    ]
+    .iter()
+    .find_map(|&(suffix, part)| s.strip_suffix(suffix).map(|rest| (rest, part)))
+    .unwrap_or((s, (0, 1, 1)));
+
+    let name = rest.strip_suffix("時代").unwrap_or(rest);
+    let jidai = Jidai::ALL
+        .iter()
+        .find(|jidai| jidai.kanji().trim_end_matches("時代") == name)
+        .ok_or(NihonifyError::UnknownEra)?;
+
+    let range = jidai.date_range();
+    if parts == 1 {
+        return Ok(range);
+    }
+    if range.last == NaiveDate::MAX {
+        return Err(NihonifyError::DateOutOfRange);
+    }
+
+    let days = range.days() as u64;
+    Ok(DateRange {
+        first: range.first + Days::new(days * from / parts),
+        last: range.first + Days::new(days * to / parts - 1),
+    })
+}
+
+/// Parses a century, e.g. 21世紀 or 紀元前1世紀, into the days it spans.
+/// Centuries run from year 1 to year 100, so 21世紀 is 2001 to 2100.
+pub fn parse_century(s: &str) -> Result<DateRange, NihonifyError> {
+    let s = s.trim();
+    let (bce, rest) = match s.strip_prefix("紀元前") {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix("西暦").unwrap_or(s)),
+    };
+    let (century, rest) = take_number(rest)?;
+    if rest != "世紀" || century == 0 {
+        return Err(NihonifyError::ParseError);
+    }
+
+    // Widened so that no century overflows; `years` rejects those out of range.
+    let century = i64::from(century);
+    let (first, last) = if bce {
+        // There's no year zero: 1 BCE is the proleptic year 0.
+        (-100 * century + 1, -100 * (century - 1))
+    } else {
+        (100 * (century - 1) + 1, 100 * century)
+    };
+    years(first, last)
+}
+
+/// Parses a decade, e.g. 1980年代, 昭和50年代 or 平成一桁 (the first nine
+/// years of the era), into the days it spans. Era decades are counted in era
+/// years, so 昭和50年代 is 1975 to 1984, and are cut down to the part that
+/// falls within the era.
+pub fn parse_decade(s: &str) -> Result<DateRange, NihonifyError> {
+    let s = s.trim();
+    let era = match_era(s);
+    let rest = era.map_or(s, |era| &s[era.kanji.unwrap().len()..]);
+    let rest = rest.strip_prefix("西暦").unwrap_or(rest);
+
+    let (first, last) = match (era, rest) {
+        (Some(_), "一桁") => (1, 9),
+        _ => {
+            let (decade, rest) = take_number(rest)?;
+            if rest != "年代" || decade % 10 != 0 || (era.is_some() && decade == 0) {
+                return Err(NihonifyError::ParseError);
+            }
+            (i64::from(decade), i64::from(decade) + 9)
+        }
+    };
+
+    match era {
+        Some(era) => {
+            let offset = i64::from(era.date_range().first.year()) - 1;
+            years(offset + first, offset + last)?
+                .intersection(&era.date_range())
+                .ok_or(NihonifyError::DateOutOfRange)
+        }
+        None => years(first, last),
+    }
+}
+
+/// The days from the start of the year `first` to the end of the year `last`.
+fn years(first: i64, last: i64) -> Result<DateRange, NihonifyError> {
+    let year = |year: i64| year.try_into().map_err(|_| NihonifyError::DateOutOfRange);
+    Ok(DateRange {
+        first: NaiveDate::from_ymd_opt(year(first)?, 1, 1).ok_or(NihonifyError::DateOutOfRange)?,
+        last: NaiveDate::from_ymd_opt(year(last)?, 12, 31).ok_or(NihonifyError::DateOutOfRange)?,
+    })
+}
+
+/// Parses a wareki date typed in romaji, e.g. heisei31.4.30, Reiwa 3/11/12 or
+/// reiwa gannen 5 gatsu 1 nichi. The era name may be spelled in any
+/// `RomanizationStyle`, as for `Era::from_romaji`.
+pub fn parse_romaji_wareki(s: &str) -> Result<NaiveDate, NihonifyError> {
+    let s = normalize_romaji(s.trim());
+    let (era, len) = match_romaji_era(&s).ok_or(NihonifyError::UnknownEra)?;
+
+    let mut numbers = [0; 3];
+    let mut count = 0;
+    let mut rest = &s[len..];
+    while let Some(c) = rest.chars().next() {
+        if c.is_alphabetic() {
+            let end = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            match &rest[..end] {
+                "gannen" | "ganen" if count == 0 => {
+                    numbers[0] = 1;
+                    count = 1;
+                }
+                "nen" | "gatsu" | "nichi" => (),
+                _ => return Err(NihonifyError::ParseError),
+            }
+            rest = &rest[end..];
+        } else if c.is_ascii_digit() {
+            if count == 3 {
+                return Err(NihonifyError::ParseError);
+            }
+            let (number, r) = take_number(rest)?;
+            numbers[count] = number;
+            count += 1;
+            rest = r;
+        } else if matches!(c, '.' | '/' | '-' | ',') || c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else {
+            return Err(NihonifyError::ParseError);
+        }
+    }
+    if count != 3 {
+        return Err(NihonifyError::ParseError);
+    }
+
+    era_date(era, numbers[0], numbers[1], numbers[2])
+}
+
+/// Parses a compact wareki date, as written on forms and in filenames, e.g.
+/// R3.11.12 or 令3.11.12. The era is given by its Latin initial, in either
+/// case, or by the first kanji of its name, so only the modern eras, Meiji
+/// through Reiwa, can be written so. The year, month and day may be separated
+/// by '.', '/' or '-'.
+pub fn parse_compact_wareki(s: &str) -> Result<NaiveDate, NihonifyError> {
+    let s = s.trim();
+    let (era, len) = match_compact_era(s).ok_or(NihonifyError::UnknownEra)?;
+
+    let (year, rest) = take_number(&s[len..])?;
+    let rest = take_separator(rest)?;
+    let (month, rest) = take_number(rest)?;
+    let rest = take_separator(rest)?;
+    let (day, rest) = take_number(rest)?;
+    if !rest.is_empty() {
+        return Err(NihonifyError::ParseError);
+    }
+
+    era_date(era, year, month, day)
+}
+
+/// Parses a date in the JIS X 0301 representation, either with the era's
+/// Latin initial, e.g. R03.11.12, or with its number, e.g. 5031112. The year,
+/// month and day must be written with two digits each.
+pub fn parse_jis_x0301(s: &str) -> Result<NaiveDate, NihonifyError> {
+    let mut chars = s.chars();
+    let code = chars.next().ok_or(NihonifyError::ParseError)?;
+    let rest = chars.as_str();
+    let (era, numbers) = if code.is_ascii_digit() {
+        let era = Jidai::Modern
+            .eras()
+            .find(|era| era.jis_code().map(|n| char::from(b'0' + n)) == Some(code))
+            .ok_or(NihonifyError::UnknownEra)?;
+        (era, [rest.get(..2), rest.get(2..4), rest.get(4..)])
+    } else {
+        let era = Jidai::Modern
+            .eras()
+            .find(|era| era.initial() == Some(code.to_ascii_uppercase()))
+            .ok_or(NihonifyError::UnknownEra)?;
+        let mut fields = rest.split('.');
+        let numbers = [fields.next(), fields.next(), fields.next()];
+        if fields.next().is_some() {
+            return Err(NihonifyError::ParseError);
+        }
+        (era, numbers)
+    };
+
+    let mut parsed = [0; 3];
+    for (number, field) in parsed.iter_mut().zip(numbers.iter()) {
+        *number = match field {
+            Some(field) if field.len() == 2 && field.bytes().all(|b| b.is_ascii_digit()) => {
+                field.parse().unwrap()
+            }
+            _ => return Err(NihonifyError::ParseError),
+        };
+    }
+
+    era_date(era, parsed[0], parsed[1], parsed[2])
+}
+
+/// Finds the modern era whose Latin initial or abbreviation starts `s`.
+/// Returns it and the length of the initial or abbreviation.
+fn match_compact_era(s: &str) -> Option<(&'static Era, usize)> {
+    let c = s.chars().next()?;
+    let era = Jidai::Modern.eras().find(|era| {
+        era.initial() == Some(c.to_ascii_uppercase()) || era.abbreviation() == Some(c)
+    })?;
+    Some((era, c.len_utf8()))
+}
+
+/// Takes the '.', '/' or '-' between the numbers of a compact date.
+fn take_separator(s: &str) -> Result<&str, NihonifyError> {
+    s.strip_prefix(['.', '/', '-'])
+        .ok_or(NihonifyError::ParseError)
+}
+
+/// Finds the era whose romaji name prefixes the lowercase `s`, in any of the
+/// supported romanizations. Returns it and the length of the name. Later eras
+/// win when names clash, e.g. shouwa is 昭和 rather than 正和.
+fn match_romaji_era(s: &str) -> Option<(&'static Era, usize)> {
+    // The longest spelling that matches, then the later of the eras that
+    // share it, as `Era::from_romaji`.
+    named_eras()
+        .filter_map(|era| {
+            let len = romaji_spellings(era.romaji?)
+                .iter()
+                .filter(|spelling| s.starts_with(spelling.as_str()))
+                .map(|spelling| spelling.len())
+                .max()?;
+            Some((era, len))
+        })
+        .max_by_key(|&(era, len)| (len, era.started_at))
+}
+
+/// The ways of writing the era table's romaji name in each
+/// `RomanizationStyle`, e.g. shouwa, shōwa and showa.
+fn romaji_spellings(romaji: &str) -> [String; 3] {
+    [
+        String::from(romaji),
+        wapuro_to_hepburn(romaji, true),
+        wapuro_to_hepburn(romaji, false),
+    ]
+}
+
+/// Lowercases romaji and writes long vowels marked with circumflexes, as in
+/// Kunrei-shiki, with macrons instead.
+fn normalize_romaji(romaji: &str) -> String {
+    romaji.to_lowercase().replace('ô', "ō").replace('û', "ū")
+}
+
+/// Maps full-width digits and separators to ASCII, dropping whitespace.
+/// Returns whether anything other than whitespace was changed.
+fn normalize(s: &str) -> (String, bool) {
+    let mut changed = false;
+    let normalized = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '０'..='９' | '／' | '－' | '．' => {
+                changed = true;
+                // Full-width forms are shifted 65,248 slots away from ASCII.
+                char::from_u32(c as u32 - 65248).unwrap()
+            }
+            _ => c,
+        })
+        .collect();
+
+    (normalized, changed)
+}
+
+/// Finds the era whose kanji name prefixes `s`, including the private eras if
+/// the `private-eras` feature is enabled.
+fn match_era(s: &str) -> Option<&'static Era> {
+    named_eras()
+        .filter(|era| era.kanji.is_some_and(|kanji| s.starts_with(kanji)))
+        .max_by_key(|era| era.kanji.unwrap().len())
+}
+
+/// Takes the year, which may be 元 for the first year of an era. Returns the
+/// year, the number of digits it was written with, and the rest of `s`.
+fn take_year(s: &str) -> Result<(u32, usize, &str), NihonifyError> {
+    if let Some(rest) = s.strip_prefix('元') {
+        return Ok((1, 1, rest));
+    }
+    let (year, rest) = take_number(s)?;
+    let written = &s[..s.len() - rest.len()];
+    // Kanji numerals don't write a digit per place, e.g. 二十一 and 三十, so
+    // those are counted by their value's digits instead.
+    let digits = if written.bytes().all(|b| b.is_ascii_digit()) {
+        written.len()
+    } else {
+        year.checked_ilog10().map_or(1, |log| log as usize + 1)
+    };
+    Ok((year, digits, rest))
+}
+
+/// Takes a number written in ASCII or full-width digits, or in kanji numerals,
+/// e.g. 三十一.
+pub(crate) fn take_number(s: &str) -> Result<(u32, &str), NihonifyError> {
+    let end = s.find(|c| !is_numeral_char(c)).unwrap_or(s.len());
+    let number = parse_jp_number(&s[..end])?;
+    Ok((
+        number.try_into().map_err(|_| NihonifyError::ParseError)?,
+        &s[end..],
+    ))
+}
+
+fn take_delim(s: &str) -> Result<(char, &str), NihonifyError> {
+    match s.chars().next() {
+        Some(c @ ('年' | '月' | '/' | '-' | '.')) => Ok((c, &s[c.len_utf8()..])),
+        _ => Err(NihonifyError::ParseError),
+    }
+}
+
+/// Converts a wareki date literal to a `NaiveDate` at compile time, failing
+/// the build if the literal isn't a valid date, e.g.
+/// `wareki!("令和3年11月12日")`. Takes the same forms as `parse_wareki_prefix`,
+/// but with the numbers in ASCII or full-width digits only, not in kanji
+/// numerals.
+///
+/// ```
+/// # use nihonify_core::wareki;
+/// let date = wareki!("令和元年５月１日");
+/// assert_eq!(date, chrono::NaiveDate::from_ymd_opt(2019, 5, 1).unwrap());
+/// ```
+///
+/// ```compile_fail
+/// # use nihonify_core::wareki;
+/// // Heisei ended on April 30th, 2019.
+/// let date = wareki!("平成31年5月1日");
+/// ```
+#[macro_export]
+macro_rules! wareki {
+    ($date:literal) => {
+        const {
+            match $crate::parse::parse_wareki_const($date) {
+                Some(date) => date,
+                None => panic!(concat!("invalid wareki date: ", $date)),
+            }
+        }
+    };
+}
+
+/// The const implementation behind `wareki!`: parses the whole string as a
+/// wareki date, as `parse_wareki_prefix` does, but with digits only.
+#[doc(hidden)]
+pub const fn parse_wareki_const(s: &str) -> Option<NaiveDate> {
+    let s = s.as_bytes();
+
+    // Find the era with the longest kanji name that prefixes the string.
+    #[cfg(feature = "private-eras")]
+    let tables = [SORTED_ERAS, SOUTHERN_COURT_ERAS, PRIVATE_ERAS];
+    #[cfg(not(feature = "private-eras"))]
+    let tables = [SORTED_ERAS, SOUTHERN_COURT_ERAS];
+    let mut era: Option<&Era> = None;
+    let mut table = 0;
+    let mut i = 0;
+    while table < tables.len() {
+        if i == tables[table].len() {
+            table += 1;
+            i = 0;
+            continue;
+        }
+        if let Some(kanji) = tables[table][i].kanji {
+            let longer = match era {
+                Some(Era {
+                    kanji: Some(matched),
+                    ..
+                }) => kanji.len() >= matched.len(),
+                _ => true,
+            };
+            if longer && starts_with_at(s, 0, kanji.as_bytes()) {
+                era = Some(&tables[table][i]);
+            }
+        }
+        i += 1;
+    }
+    let era = match era {
+        Some(era) => era,
+        None => return None,
+    };
+    let mut at = match era.kanji {
+        Some(kanji) => kanji.len(),
+        None => return None,
+    };
+
+    let year = if starts_with_at(s, at, "元".as_bytes()) {
+        at += "元".len();
+        1
+    } else {
+        match const_take_number(s, at) {
+            Some((year, end)) => {
+                at = end;
+                year
+            }
+            None => return None,
+        }
+    };
+    let mut parts = [year, 0, 0];
+    let delims = ["年", "月", "日"];
+    let mut part = 0;
+    while part < 3 {
+        if part > 0 {
+            match const_take_number(s, at) {
+                Some((number, end)) => {
+                    parts[part] = number;
+                    at = end;
+                }
+                None => return None,
+            }
+        }
+        if !starts_with_at(s, at, delims[part].as_bytes()) {
+            return None;
+        }
+        at += delims[part].len();
+        part += 1;
+    }
+    if at != s.len() {
+        return None;
+    }
+
+    // Era boundaries are midnight JST, so the civil dates are those in Japan.
+    let (start_year, start_month, start_day) =
+        civil_from_unix_epoch(era.started_at + JST_OFFSET as i64);
+    let year = match parts[0] as i64 + start_year as i64 - 1 {
+        year if year <= i32::MAX as i64 => year as i32,
+        _ => return None,
+    };
+    let date = (year, parts[1], parts[2]);
+    if !const_ymd_lt((start_year, start_month, start_day), date, true) {
+        return None;
+    }
+    if let Some(ended_at) = era.ended_at {
+        if !const_ymd_lt(
+            date,
+            civil_from_unix_epoch(ended_at + JST_OFFSET as i64),
+            false,
+        ) {
+            return None;
+        }
+    }
+
+    NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+}
+
+const fn starts_with_at(s: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if at + prefix.len() > s.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Takes a number written in ASCII or full-width digits, starting at `at`.
+/// Returns the number and where it ends.
+const fn const_take_number(s: &[u8], mut at: usize) -> Option<(u32, usize)> {
+    let start = at;
+    let mut number: u32 = 0;
+    loop {
+        let digit = if at < s.len() && s[at].is_ascii_digit() {
+            at += 1;
+            s[at - 1] - b'0'
+        } else if at + 2 < s.len()
+            && s[at] == 0xEF
+            && s[at + 1] == 0xBC
+            && matches!(s[at + 2], 0x90..=0x99)
+        {
+            // Full-width digits are U+FF10 to U+FF19.
+            at += 3;
+            s[at - 1] - 0x90
+        } else {
+            break;
+        };
+        number = match number.checked_mul(10) {
+            Some(n) => match n.checked_add(digit as u32) {
+                Some(n) => n,
+                None => return None,
+            },
+            None => return None,
+        };
+    }
+
+    if at == start {
+        None
+    } else {
+        Some((number, at))
+    }
+}
+
+/// Whether the (year, month, day) `a` comes before `b`, or is the same day if
+/// `or_equal`.
+const fn const_ymd_lt(a: (i32, u32, u32), b: (i32, u32, u32), or_equal: bool) -> bool {
+    if a.0 != b.0 {
+        return a.0 < b.0;
+    }
+    if a.1 != b.1 {
+        return a.1 < b.1;
+    }
+    a.2 < b.2 || (or_equal && a.2 == b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::FixedClock;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn parse(s: &str) -> (Result<NaiveDate, NihonifyError>, Vec<Assumption>) {
+        let clock = FixedClock(utc_dt("2021-11-12"));
+        let mut assumptions = Vec::new();
+        let result = parse_lenient_with(s, &clock, |a| assumptions.push(a));
+        (result, assumptions)
+    }
+
+    #[test]
+    fn test_try_utc_dt() {
+        assert_eq!(try_utc_dt("2021-11-12"), Ok(utc_dt("2021-11-12")));
+        assert_eq!(try_utc_dt("2021-02-30"), Err(NihonifyError::ParseError));
+        assert_eq!(try_utc_dt("yesterday"), Err(NihonifyError::ParseError));
+    }
+
+    #[test]
+    fn test_era_from_str() {
+        let era: &Era = "令和".parse().unwrap();
+        assert_eq!(era.romaji, Some("reiwa"));
+        let era: &Era = " Heisei ".parse().unwrap();
+        assert_eq!(era.kanji, Some("平成"));
+        assert_eq!("showa".parse::<&Era>().unwrap().kanji, Some("昭和"));
+        assert_eq!("令".parse::<&Era>(), Err(NihonifyError::UnknownEra));
+        assert_eq!("令和".parse::<&Era>().unwrap().to_string(), "令和");
+    }
+
+    #[test]
+    fn test_from_romaji() {
+        assert_eq!(Era::from_romaji("reiwa").unwrap().kanji, Some("令和"));
+        assert_eq!(Era::from_romaji("Heisei").unwrap().kanji, Some("平成"));
+        assert_eq!(Era::from_romaji("shouwa").unwrap().kanji, Some("昭和"));
+        assert_eq!(Era::from_romaji("Shōwa").unwrap().kanji, Some("昭和"));
+        assert_eq!(Era::from_romaji("showa").unwrap().kanji, Some("昭和"));
+        assert_eq!(Era::from_romaji("Shôwa").unwrap().kanji, Some("昭和"));
+        assert_eq!(Era::from_romaji("TAISHŌ").unwrap().kanji, Some("大正"));
+        assert_eq!(Era::from_romaji("taika").unwrap().kanji, Some("大化"));
+        assert_eq!(Era::from_romaji("kouwa").unwrap().kanji, Some("弘和"));
+        assert_eq!(Era::from_romaji("rei"), None);
+    }
+
+    #[test]
+    fn test_shared_romaji() {
+        // 康和 (1099) and the Southern Court's 弘和 (1381) are both kouwa.
+        assert_eq!(Era::from_romaji("Kōwa").unwrap().kanji, Some("弘和"));
+        assert_eq!(
+            parse_romaji_wareki("kouwa 2.1.1"),
+            Ok("弘和2年1月1日"
+                .parse::<WarekiDate>()
+                .unwrap()
+                .to_naive_date())
+        );
+    }
+
+    #[test]
+    fn test_parse_wareki() {
+        assert_eq!(
+            Era::parse_wareki("令和3年11月12日"),
+            Ok(utc_dt("2021-11-12")
+                .date_naive()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc())
+        );
+        assert_eq!(
+            Era::parse_wareki("令和３年１１月１２日"),
+            Era::parse_wareki("令和3年11月12日")
+        );
+        assert_eq!(
+            Era::parse_wareki("令和三年十一月十二日"),
+            Era::parse_wareki("令和3年11月12日")
+        );
+        let heisei_end = Era::parse_wareki("平成31年4月30日").unwrap();
+        assert_eq!(
+            Era::to_jp_nenkou_string(heisei_end),
+            Some(String::from("平成３１年４月３０日"))
+        );
+        assert_eq!(
+            Era::parse_wareki("平成32年1月1日"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            Era::parse_wareki("2021年11月12日"),
+            Err(NihonifyError::UnknownEra)
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let date = NaiveDate::from_ymd_opt(2021, 11, 12).unwrap();
+        assert_eq!(parse("2021-11-12"), (Ok(date), Vec::new()));
+        assert_eq!(parse("令和3年11月12日"), (Ok(date), Vec::new()));
+        assert_eq!(
+            parse("令和元年5月1日").0,
+            Ok(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap())
+        );
+        assert_eq!(
+            parse("２０２１年１１月１２日"),
+            (Ok(date), vec![Assumption::NumeralsNormalized])
+        );
+        assert_eq!(parse("2021/13/12").0, Err(NihonifyError::ParseError));
+        assert_eq!(
+            parse("令和1年4月30日").0,
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse("令和2147483647年1月1日").0,
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_wareki_prefix() {
+        assert_eq!(
+            parse_wareki_prefix("令和3年11月12日に"),
+            Some((NaiveDate::from_ymd_opt(2021, 11, 12).unwrap(), 20))
+        );
+        assert_eq!(
+            parse_wareki_prefix("昭和６０年１月１日"),
+            Some((NaiveDate::from_ymd_opt(1985, 1, 1).unwrap(), 27))
+        );
+        // The Southern Court's eras parse too.
+        assert_eq!(
+            parse_wareki_prefix("正平5年1月1日"),
+            Some((NaiveDate::from_ymd_opt(1351, 1, 1).unwrap(), 18))
+        );
+        assert_eq!(
+            parse_wareki_const("正平5年1月1日"),
+            NaiveDate::from_ymd_opt(1351, 1, 1)
+        );
+        assert_eq!(parse_wareki_prefix("令和3年11月"), None);
+        assert_eq!(parse_wareki_prefix("2021年11月12日"), None);
+    }
+
+    #[test]
+    fn test_wareki() {
+        assert_eq!(
+            crate::wareki!("令和3年11月12日"),
+            NaiveDate::from_ymd_opt(2021, 11, 12).unwrap()
+        );
+        assert_eq!(
+            parse_wareki_const("平成３１年４月３０日"),
+            NaiveDate::from_ymd_opt(2019, 4, 30)
+        );
+        assert_eq!(parse_wareki_const("令和元年4月30日"), None);
+        assert_eq!(parse_wareki_const("令和3年11月12日です"), None);
+        assert_eq!(parse_wareki_const("令和3年13月12日"), None);
+        assert_eq!(parse_wareki_const("令和三年11月12日"), None);
+        assert_eq!(parse_wareki_const("令和2147483647年1月1日"), None);
+    }
+
+    #[test]
+    fn test_parse_jun() {
+        let range = |first: (i32, u32, u32), last: (i32, u32, u32)| {
+            Ok(DateRange {
+                first: NaiveDate::from_ymd_opt(first.0, first.1, first.2).unwrap(),
+                last: NaiveDate::from_ymd_opt(last.0, last.1, last.2).unwrap(),
+            })
+        };
+        assert_eq!(
+            parse_jun("令和3年11月上旬"),
+            range((2021, 11, 1), (2021, 11, 10))
+        );
+        assert_eq!(
+            parse_jun("令和３年１１月中旬"),
+            range((2021, 11, 11), (2021, 11, 20))
+        );
+        assert_eq!(
+            parse_jun("2024年2月下旬"),
+            range((2024, 2, 21), (2024, 2, 29))
+        );
+        // Shōwa ended on January 7th, 1989.
+        assert_eq!(
+            parse_jun("昭和64年1月上旬"),
+            range((1989, 1, 1), (1989, 1, 7))
+        );
+        assert_eq!(
+            parse_jun("昭和64年1月下旬"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(parse_jun("令和3年11月"), Err(NihonifyError::ParseError));
+        assert_eq!(
+            parse_jun("令和2147483647年1月上旬"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_jun("4294967295年1月上旬"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_jidai_period() {
+        let range = |s| {
+            let range = parse_jidai_period(s).unwrap();
+            (range.first.year(), range.last.year())
+        };
+        assert_eq!(range("江戸時代"), (1615, 1868));
+        assert_eq!(range("江戸"), (1615, 1868));
+        assert_eq!(range("江戸時代前期"), (1615, 1700));
+        assert_eq!(range("江戸時代中期"), (1700, 1784));
+        assert_eq!(range("江戸時代後期"), (1784, 1868));
+        assert_eq!(range("江戸末期"), (1818, 1868));
+        assert_eq!(range("鎌倉時代初期"), (1185, 1215));
+
+        let thirds = ["平安時代前期", "平安時代中期", "平安時代後期"]
+            .map(|s| parse_jidai_period(s).unwrap());
+        assert_eq!(
+            thirds[0].first,
+            parse_jidai_period("平安時代").unwrap().first
+        );
+        assert_eq!(thirds[0].last.succ_opt().unwrap(), thirds[1].first);
+        assert_eq!(thirds[1].last.succ_opt().unwrap(), thirds[2].first);
+        assert_eq!(thirds[2].last, parse_jidai_period("平安時代").unwrap().last);
+
+        assert_eq!(
+            parse_jidai_period("近現代後期"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_jidai_period("大正時代後期"),
+            Err(NihonifyError::UnknownEra)
+        );
+    }
+
+    #[test]
+    fn test_parse_century_and_decade() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            parse_century("21世紀"),
+            Ok(DateRange {
+                first: ymd(2001, 1, 1),
+                last: ymd(2100, 12, 31)
+            })
+        );
+        assert_eq!(
+            parse_century("紀元前１世紀"),
+            Ok(DateRange {
+                first: ymd(-99, 1, 1),
+                last: ymd(0, 12, 31)
+            })
+        );
+        assert_eq!(
+            parse_decade("1980年代"),
+            Ok(DateRange {
+                first: ymd(1980, 1, 1),
+                last: ymd(1989, 12, 31)
+            })
+        );
+        assert_eq!(
+            parse_decade("昭和50年代"),
+            Ok(DateRange {
+                first: ymd(1975, 1, 1),
+                last: ymd(1984, 12, 31)
+            })
+        );
+        assert_eq!(
+            parse_decade("昭和60年代"),
+            Ok(DateRange {
+                first: ymd(1985, 1, 1),
+                last: ymd(1989, 1, 7)
+            })
+        );
+        assert_eq!(
+            parse_decade("平成一桁"),
+            Ok(DateRange {
+                first: ymd(1989, 1, 8),
+                last: ymd(1997, 12, 31)
+            })
+        );
+        assert_eq!(parse_decade("昭和55年代"), Err(NihonifyError::ParseError));
+        assert_eq!(
+            parse_decade("昭和70年代"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(parse_century("0世紀"), Err(NihonifyError::ParseError));
+        assert_eq!(
+            parse_century("99999999世紀"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_century("紀元前99999999世紀"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_decade("2147483640年代"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_decade("令和2147483640年代"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_wareki_date_from_str() {
+        let date: WarekiDate = "令和3年11月12日".parse().unwrap();
+        assert_eq!(date.era().kanji, Some("令和"));
+        assert_eq!((date.year(), date.month(), date.day()), (3, 11, 12));
+        assert_eq!(date.to_unix_epoch(), 1636675200);
+
+        let date: WarekiDate = "令和元年５月１日".parse().unwrap();
+        assert_eq!(date.year(), 1);
+        assert_eq!(
+            "令和3年11月".parse::<WarekiDate>(),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            "令和3年11月12日です".parse::<WarekiDate>(),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            "2021年11月12日".parse::<WarekiDate>(),
+            Err(NihonifyError::UnknownEra)
+        );
+        assert_eq!(
+            "令和65535年1月1日".parse::<WarekiDate>(),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_romaji_wareki() {
+        let date = |y, m, d| Ok(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(parse_romaji_wareki("heisei31.4.30"), date(2019, 4, 30));
+        assert_eq!(parse_romaji_wareki("reiwa gannen 5/1"), date(2019, 5, 1));
+        assert_eq!(parse_romaji_wareki("Reiwa 3-11-12"), date(2021, 11, 12));
+        assert_eq!(
+            parse_romaji_wareki("reiwa 3 nen 11 gatsu 12 nichi"),
+            date(2021, 11, 12)
+        );
+        assert_eq!(parse_romaji_wareki("Shōwa 60.1.1"), date(1985, 1, 1));
+        assert_eq!(parse_romaji_wareki("showa60.1.1"), date(1985, 1, 1));
+        assert_eq!(parse_romaji_wareki("shouwa60.1.1"), date(1985, 1, 1));
+        assert_eq!(parse_romaji_wareki("Shôwa 60.1.1"), date(1985, 1, 1));
+        // Southern court eras are looked up too.
+        assert_eq!(
+            parse_romaji_wareki("genchuu 2.1.1"),
+            Ok("元中2年1月1日"
+                .parse::<WarekiDate>()
+                .unwrap()
+                .to_naive_date())
+        );
+        assert_eq!(
+            parse_romaji_wareki("heisei31.5.1"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_romaji_wareki("tokyo 3.11.12"),
+            Err(NihonifyError::UnknownEra)
+        );
+        assert_eq!(
+            parse_romaji_wareki("reiwa 3.11"),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            parse_romaji_wareki("reiwa 2147483647.1.1"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_jis_x0301() {
+        let date = |y, m, d| Ok(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(parse_jis_x0301("R03.11.12"), date(2021, 11, 12));
+        assert_eq!(parse_jis_x0301("h31.04.30"), date(2019, 4, 30));
+        assert_eq!(parse_jis_x0301("5031112"), date(2021, 11, 12));
+        assert_eq!(parse_jis_x0301("3640107"), date(1989, 1, 7));
+        assert_eq!(parse_jis_x0301("R3.11.12"), Err(NihonifyError::ParseError));
+        assert_eq!(
+            parse_jis_x0301("R03.11.12.1"),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(parse_jis_x0301("503111"), Err(NihonifyError::ParseError));
+        assert_eq!(parse_jis_x0301("6031112"), Err(NihonifyError::UnknownEra));
+        assert_eq!(
+            parse_jis_x0301("4310501"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_compact_wareki() {
+        let date = |y, m, d| Ok(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(parse_compact_wareki("R3.11.12"), date(2021, 11, 12));
+        assert_eq!(parse_compact_wareki("r03.11.12"), date(2021, 11, 12));
+        assert_eq!(parse_compact_wareki("令3.11.12"), date(2021, 11, 12));
+        assert_eq!(parse_compact_wareki("H31/4/30"), date(2019, 4, 30));
+        assert_eq!(parse_compact_wareki("昭64-1-7"), date(1989, 1, 7));
+        assert_eq!(parse_compact_wareki("大１５.１２.２４"), date(1926, 12, 24));
+        assert_eq!(
+            parse_compact_wareki("H31.5.1"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_compact_wareki("X3.11.12"),
+            Err(NihonifyError::UnknownEra)
+        );
+        assert_eq!(
+            parse_compact_wareki("R3.11"),
+            Err(NihonifyError::ParseError)
+        );
+        assert_eq!(
+            parse_compact_wareki("R2147483647.1.1"),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(
+            parse_lenient_with("R3.11.12", &FixedClock(utc_dt("2021-11-12")), |_| ()),
+            date(2021, 11, 12)
+        );
+        assert_eq!(
+            parse_lenient_with("令3年11月12日", &FixedClock(utc_dt("2021-11-12")), |_| ()),
+            date(2021, 11, 12)
+        );
+    }
+
+    #[test]
+    fn test_parse_wareki_period_prefix() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            parse_wareki_period_prefix("昭和60年度"),
+            Some((date(1985, 1, 1), 11))
+        );
+        assert_eq!(
+            parse_wareki_period_prefix("令和3年11月"),
+            Some((date(2021, 11, 1), 15))
+        );
+        assert_eq!(
+            parse_wareki_period_prefix("令和3年11月12日"),
+            Some((date(2021, 11, 12), 20))
+        );
+        assert_eq!(
+            parse_wareki_period_prefix("令和元年"),
+            Some((date(2019, 5, 1), 12))
+        );
+        assert_eq!(parse_wareki_period_prefix("令和元年4月"), None);
+        assert_eq!(parse_wareki_period_prefix("令和2147483647年"), None);
+    }
+
+    #[test]
+    fn test_parse_lenient_reports_assumptions() {
+        assert_eq!(
+            parse("21/11/12").1,
+            vec![Assumption::TwoDigitYearExpanded { from: 21, to: 2021 }]
+        );
+        assert_eq!(
+            parse("98.1.2"),
+            (
+                Ok(NaiveDate::from_ymd_opt(1998, 1, 2).unwrap()),
+                vec![Assumption::TwoDigitYearExpanded { from: 98, to: 1998 }]
+            )
+        );
+
+        // Kanji numerals are normalized too, and 二十一年 is as short a year
+        // as 三十年.
+        assert_eq!(
+            parse("令和三年十一月十二日"),
+            (
+                Ok(NaiveDate::from_ymd_opt(2021, 11, 12).unwrap()),
+                vec![Assumption::NumeralsNormalized]
+            )
+        );
+        assert_eq!(parse("万延元年1月1日").1, Vec::new());
+        assert_eq!(
+            parse("二十一年一月一日").0,
+            Ok(NaiveDate::from_ymd_opt(2039, 1, 1).unwrap())
+        );
+        assert_eq!(
+            parse("三十年一月一日").0,
+            Ok(NaiveDate::from_ymd_opt(2048, 1, 1).unwrap())
+        );
+
+        let (result, assumptions) = parse("３年１１月１２日");
+        assert_eq!(result, Ok(NaiveDate::from_ymd_opt(2021, 11, 12).unwrap()));
+        assert_eq!(
+            assumptions,
+            vec![
+                Assumption::NumeralsNormalized,
+                Assumption::EraInferred(Era::from_datetime(utc_dt("2021-11-12")).unwrap())
+            ]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::calendar::{Era, Jidai};
+
+/// Private eras (私年号): era names that were used without being proclaimed
+/// by the court, e.g. in temple inscriptions and regional documents. Kept
+/// apart from `SORTED_ERAS`, since they overlap the official eras.
+///
+/// Only eras whose use is well attested are listed. Their exact start and end
+/// days aren't known, so they run from January 1st of the first year they're
+/// attested in to the end of the last.
+pub const PRIVATE_ERAS: &[Era] = &[
+    Era {
+        kanji: Some("法興"),
+        romaji: Some("houkou"),
+        reading: Some("ほうこう"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -43517034000,
+        ended_at: Some(-42507277200),
+    },
+    Era {
+        kanji: Some("白鳳"),
+        romaji: Some("hakuhou"),
+        reading: Some("はくほう"),
+        gloss: None,
+        jidai: Jidai::Asuka,
+        started_at: -40960976400,
+        ended_at: Some(-40487590800),
+    },
+    Era {
+        kanji: Some("福徳"),
+        romaji: Some("fukutoku"),
+        reading: Some("ふくとく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -15178870800,
+        ended_at: Some(-15052640400),
+    },
+    Era {
+        kanji: Some("弥勒"),
+        romaji: Some("miroku"),
+        reading: Some("みろく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -14642499600,
+        ended_at: Some(-14579427600),
+    },
+    Era {
+        kanji: Some("命禄"),
+        romaji: Some("meiroku"),
+        reading: Some("めいろく"),
+        gloss: None,
+        jidai: Jidai::Sengoku,
+        started_at: -13569584400,
+        ended_at: Some(-13443354000),
+    },
+];
+
+impl Era {
+    /// Whether this is a private era (私年号) rather than an official one.
+    pub fn is_private(&self) -> bool {
+        PRIVATE_ERAS.contains(self)
+    }
+
+    /// The private eras in use at the given time. Unlike official eras,
+    /// several can overlap.
+    pub fn private_from_unix_epoch(unix_epoch: i64) -> impl Iterator<Item = &'static Era> {
+        PRIVATE_ERAS.iter().filter(move |era| {
+            era.started_at <= unix_epoch && era.ended_at.is_none_or(|end| unix_epoch < end)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::{parse_wareki_const, parse_wareki_prefix, utc_dt};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_private_from_unix_epoch() {
+        let eras: Vec<_> = Era::private_from_unix_epoch(utc_dt("1507-06-01").timestamp())
+            .map(|era| era.kanji)
+            .collect();
+        assert_eq!(eras, vec![Some("弥勒")]);
+        assert!(
+            Era::private_from_unix_epoch(utc_dt("2000-01-01").timestamp())
+                .next()
+                .is_none()
+        );
+        assert!(PRIVATE_ERAS[0].is_private());
+    }
+
+    #[test]
+    fn test_parse_private_era() {
+        let date = NaiveDate::from_ymd_opt(1507, 3, 1).unwrap();
+        assert_eq!(parse_wareki_prefix("弥勒2年3月1日"), Some((date, 18)));
+        assert_eq!(parse_wareki_const("弥勒2年3月1日"), Some(date));
+    }
+}
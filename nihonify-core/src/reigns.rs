@@ -0,0 +1,764 @@
+use crate::calendar::Era;
+use crate::eras::SORTED_ERAS;
+use chrono::prelude::*;
+
+/// An emperor's reign. One reign can span several eras, and, before Meiji,
+/// one era several reigns.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Reign {
+    /// The emperor's place in the official succession, e.g. 126 for Naruhito.
+    pub number: u32,
+    /// The emperor's posthumous name, or their given name for the emperors
+    /// who don't have one yet, e.g. 明治 or 徳仁.
+    pub kanji: &'static str,
+    pub romaji: &'static str,
+    /// The unix epoch of midnight JST on the reign's first day.
+    pub started_at: i64,
+    /// The unix epoch of midnight JST after the reign's last day, or `None`
+    /// for the current reign.
+    pub ended_at: Option<i64>,
+}
+
+/// The reigns since Kōtoku, whose reign saw the first era, in order. The
+/// Northern Court (北朝) isn't part of the official succession, so its
+/// emperors are left out.
+///
+/// Reigns from Meiji onwards start on the day of accession. The accession days
+/// of earlier reigns aren't tracked, so they start on January 1st of the year
+/// of accession; a year with two accessions leaves the first reign empty.
+pub const REIGNS: &[Reign] = &[
+    Reign {
+        number: 36,
+        kanji: "孝徳",
+        romaji: "koutoku",
+        started_at: -41812966800,
+        ended_at: Some(-41497434000),
+    },
+    Reign {
+        number: 37,
+        kanji: "斉明",
+        romaji: "saimei",
+        started_at: -41497434000,
+        ended_at: Some(-41087206800),
+    },
+    Reign {
+        number: 38,
+        kanji: "天智",
+        romaji: "tenji",
+        started_at: -41087206800,
+        ended_at: Some(-40960976400),
+    },
+    Reign {
+        number: 39,
+        kanji: "弘文",
+        romaji: "koubun",
+        started_at: -40960976400,
+        ended_at: Some(-40929354000),
+    },
+    Reign {
+        number: 40,
+        kanji: "天武",
+        romaji: "tenmu",
+        started_at: -40929354000,
+        ended_at: Some(-40519126800),
+    },
+    Reign {
+        number: 41,
+        kanji: "持統",
+        romaji: "jitou",
+        started_at: -40519126800,
+        ended_at: Some(-40171971600),
+    },
+    Reign {
+        number: 42,
+        kanji: "文武",
+        romaji: "monmu",
+        started_at: -40171971600,
+        ended_at: Some(-39856525200),
+    },
+    Reign {
+        number: 43,
+        kanji: "元明",
+        romaji: "genmei",
+        started_at: -39856525200,
+        ended_at: Some(-39604064400),
+    },
+    Reign {
+        number: 44,
+        kanji: "元正",
+        romaji: "genshou",
+        started_at: -39604064400,
+        ended_at: Some(-39320067600),
+    },
+    Reign {
+        number: 45,
+        kanji: "聖武",
+        romaji: "shoumu",
+        started_at: -39320067600,
+        ended_at: Some(-38531062800),
+    },
+    Reign {
+        number: 46,
+        kanji: "孝謙",
+        romaji: "kouken",
+        started_at: -38531062800,
+        ended_at: Some(-38247066000),
+    },
+    Reign {
+        number: 47,
+        kanji: "淳仁",
+        romaji: "junnin",
+        started_at: -38247066000,
+        ended_at: Some(-38057763600),
+    },
+    Reign {
+        number: 48,
+        kanji: "称徳",
+        romaji: "shoutoku",
+        started_at: -38057763600,
+        ended_at: Some(-37868374800),
+    },
+    Reign {
+        number: 49,
+        kanji: "光仁",
+        romaji: "kounin",
+        started_at: -37868374800,
+        ended_at: Some(-37521219600),
+    },
+    Reign {
+        number: 50,
+        kanji: "桓武",
+        romaji: "kanmu",
+        started_at: -37521219600,
+        ended_at: Some(-36732301200),
+    },
+    Reign {
+        number: 51,
+        kanji: "平城",
+        romaji: "heizei",
+        started_at: -36732301200,
+        ended_at: Some(-36637606800),
+    },
+    Reign {
+        number: 52,
+        kanji: "嵯峨",
+        romaji: "saga",
+        started_at: -36637606800,
+        ended_at: Some(-36195843600),
+    },
+    Reign {
+        number: 53,
+        kanji: "淳和",
+        romaji: "junna",
+        started_at: -36195843600,
+        ended_at: Some(-35880224400),
+    },
+    Reign {
+        number: 54,
+        kanji: "仁明",
+        romaji: "ninmyou",
+        started_at: -35880224400,
+        ended_at: Some(-35343766800),
+    },
+    Reign {
+        number: 55,
+        kanji: "文徳",
+        romaji: "montoku",
+        started_at: -35343766800,
+        ended_at: Some(-35091306000),
+    },
+    Reign {
+        number: 56,
+        kanji: "清和",
+        romaji: "seiwa",
+        started_at: -35091306000,
+        ended_at: Some(-34523312400),
+    },
+    Reign {
+        number: 57,
+        kanji: "陽成",
+        romaji: "youzei",
+        started_at: -34523312400,
+        ended_at: Some(-34270851600),
+    },
+    Reign {
+        number: 58,
+        kanji: "光孝",
+        romaji: "koukou",
+        started_at: -34270851600,
+        ended_at: Some(-34176157200),
+    },
+    Reign {
+        number: 59,
+        kanji: "宇多",
+        romaji: "uda",
+        started_at: -34176157200,
+        ended_at: Some(-33860538000),
+    },
+    Reign {
+        number: 60,
+        kanji: "醍醐",
+        romaji: "daigo",
+        started_at: -33860538000,
+        ended_at: Some(-32819245200),
+    },
+    Reign {
+        number: 61,
+        kanji: "朱雀",
+        romaji: "suzaku",
+        started_at: -32819245200,
+        ended_at: Some(-32314323600),
+    },
+    Reign {
+        number: 62,
+        kanji: "村上",
+        romaji: "murakami",
+        started_at: -32314323600,
+        ended_at: Some(-31651635600),
+    },
+    Reign {
+        number: 63,
+        kanji: "冷泉",
+        romaji: "reizei",
+        started_at: -31651635600,
+        ended_at: Some(-31588477200),
+    },
+    Reign {
+        number: 64,
+        kanji: "円融",
+        romaji: "ennyuu",
+        started_at: -31588477200,
+        ended_at: Some(-31115178000),
+    },
+    Reign {
+        number: 65,
+        kanji: "花山",
+        romaji: "kazan",
+        started_at: -31115178000,
+        ended_at: Some(-31052019600),
+    },
+    Reign {
+        number: 66,
+        kanji: "一条",
+        romaji: "ichijou",
+        started_at: -31052019600,
+        ended_at: Some(-30263187600),
+    },
+    Reign {
+        number: 67,
+        kanji: "三条",
+        romaji: "sanjou",
+        started_at: -30263187600,
+        ended_at: Some(-30105421200),
+    },
+    Reign {
+        number: 68,
+        kanji: "後一条",
+        romaji: "goichijou",
+        started_at: -30105421200,
+        ended_at: Some(-29474269200),
+    },
+    Reign {
+        number: 69,
+        kanji: "後朱雀",
+        romaji: "gosuzaku",
+        started_at: -29474269200,
+        ended_at: Some(-29190186000),
+    },
+    Reign {
+        number: 70,
+        kanji: "後冷泉",
+        romaji: "goreizei",
+        started_at: -29190186000,
+        ended_at: Some(-28464426000),
+    },
+    Reign {
+        number: 71,
+        kanji: "後三条",
+        romaji: "gosanjou",
+        started_at: -28464426000,
+        ended_at: Some(-28306573200),
+    },
+    Reign {
+        number: 72,
+        kanji: "白河",
+        romaji: "shirakawa",
+        started_at: -28306573200,
+        ended_at: Some(-27864810000),
+    },
+    Reign {
+        number: 73,
+        kanji: "堀河",
+        romaji: "horikawa",
+        started_at: -27864810000,
+        ended_at: Some(-27233744400),
+    },
+    Reign {
+        number: 74,
+        kanji: "鳥羽",
+        romaji: "toba",
+        started_at: -27233744400,
+        ended_at: Some(-26728822800),
+    },
+    Reign {
+        number: 75,
+        kanji: "崇徳",
+        romaji: "sutoku",
+        started_at: -26728822800,
+        ended_at: Some(-26129206800),
+    },
+    Reign {
+        number: 76,
+        kanji: "近衛",
+        romaji: "konoe",
+        started_at: -26129206800,
+        ended_at: Some(-25718979600),
+    },
+    Reign {
+        number: 77,
+        kanji: "後白河",
+        romaji: "goshirakawa",
+        started_at: -25718979600,
+        ended_at: Some(-25624285200),
+    },
+    Reign {
+        number: 78,
+        kanji: "二条",
+        romaji: "nijou",
+        started_at: -25624285200,
+        ended_at: Some(-25403360400),
+    },
+    Reign {
+        number: 79,
+        kanji: "六条",
+        romaji: "rokujou",
+        started_at: -25403360400,
+        ended_at: Some(-25308752400),
+    },
+    Reign {
+        number: 80,
+        kanji: "高倉",
+        romaji: "takakura",
+        started_at: -25308752400,
+        ended_at: Some(-24930061200),
+    },
+    Reign {
+        number: 81,
+        kanji: "安徳",
+        romaji: "antoku",
+        started_at: -24930061200,
+        ended_at: Some(-24835366800),
+    },
+    Reign {
+        number: 82,
+        kanji: "後鳥羽",
+        romaji: "gotoba",
+        started_at: -24835366800,
+        ended_at: Some(-24361981200),
+    },
+    Reign {
+        number: 83,
+        kanji: "土御門",
+        romaji: "tsuchimikado",
+        started_at: -24361981200,
+        ended_at: Some(-23983290000),
+    },
+    Reign {
+        number: 84,
+        kanji: "順徳",
+        romaji: "juntoku",
+        started_at: -23983290000,
+        ended_at: Some(-23636134800),
+    },
+    Reign {
+        number: 85,
+        kanji: "仲恭",
+        romaji: "chuukyou",
+        started_at: -23636134800,
+        ended_at: Some(-23636134800),
+    },
+    Reign {
+        number: 86,
+        kanji: "後堀河",
+        romaji: "gohorikawa",
+        started_at: -23636134800,
+        ended_at: Some(-23289066000),
+    },
+    Reign {
+        number: 87,
+        kanji: "四条",
+        romaji: "shijou",
+        started_at: -23289066000,
+        ended_at: Some(-22973446800),
+    },
+    Reign {
+        number: 88,
+        kanji: "後嵯峨",
+        romaji: "gosaga",
+        started_at: -22973446800,
+        ended_at: Some(-22847216400),
+    },
+    Reign {
+        number: 89,
+        kanji: "後深草",
+        romaji: "gofukakusa",
+        started_at: -22847216400,
+        ended_at: Some(-22405453200),
+    },
+    Reign {
+        number: 90,
+        kanji: "亀山",
+        romaji: "kameyama",
+        started_at: -22405453200,
+        ended_at: Some(-21963603600),
+    },
+    Reign {
+        number: 91,
+        kanji: "後宇多",
+        romaji: "gouda",
+        started_at: -21963603600,
+        ended_at: Some(-21553376400),
+    },
+    Reign {
+        number: 92,
+        kanji: "伏見",
+        romaji: "fushimi",
+        started_at: -21553376400,
+        ended_at: Some(-21206221200),
+    },
+    Reign {
+        number: 93,
+        kanji: "後伏見",
+        romaji: "gofushimi",
+        started_at: -21206221200,
+        ended_at: Some(-21111613200),
+    },
+    Reign {
+        number: 94,
+        kanji: "後二条",
+        romaji: "gonijou",
+        started_at: -21111613200,
+        ended_at: Some(-20890774800),
+    },
+    Reign {
+        number: 95,
+        kanji: "花園",
+        romaji: "hanazono",
+        started_at: -20890774800,
+        ended_at: Some(-20575155600),
+    },
+    Reign {
+        number: 96,
+        kanji: "後醍醐",
+        romaji: "godaigo",
+        started_at: -20575155600,
+        ended_at: Some(-19912467600),
+    },
+    Reign {
+        number: 97,
+        kanji: "後村上",
+        romaji: "gomurakami",
+        started_at: -19912467600,
+        ended_at: Some(-18997318800),
+    },
+    Reign {
+        number: 98,
+        kanji: "長慶",
+        romaji: "choukei",
+        started_at: -18997318800,
+        ended_at: Some(-18523933200),
+    },
+    Reign {
+        number: 99,
+        kanji: "後亀山",
+        romaji: "gokameyama",
+        started_at: -18523933200,
+        ended_at: Some(-18239936400),
+    },
+    Reign {
+        number: 100,
+        kanji: "後小松",
+        romaji: "gokomatsu",
+        started_at: -18239936400,
+        ended_at: Some(-17608870800),
+    },
+    Reign {
+        number: 101,
+        kanji: "称光",
+        romaji: "shoukou",
+        started_at: -17608870800,
+        ended_at: Some(-17103949200),
+    },
+    Reign {
+        number: 102,
+        kanji: "後花園",
+        romaji: "gohanazono",
+        started_at: -17103949200,
+        ended_at: Some(-15967875600),
+    },
+    Reign {
+        number: 103,
+        kanji: "後土御門",
+        romaji: "gotsuchimikado",
+        started_at: -15967875600,
+        ended_at: Some(-14831802000),
+    },
+    Reign {
+        number: 104,
+        kanji: "後柏原",
+        romaji: "gokashiwabara",
+        started_at: -14831802000,
+        ended_at: Some(-14011347600),
+    },
+    Reign {
+        number: 105,
+        kanji: "後奈良",
+        romaji: "gonara",
+        started_at: -14011347600,
+        ended_at: Some(-13033040400),
+    },
+    Reign {
+        number: 106,
+        kanji: "正親町",
+        romaji: "oogimachi",
+        started_at: -13033040400,
+        ended_at: Some(-12117891600),
+    },
+    Reign {
+        number: 107,
+        kanji: "後陽成",
+        romaji: "goyouzei",
+        started_at: -12117891600,
+        ended_at: Some(-11328973200),
+    },
+    Reign {
+        number: 108,
+        kanji: "後水尾",
+        romaji: "gomizunoo",
+        started_at: -11328973200,
+        ended_at: Some(-10760893200),
+    },
+    Reign {
+        number: 109,
+        kanji: "明正",
+        romaji: "meishou",
+        started_at: -10760893200,
+        ended_at: Some(-10319130000),
+    },
+    Reign {
+        number: 110,
+        kanji: "後光明",
+        romaji: "gokoumyou",
+        started_at: -10319130000,
+        ended_at: Some(-9940438800),
+    },
+    Reign {
+        number: 111,
+        kanji: "後西",
+        romaji: "gosai",
+        started_at: -9940438800,
+        ended_at: Some(-9687978000),
+    },
+    Reign {
+        number: 112,
+        kanji: "霊元",
+        romaji: "reigen",
+        started_at: -9687978000,
+        ended_at: Some(-8930595600),
+    },
+    Reign {
+        number: 113,
+        kanji: "東山",
+        romaji: "higashiyama",
+        started_at: -8930595600,
+        ended_at: Some(-8236371600),
+    },
+    Reign {
+        number: 114,
+        kanji: "中御門",
+        romaji: "nakamikado",
+        started_at: -8236371600,
+        ended_at: Some(-7415917200),
+    },
+    Reign {
+        number: 115,
+        kanji: "桜町",
+        romaji: "sakuramachi",
+        started_at: -7415917200,
+        ended_at: Some(-7037226000),
+    },
+    Reign {
+        number: 116,
+        kanji: "桃園",
+        romaji: "momozono",
+        started_at: -7037226000,
+        ended_at: Some(-6563840400),
+    },
+    Reign {
+        number: 117,
+        kanji: "後桜町",
+        romaji: "gosakuramachi",
+        started_at: -6563840400,
+        ended_at: Some(-6279843600),
+    },
+    Reign {
+        number: 118,
+        kanji: "後桃園",
+        romaji: "gomomozono",
+        started_at: -6279843600,
+        ended_at: Some(-6027382800),
+    },
+    Reign {
+        number: 119,
+        kanji: "光格",
+        romaji: "koukaku",
+        started_at: -6027382800,
+        ended_at: Some(-4828237200),
+    },
+    Reign {
+        number: 120,
+        kanji: "仁孝",
+        romaji: "ninkou",
+        started_at: -4828237200,
+        ended_at: Some(-3913088400),
+    },
+    Reign {
+        number: 121,
+        kanji: "孝明",
+        romaji: "koumei",
+        started_at: -3913088400,
+        ended_at: Some(-3246685200),
+    },
+    Reign {
+        number: 122,
+        kanji: "明治",
+        romaji: "meiji",
+        started_at: -3246685200,
+        ended_at: Some(-1812186000),
+    },
+    Reign {
+        number: 123,
+        kanji: "大正",
+        romaji: "taishou",
+        started_at: -1812186000,
+        ended_at: Some(-1357635600),
+    },
+    Reign {
+        number: 124,
+        kanji: "昭和",
+        romaji: "shouwa",
+        started_at: -1357635600,
+        ended_at: Some(600102000),
+    },
+    Reign {
+        number: 125,
+        kanji: "明仁",
+        romaji: "akihito",
+        started_at: 600102000,
+        ended_at: Some(1556636400),
+    },
+    Reign {
+        number: 126,
+        kanji: "徳仁",
+        romaji: "naruhito",
+        started_at: 1556636400,
+        ended_at: None,
+    },
+];
+
+impl Reign {
+    /// Given a datetime, returns the reign it falls in.
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Option<&'static Reign> {
+        Reign::from_unix_epoch(datetime.timestamp())
+    }
+
+    /// Given a unix epoch, returns the reign it falls in.
+    pub fn from_unix_epoch(unix_epoch: i64) -> Option<&'static Reign> {
+        REIGNS.iter().find(|reign| {
+            reign.started_at <= unix_epoch && reign.ended_at.is_none_or(|end| unix_epoch < end)
+        })
+    }
+
+    /// The eras proclaimed during, or still in use at the start of, this
+    /// reign.
+    pub fn eras(&self) -> impl Iterator<Item = &'static Era> + '_ {
+        SORTED_ERAS.iter().filter(move |era| {
+            overlaps(self.started_at, self.ended_at, era.started_at, era.ended_at)
+        })
+    }
+}
+
+impl Era {
+    /// The reign this era was proclaimed in.
+    pub fn emperor(&self) -> Option<&'static Reign> {
+        Reign::from_unix_epoch(self.started_at)
+    }
+
+    /// The reigns this era was in use during.
+    pub fn reigns(&self) -> impl Iterator<Item = &'static Reign> + '_ {
+        REIGNS.iter().filter(move |reign| {
+            overlaps(
+                self.started_at,
+                self.ended_at,
+                reign.started_at,
+                reign.ended_at,
+            )
+        })
+    }
+}
+
+/// Whether the two half-open spans share a moment.
+fn overlaps(a_start: i64, a_end: Option<i64>, b_start: i64, b_end: Option<i64>) -> bool {
+    a_end.is_none_or(|a_end| b_start < a_end) && b_end.is_none_or(|b_end| a_start < b_end)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::utc_dt;
+
+    #[test]
+    fn test_from_datetime() {
+        let kanji = |date| Reign::from_datetime(utc_dt(date)).map(|reign| reign.kanji);
+        assert_eq!(kanji("2021-11-12"), Some("徳仁"));
+        assert_eq!(kanji("2019-04-30"), Some("明仁"));
+        assert_eq!(kanji("1989-01-07"), Some("明仁"));
+        assert_eq!(kanji("1989-01-06"), Some("昭和"));
+        assert_eq!(kanji("1700-06-01"), Some("東山"));
+        assert_eq!(kanji("0600-01-01"), None);
+    }
+
+    #[test]
+    fn test_reigns_are_contiguous() {
+        for pair in REIGNS.windows(2) {
+            assert_eq!(pair[0].number + 1, pair[1].number);
+            assert_eq!(pair[0].ended_at, Some(pair[1].started_at));
+        }
+    }
+
+    #[test]
+    fn test_cross_links() {
+        let showa = Reign::from_datetime(utc_dt("1970-01-01")).unwrap();
+        let eras: Vec<_> = showa.eras().map(|era| era.kanji).collect();
+        assert_eq!(eras, vec![Some("昭和")]);
+
+        // Akihito acceded on January 7th, 1989, the last day of Shōwa.
+        let akihito = Reign::from_datetime(utc_dt("2000-01-01")).unwrap();
+        let eras: Vec<_> = akihito.eras().map(|era| era.kanji).collect();
+        assert_eq!(eras, vec![Some("昭和"), Some("平成")]);
+
+        let meiji = Era::from_datetime(utc_dt("1900-01-01")).unwrap();
+        let reigns: Vec<_> = meiji.reigns().map(|reign| reign.kanji).collect();
+        assert_eq!(reigns, vec!["明治"]);
+
+        let heisei = Era::from_datetime(utc_dt("2000-01-01")).unwrap();
+        let reigns: Vec<_> = heisei.reigns().map(|reign| reign.kanji).collect();
+        assert_eq!(reigns, vec!["明仁"]);
+        assert_eq!(heisei.emperor().unwrap().number, 125);
+
+        // Reiwa was proclaimed on Naruhito's accession day.
+        let reiwa = Era::from_datetime(utc_dt("2019-05-01")).unwrap();
+        assert_eq!(reiwa.emperor().unwrap().kanji, "徳仁");
+        assert_eq!(Era::from_index(0).unwrap().emperor().unwrap().number, 36);
+    }
+}
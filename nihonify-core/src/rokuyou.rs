@@ -0,0 +1,94 @@
+//! The rokuyō (六曜), the six-day cycle of lucky and unlucky days printed on
+//! Japanese calendars, which weddings and funerals are still scheduled by.
+
+use crate::error::NihonifyError;
+use crate::lunisolar::LunisolarDate;
+use chrono::NaiveDate;
+
+/// One of the six days of the rokuyō cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rokuyou {
+    /// 先勝, lucky in the morning.
+    Senshou,
+    /// 友引, unlucky for funerals.
+    Tomobiki,
+    /// 先負, lucky in the afternoon.
+    Senbu,
+    /// 仏滅, the unluckiest day.
+    Butsumetsu,
+    /// 大安, the luckiest day, favored for weddings.
+    Taian,
+    /// 赤口, lucky only around noon.
+    Shakkou,
+}
+
+impl Rokuyou {
+    /// The day's name in kanji, e.g. 大安.
+    pub fn kanji(&self) -> &'static str {
+        match self {
+            Rokuyou::Senshou => "先勝",
+            Rokuyou::Tomobiki => "友引",
+            Rokuyou::Senbu => "先負",
+            Rokuyou::Butsumetsu => "仏滅",
+            Rokuyou::Taian => "大安",
+            Rokuyou::Shakkou => "赤口",
+        }
+    }
+
+    /// The day's name in hiragana, e.g. たいあん.
+    pub fn reading(&self) -> &'static str {
+        match self {
+            Rokuyou::Senshou => "せんしょう",
+            Rokuyou::Tomobiki => "ともびき",
+            Rokuyou::Senbu => "せんぶ",
+            Rokuyou::Butsumetsu => "ぶつめつ",
+            Rokuyou::Taian => "たいあん",
+            Rokuyou::Shakkou => "しゃっこう",
+        }
+    }
+
+    /// The rokuyō of the date, by its day in the lunisolar calendar: the 1st
+    /// of the 1st month is 先勝, and each day and each month moves one on.
+    /// Leap months count as the month they repeat. Dates the lunisolar tables
+    /// don't cover, before 1590 or after 2100, are out of range.
+    pub fn from_date(date: NaiveDate) -> Result<Rokuyou, NihonifyError> {
+        const CYCLE: [Rokuyou; 6] = [
+            Rokuyou::Taian,
+            Rokuyou::Shakkou,
+            Rokuyou::Senshou,
+            Rokuyou::Tomobiki,
+            Rokuyou::Senbu,
+            Rokuyou::Butsumetsu,
+        ];
+        let lunisolar = LunisolarDate::from_gregorian(date)?;
+        Ok(CYCLE[usize::from(lunisolar.month() + lunisolar.day()) % 6])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rokuyou(y: i32, m: u32, d: u32) -> &'static str {
+        Rokuyou::from_date(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+            .unwrap()
+            .kanji()
+    }
+
+    #[test]
+    fn test_from_date() {
+        // 旧正月 is always 先勝.
+        assert_eq!(rokuyou(2021, 2, 12), "先勝");
+        assert_eq!(rokuyou(2024, 1, 1), "赤口");
+        assert_eq!(rokuyou(2021, 11, 12), "大安");
+        // The cycle restarts with each month, 2021-03-13 being 2月1日.
+        assert_eq!(rokuyou(2021, 3, 12), "大安");
+        assert_eq!(rokuyou(2021, 3, 13), "友引");
+        // 閏2月 of 2023 counts as the 2nd month.
+        assert_eq!(rokuyou(2023, 3, 22), "友引");
+        assert_eq!(
+            Rokuyou::from_date(NaiveDate::from_ymd_opt(2200, 1, 1).unwrap()),
+            Err(NihonifyError::DateOutOfRange)
+        );
+    }
+}
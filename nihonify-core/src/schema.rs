@@ -0,0 +1,140 @@
+//! `JsonSchema` implementations, for publishing schemas of APIs built on this
+//! crate. The `serde` with-modules have matching `schema` functions, for use
+//! with `#[schemars(schema_with = "...")]`.
+
+use crate::calendar::{Era, Jidai, WarekiDate};
+use crate::eras::{SORTED_ERAS, SOUTHERN_COURT_ERAS};
+use crate::holidays::Holiday;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// An era is identified by its romaji name, e.g. "reiwa", or by its kanji
+/// name if a later era has the same romaji name.
+impl JsonSchema for Era {
+    fn schema_name() -> Cow<'static, str> {
+        "Era".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "nihonify::Era".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        // Both courts' eras, in order.
+        let mut eras: Vec<&Era> = SORTED_ERAS.iter().chain(SOUTHERN_COURT_ERAS).collect();
+        eras.sort_by_key(|era| era.started_at);
+        let names: Vec<&str> = eras.into_iter().filter_map(Era::identifier).collect();
+        json_schema!({
+            "description": "A Japanese era, by its romaji name.",
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+impl JsonSchema for Jidai {
+    fn schema_name() -> Cow<'static, str> {
+        "Jidai".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "nihonify::Jidai".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A period of Japanese history.",
+            "type": "string",
+            "enum": [
+                "Asuka",
+                "Nara",
+                "Heian",
+                "Kamakura",
+                "Nanbokuchou",
+                "Sengoku",
+                "Muromachi",
+                "AzuchiMomoyama",
+                "Edo",
+                "Modern",
+            ],
+        })
+    }
+}
+
+/// A wareki date is a string, as serialized with the `serde` feature.
+impl JsonSchema for WarekiDate {
+    fn schema_name() -> Cow<'static, str> {
+        "WarekiDate".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "nihonify::WarekiDate".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A wareki date, e.g. 令和3年11月12日.",
+            "type": "string",
+            "pattern": "^[^0-9０-９元]+([0-9０-９]+|元)年[0-9０-９]{1,2}月[0-9０-９]{1,2}日$",
+        })
+    }
+}
+
+/// A holiday is an object, as serialized with the `serde` feature.
+impl JsonSchema for Holiday {
+    fn schema_name() -> Cow<'static, str> {
+        "Holiday".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "nihonify::Holiday".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A national holiday, or a day off under the Public Holiday Act.",
+            "type": "object",
+            "properties": {
+                "date": { "type": "string", "format": "date" },
+                "name": { "type": "string" },
+            },
+            "required": ["date", "name"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schemars::schema_for;
+
+    #[test]
+    fn test_era_schema() {
+        let schema = schema_for!(Era);
+        let romaji = schema.get("enum").unwrap().as_array().unwrap();
+        assert_eq!(romaji.first().unwrap(), "taika");
+        assert_eq!(romaji.last().unwrap(), "reiwa");
+        assert!(romaji.contains(&"承和".into()));
+        assert!(romaji.contains(&"shouhei".into()));
+    }
+
+    #[test]
+    fn test_jidai_schema() {
+        let schema = schema_for!(Jidai);
+        assert_eq!(schema.get("enum").unwrap().as_array().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_wareki_date_schema() {
+        let schema = schema_for!(WarekiDate);
+        assert_eq!(schema.get("type").unwrap(), "string");
+    }
+
+    #[test]
+    fn test_holiday_schema() {
+        let schema = schema_for!(Holiday);
+        assert_eq!(schema.get("type").unwrap(), "object");
+        assert_eq!(schema.get("required").unwrap().as_array().unwrap().len(), 2);
+    }
+}
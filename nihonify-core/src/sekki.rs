@@ -0,0 +1,465 @@
+//! The twenty-four solar terms (二十四節気), which split the year by the
+//! sun's longitude into 15° steps from 春分 at 0°. The lunisolar calendar
+//! places its leap months by them, and 春分 and 秋分 are national holidays.
+//!
+//! The dates are in Japan Standard Time and cover 1873 to 2100. They were
+//! worked out with modern astronomy, the same way as the lunisolar tables,
+//! and agree with the equinoxes the holidays are set by.
+
+use crate::error::NihonifyError;
+use chrono::prelude::*;
+
+/// One of the twenty-four solar terms, in order from 立春, the traditional
+/// start of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sekki {
+    /// 立春, the start of spring.
+    Risshun,
+    /// 雨水, snow turning to rain.
+    Usui,
+    /// 啓蟄, insects waking.
+    Keichitsu,
+    /// 春分, the vernal equinox.
+    Shunbun,
+    /// 清明, clear and bright.
+    Seimei,
+    /// 穀雨, rain for the grain.
+    Kokuu,
+    /// 立夏, the start of summer.
+    Rikka,
+    /// 小満, the grain filling out.
+    Shouman,
+    /// 芒種, sowing the grain.
+    Boushu,
+    /// 夏至, the summer solstice.
+    Geshi,
+    /// 小暑, lesser heat.
+    Shousho,
+    /// 大暑, greater heat.
+    Taisho,
+    /// 立秋, the start of autumn.
+    Risshuu,
+    /// 処暑, the heat abating.
+    Shosho,
+    /// 白露, white dew.
+    Hakuro,
+    /// 秋分, the autumnal equinox.
+    Shuubun,
+    /// 寒露, cold dew.
+    Kanro,
+    /// 霜降, the first frost.
+    Soukou,
+    /// 立冬, the start of winter.
+    Rittou,
+    /// 小雪, lesser snow.
+    Shousetsu,
+    /// 大雪, greater snow.
+    Taisetsu,
+    /// 冬至, the winter solstice.
+    Touji,
+    /// 小寒, lesser cold.
+    Shoukan,
+    /// 大寒, greater cold.
+    Daikan,
+}
+
+const SEKKI: [Sekki; 24] = [
+    Sekki::Risshun,
+    Sekki::Usui,
+    Sekki::Keichitsu,
+    Sekki::Shunbun,
+    Sekki::Seimei,
+    Sekki::Kokuu,
+    Sekki::Rikka,
+    Sekki::Shouman,
+    Sekki::Boushu,
+    Sekki::Geshi,
+    Sekki::Shousho,
+    Sekki::Taisho,
+    Sekki::Risshuu,
+    Sekki::Shosho,
+    Sekki::Hakuro,
+    Sekki::Shuubun,
+    Sekki::Kanro,
+    Sekki::Soukou,
+    Sekki::Rittou,
+    Sekki::Shousetsu,
+    Sekki::Taisetsu,
+    Sekki::Touji,
+    Sekki::Shoukan,
+    Sekki::Daikan,
+];
+
+/// The earliest day of the month each term falls on, in the order they fall
+/// in a Gregorian year, from 小寒 in January to 冬至 in December.
+const FIRST_DAYS: [u32; 24] = [
+    4, 19, 3, 18, 4, 19, 4, 19, 4, 20, 4, 20, 6, 22, 6, 22, 6, 22, 7, 22, 6, 21, 6, 21,
+];
+
+/// The years, each with how many days after its earliest day each term fell
+/// on, two bits per term from 小寒 in the lowest bits.
+const YEARS: &[(i32, u64)] = &[
+    (1873, 0x555555655515),
+    (1874, 0x555665a65a55),
+    (1875, 0x5aa665a65a56),
+    (1876, 0x15555155455a),
+    (1877, 0x555555655515),
+    (1878, 0x555665665a55),
+    (1879, 0x5aa665a65a56),
+    (1880, 0x15555155455a),
+    (1881, 0x555555555515),
+    (1882, 0x555665665a55),
+    (1883, 0x56a665a65a56),
+    (1884, 0x155551554556),
+    (1885, 0x555555554505),
+    (1886, 0x555665655a55),
+    (1887, 0x569665a65a55),
+    (1888, 0x155151554556),
+    (1889, 0x155555554505),
+    (1890, 0x555665655955),
+    (1891, 0x569665a65a55),
+    (1892, 0x055151514556),
+    (1893, 0x155555554505),
+    (1894, 0x555655655555),
+    (1895, 0x569665a65a55),
+    (1896, 0x055151510556),
+    (1897, 0x155555554505),
+    (1898, 0x555655655515),
+    (1899, 0x559665a65a55),
+    (1900, 0x5aa6a6a65a56),
+    (1901, 0x6aaaa6aa9a5a),
+    (1902, 0xaaaaaabaaa6a),
+    (1903, 0xaaabbafbafaa),
+    (1904, 0x5aa665a65aab),
+    (1905, 0x6aaaa6aa9a5a),
+    (1906, 0xaaaaaabaaa6a),
+    (1907, 0xaaabbabbafaa),
+    (1908, 0x5aa665a65aab),
+    (1909, 0x6aaaa6aa9a5a),
+    (1910, 0xaaaaaaaaaa6a),
+    (1911, 0xaaabbabbafaa),
+    (1912, 0x5aa665a65aab),
+    (1913, 0x6aaaa6aa9a5a),
+    (1914, 0xaaaaaaaaaa6a),
+    (1915, 0xaaabbabbafaa),
+    (1916, 0x56a665a65aab),
+    (1917, 0x6aaaa6aa9a56),
+    (1918, 0xaaaaaaaa9a5a),
+    (1919, 0xaaabaabaafaa),
+    (1920, 0x569665a65aaa),
+    (1921, 0x6aa6a6aa9a56),
+    (1922, 0x6aaaaaaa9a5a),
+    (1923, 0xaaabaabaaeaa),
+    (1924, 0x569665a65aaa),
+    (1925, 0x5aa6a6a65a56),
+    (1926, 0x6aaaaaaa9a5a),
+    (1927, 0xaaabaabaaaaa),
+    (1928, 0x569665a65aaa),
+    (1929, 0x5aa6a6a65a56),
+    (1930, 0x6aaaa6aa9a5a),
+    (1931, 0xaaabaabaaa6a),
+    (1932, 0x559665a65aaa),
+    (1933, 0x5aa665a65a56),
+    (1934, 0x6aaaa6aa9a5a),
+    (1935, 0xaaaaaabaaa6a),
+    (1936, 0x555665665aaa),
+    (1937, 0x5aa665a65a56),
+    (1938, 0x6aaaa6aa9a5a),
+    (1939, 0xaaaaaaaaaa6a),
+    (1940, 0x555665665aaa),
+    (1941, 0x5aa665a65a56),
+    (1942, 0x6aaaa6aa9a5a),
+    (1943, 0xaaaaaaaaaa6a),
+    (1944, 0x555665665aaa),
+    (1945, 0x5aa665a65a56),
+    (1946, 0x6aaaa6aa9a5a),
+    (1947, 0xaaaaaaaaaa6a),
+    (1948, 0x555665655aaa),
+    (1949, 0x5aa665a65a56),
+    (1950, 0x6aa6a6aa9a5a),
+    (1951, 0xaaaaaaaa9a6a),
+    (1952, 0x555655655aaa),
+    (1953, 0x569665a65a56),
+    (1954, 0x6aa6a6a69a56),
+    (1955, 0xaaaaaaaa9a5a),
+    (1956, 0x5556556559aa),
+    (1957, 0x569665a65a55),
+    (1958, 0x5aa6a6a65a56),
+    (1959, 0x6aaaa6aa9a5a),
+    (1960, 0x5556556555aa),
+    (1961, 0x569665a65a55),
+    (1962, 0x5aa6a5a65a56),
+    (1963, 0x6aaaa6aa9a5a),
+    (1964, 0x55555565556a),
+    (1965, 0x569665665a55),
+    (1966, 0x5aa665a65a56),
+    (1967, 0x6aaaa6aa9a5a),
+    (1968, 0x55555565556a),
+    (1969, 0x555665665a55),
+    (1970, 0x5aa665a65a56),
+    (1971, 0x6aaaa6aa9a5a),
+    (1972, 0x55555555556a),
+    (1973, 0x555665665a55),
+    (1974, 0x5aa665a65a56),
+    (1975, 0x6aaaa6aa9a5a),
+    (1976, 0x55555555556a),
+    (1977, 0x555665665a55),
+    (1978, 0x5aa665a65a56),
+    (1979, 0x6aaaa6aa9a5a),
+    (1980, 0x55555555556a),
+    (1981, 0x555655655a55),
+    (1982, 0x5aa665a65a56),
+    (1983, 0x6aa6a6aa9a5a),
+    (1984, 0x55555555456a),
+    (1985, 0x555655655a55),
+    (1986, 0x569665a65a56),
+    (1987, 0x6aa6a6a65a56),
+    (1988, 0x55555155455a),
+    (1989, 0x555655655955),
+    (1990, 0x569665a65a55),
+    (1991, 0x5aa6a5a65a56),
+    (1992, 0x15555155455a),
+    (1993, 0x555655655555),
+    (1994, 0x569665665a55),
+    (1995, 0x5aa665a65a56),
+    (1996, 0x15555155455a),
+    (1997, 0x555555655515),
+    (1998, 0x565665665a55),
+    (1999, 0x5aa665a65a56),
+    (2000, 0x15555155455a),
+    (2001, 0x555555555515),
+    (2002, 0x555665665a55),
+    (2003, 0x5aa665a65a56),
+    (2004, 0x15555155455a),
+    (2005, 0x555555555515),
+    (2006, 0x555665665a55),
+    (2007, 0x5aa665a65a56),
+    (2008, 0x15555155455a),
+    (2009, 0x555555555515),
+    (2010, 0x555655655a55),
+    (2011, 0x5aa665a65a56),
+    (2012, 0x15515155455a),
+    (2013, 0x555555555515),
+    (2014, 0x555655655a55),
+    (2015, 0x5a9665a65a56),
+    (2016, 0x15515151455a),
+    (2017, 0x555555554515),
+    (2018, 0x555655655a55),
+    (2019, 0x569665a65a56),
+    (2020, 0x155151510556),
+    (2021, 0x555551554505),
+    (2022, 0x555655655955),
+    (2023, 0x569665665a56),
+    (2024, 0x155110510556),
+    (2025, 0x555551554505),
+    (2026, 0x555555655555),
+    (2027, 0x569665665a55),
+    (2028, 0x055110510556),
+    (2029, 0x155551554505),
+    (2030, 0x555555555515),
+    (2031, 0x565665665a55),
+    (2032, 0x055110510556),
+    (2033, 0x155551554505),
+    (2034, 0x555555555515),
+    (2035, 0x555665665a55),
+    (2036, 0x055110510556),
+    (2037, 0x155551554505),
+    (2038, 0x555555555515),
+    (2039, 0x555665665a55),
+    (2040, 0x055110510556),
+    (2041, 0x155551554505),
+    (2042, 0x555555555515),
+    (2043, 0x555655655a55),
+    (2044, 0x055110510556),
+    (2045, 0x155151514505),
+    (2046, 0x555555554515),
+    (2047, 0x555655655a55),
+    (2048, 0x054110510556),
+    (2049, 0x155151514505),
+    (2050, 0x555551554515),
+    (2051, 0x555655655a55),
+    (2052, 0x014110510556),
+    (2053, 0x155150510501),
+    (2054, 0x555551554515),
+    (2055, 0x555655655955),
+    (2056, 0x014110110556),
+    (2057, 0x155110510501),
+    (2058, 0x555551554505),
+    (2059, 0x555555655555),
+    (2060, 0x014110110555),
+    (2061, 0x055110510501),
+    (2062, 0x155551554505),
+    (2063, 0x555555555555),
+    (2064, 0x010110110555),
+    (2065, 0x055110510501),
+    (2066, 0x155551554505),
+    (2067, 0x555555555515),
+    (2068, 0x000110110555),
+    (2069, 0x055110510501),
+    (2070, 0x155551554505),
+    (2071, 0x555555555515),
+    (2072, 0x000100100555),
+    (2073, 0x055110510501),
+    (2074, 0x155551554505),
+    (2075, 0x555555555515),
+    (2076, 0x000100100555),
+    (2077, 0x055110510501),
+    (2078, 0x155151514505),
+    (2079, 0x555551554515),
+    (2080, 0x000100100555),
+    (2081, 0x054110510501),
+    (2082, 0x155150510505),
+    (2083, 0x555551554515),
+    (2084, 0x000100100555),
+    (2085, 0x014110110501),
+    (2086, 0x155110510505),
+    (2087, 0x555551554515),
+    (2088, 0x000000100455),
+    (2089, 0x014110110501),
+    (2090, 0x155110510501),
+    (2091, 0x555551554505),
+    (2092, 0x000000000055),
+    (2093, 0x014110110500),
+    (2094, 0x155110510501),
+    (2095, 0x155551554505),
+    (2096, 0x000000000055),
+    (2097, 0x010110110500),
+    (2098, 0x055110510501),
+    (2099, 0x155551554505),
+    (2100, 0x555555555515),
+];
+
+impl Sekki {
+    /// The term's name in kanji, e.g. 立春.
+    pub fn kanji(&self) -> &'static str {
+        [
+            "立春", "雨水", "啓蟄", "春分", "清明", "穀雨", "立夏", "小満", "芒種", "夏至", "小暑",
+            "大暑", "立秋", "処暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
+            "小寒", "大寒",
+        ][*self as usize]
+    }
+
+    /// The term's name in hiragana, e.g. りっしゅん.
+    pub fn reading(&self) -> &'static str {
+        [
+            "りっしゅん",
+            "うすい",
+            "けいちつ",
+            "しゅんぶん",
+            "せいめい",
+            "こくう",
+            "りっか",
+            "しょうまん",
+            "ぼうしゅ",
+            "げし",
+            "しょうしょ",
+            "たいしょ",
+            "りっしゅう",
+            "しょしょ",
+            "はくろ",
+            "しゅうぶん",
+            "かんろ",
+            "そうこう",
+            "りっとう",
+            "しょうせつ",
+            "たいせつ",
+            "とうじ",
+            "しょうかん",
+            "だいかん",
+        ][*self as usize]
+    }
+
+    /// The sun's longitude in degrees when the term starts, e.g. 315 for 立春.
+    pub fn longitude(&self) -> u16 {
+        (315 + 15 * *self as u16) % 360
+    }
+
+    /// The day the term starts in the year.
+    pub fn date_in(&self, year: i32) -> Result<NaiveDate, NihonifyError> {
+        let index = (*self as usize + 2) % 24;
+        Ok(sekki_in_year(year)?[index].1)
+    }
+
+    /// The term the date falls in: the last to start on or before it.
+    /// Dates the tables don't cover, before 小寒 of 1873 or after 2100, are
+    /// out of range.
+    pub fn from_date(date: NaiveDate) -> Result<Sekki, NihonifyError> {
+        let terms = sekki_in_year(date.year())?;
+        match terms.iter().rev().find(|&&(_, start)| start <= date) {
+            Some(&(sekki, _)) => Ok(sekki),
+            // Before 小寒, still in the previous year's 冬至.
+            None => sekki_in_year(date.year() - 1).map(|_| Sekki::Touji),
+        }
+    }
+}
+
+/// The terms of the year, in the order they fall, from 小寒 in January to
+/// 冬至 in December.
+pub fn sekki_in_year(year: i32) -> Result<[(Sekki, NaiveDate); 24], NihonifyError> {
+    let &(_, offsets) = YEARS
+        .binary_search_by_key(&year, |&(year, _)| year)
+        .map(|index| &YEARS[index])
+        .map_err(|_| NihonifyError::DateOutOfRange)?;
+    let mut terms = [(Sekki::Shoukan, NaiveDate::MIN); 24];
+    for (n, term) in terms.iter_mut().enumerate() {
+        let day = FIRST_DAYS[n] + ((offsets >> (2 * n)) & 0b11) as u32;
+        let month = n as u32 / 2 + 1;
+        *term = (
+            SEKKI[(n + 22) % 24],
+            NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        );
+    }
+    Ok(terms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_sekki_in_year() {
+        let terms = sekki_in_year(2021).unwrap();
+        assert_eq!(terms[0], (Sekki::Shoukan, ymd(2021, 1, 5)));
+        // 立春 fell on 2月3日 for the first time since 1897.
+        assert_eq!(terms[2], (Sekki::Risshun, ymd(2021, 2, 3)));
+        assert_eq!(Sekki::Risshun.date_in(1897), Ok(ymd(1897, 2, 3)));
+        assert_eq!(Sekki::Risshun.date_in(2020), Ok(ymd(2020, 2, 4)));
+        assert_eq!(terms[11], (Sekki::Geshi, ymd(2021, 6, 21)));
+        assert_eq!(terms[23], (Sekki::Touji, ymd(2021, 12, 22)));
+        assert_eq!(Sekki::Touji.date_in(2025), Ok(ymd(2025, 12, 22)));
+        assert_eq!(sekki_in_year(1872), Err(NihonifyError::DateOutOfRange));
+        assert_eq!(sekki_in_year(2101), Err(NihonifyError::DateOutOfRange));
+    }
+
+    #[test]
+    fn test_from_date() {
+        assert_eq!(Sekki::from_date(ymd(2021, 2, 2)), Ok(Sekki::Daikan));
+        assert_eq!(Sekki::from_date(ymd(2021, 2, 3)), Ok(Sekki::Risshun));
+        assert_eq!(Sekki::from_date(ymd(2021, 11, 12)), Ok(Sekki::Rittou));
+        assert_eq!(Sekki::from_date(ymd(2021, 1, 1)), Ok(Sekki::Touji));
+        assert_eq!(Sekki::from_date(ymd(2100, 12, 31)), Ok(Sekki::Touji));
+        assert_eq!(
+            Sekki::from_date(ymd(1873, 1, 1)),
+            Err(NihonifyError::DateOutOfRange)
+        );
+        assert_eq!(Sekki::Shunbun.longitude(), 0);
+        assert_eq!(Sekki::Risshun.longitude(), 315);
+        assert_eq!(Sekki::Touji.kanji(), "冬至");
+        assert_eq!(Sekki::Touji.reading(), "とうじ");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_equinox_holidays() {
+        for year in 1949..=2100 {
+            let holidays = crate::holidays::holidays_in_year(year);
+            let day_of = |name| holidays.iter().find(|day| day.name == name).unwrap().date;
+            assert_eq!(Sekki::Shunbun.date_in(year), Ok(day_of("春分の日")));
+            assert_eq!(Sekki::Shuubun.date_in(year), Ok(day_of("秋分の日")));
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Modules for `#[serde(with = "...")]` that (de)serialize `NaiveDate` fields
+//! as wareki strings:
+//!
+//! ```
+//! # use chrono::NaiveDate;
+//! # use serde_derive::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Contract {
+//!     #[serde(with = "nihonify_core::serde::wareki_date")]
+//!     signed_on: NaiveDate,
+//!     #[serde(with = "nihonify_core::serde::wareki_date_compact")]
+//!     expires_on: NaiveDate,
+//! }
+//! ```
+
+use crate::calendar::{Era, Jidai, WarekiDate};
+use crate::holidays::Holiday;
+use crate::parse::{parse_compact_wareki, parse_wareki_prefix};
+use alloc::format;
+use alloc::string::String;
+use chrono::prelude::*;
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::ser::{self, SerializeStruct, Serializer};
+
+/// As 令和3年11月12日, with ASCII digits and 元年 for the first year of an
+/// era. Also deserializes full-width digits and 1年.
+pub mod wareki_date {
+    use super::*;
+    use serde::Deserializer;
+
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let (era, year) = era_and_year(date)?;
+        let kanji = era
+            .kanji
+            .ok_or_else(|| ser::Error::custom("the era has no name"))?;
+        let year = match year {
+            1 => String::from("元"),
+            year => format!("{}", year),
+        };
+        serializer.collect_str(&format_args!(
+            "{}{}年{}月{}日",
+            kanji,
+            year,
+            date.month(),
+            date.day()
+        ))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        deserializer.deserialize_str(WarekiVisitor(|s| match parse_wareki_prefix(s) {
+            Some((date, len)) if len == s.len() => Some(date),
+            _ => None,
+        }))
+    }
+
+    #[cfg(feature = "schemars")]
+    pub fn schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A wareki date, e.g. 令和3年11月12日.",
+            "type": "string",
+            "pattern": "^[^0-9０-９元]+([0-9０-９]+|元)年[0-9０-９]{1,2}月[0-9０-９]{1,2}日$",
+        })
+    }
+}
+
+/// As R3.11.12, using the Latin initial of the era. Only the modern eras,
+/// Meiji through Reiwa, have one. Deserializes any form
+/// `parse_compact_wareki` takes.
+pub mod wareki_date_compact {
+    use super::*;
+    use serde::Deserializer;
+
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let (era, year) = era_and_year(date)?;
+        let initial = era
+            .initial()
+            .ok_or_else(|| ser::Error::custom("the era has no Latin initial"))?;
+        serializer.collect_str(&format_args!(
+            "{}{}.{}.{}",
+            initial,
+            year,
+            date.month(),
+            date.day()
+        ))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        deserializer.deserialize_str(WarekiVisitor(|s| parse_compact_wareki(s).ok()))
+    }
+
+    #[cfg(feature = "schemars")]
+    pub fn schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A compact wareki date, e.g. R3.11.12.",
+            "type": "string",
+            "pattern": "^[MTSHRmtshr][0-9]+\\.[0-9]{1,2}\\.[0-9]{1,2}$",
+        })
+    }
+}
+
+/// Serialized by its romaji name, e.g. "reiwa", or by its kanji name for the
+/// earlier of two eras with the same romaji name, e.g. "承和" (but "shouwa"
+/// for 昭和). Deserializes from either name. The unnamed interregna can't be
+/// serialized.
+impl ser::Serialize for Era {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = self
+            .identifier()
+            .ok_or_else(|| ser::Error::custom("the era has no name"))?;
+        serializer.serialize_str(name)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for &'static Era {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NameVisitor("an era name", |s| s.parse().ok()))
+    }
+}
+
+/// Serialized by its variant name, e.g. "Edo".
+impl ser::Serialize for Jidai {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(jidai_name(*self))
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Jidai {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NameVisitor("a jidai name", |s| {
+            Jidai::ALL
+                .iter()
+                .copied()
+                .find(|&jidai| jidai_name(jidai) == s)
+        }))
+    }
+}
+
+fn jidai_name(jidai: Jidai) -> &'static str {
+    match jidai {
+        Jidai::Asuka => "Asuka",
+        Jidai::Nara => "Nara",
+        Jidai::Heian => "Heian",
+        Jidai::Kamakura => "Kamakura",
+        Jidai::Nanbokuchou => "Nanbokuchou",
+        Jidai::Sengoku => "Sengoku",
+        Jidai::Muromachi => "Muromachi",
+        Jidai::AzuchiMomoyama => "AzuchiMomoyama",
+        Jidai::Edo => "Edo",
+        Jidai::Modern => "Modern",
+    }
+}
+
+struct NameVisitor<T>(&'static str, fn(&str) -> Option<T>);
+
+impl<'de, T> Visitor<'de> for NameVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        (self.1)(s).ok_or_else(|| E::invalid_value(de::Unexpected::Str(s), &self))
+    }
+}
+
+/// Serialized by its `Display` form, e.g. 令和3年11月12日, which keeps its
+/// era even where another era was current, e.g. a Southern Court era.
+/// Deserializes from any form its `FromStr` takes.
+impl ser::Serialize for WarekiDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.era().kanji.is_none() {
+            return Err(ser::Error::custom("the era has no name"));
+        }
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for WarekiDate {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NameVisitor("a wareki date string", |s| s.parse().ok()))
+    }
+}
+
+/// Serialized as an object with the ISO 8601 `date` and the `name`, e.g.
+/// `{"date":"2019-05-01","name":"天皇の即位の日"}`.
+impl ser::Serialize for Holiday {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut holiday = serializer.serialize_struct("Holiday", 2)?;
+        holiday.serialize_field("date", &format!("{}", self.date))?;
+        holiday.serialize_field("name", self.name)?;
+        holiday.end()
+    }
+}
+
+fn era_and_year<E: ser::Error>(date: &NaiveDate) -> Result<(&'static Era, u32), E> {
+    let datetime = date.and_time(NaiveTime::MIN).and_utc();
+    let era = Era::try_from_datetime(datetime).map_err(E::custom)?;
+    Ok((era, era.year_of(datetime)))
+}
+
+struct WarekiVisitor(fn(&str) -> Option<NaiveDate>);
+
+impl<'de> Visitor<'de> for WarekiVisitor {
+    type Value = NaiveDate;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a wareki date string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<NaiveDate, E> {
+        (self.0)(s).ok_or_else(|| E::invalid_value(de::Unexpected::Str(s), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use core::convert::TryFrom;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde::wareki_date")]
+        long: NaiveDate,
+        #[serde(with = "crate::serde::wareki_date_compact")]
+        compact: NaiveDate,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let record = Record {
+            long: NaiveDate::from_ymd_opt(2019, 5, 1).unwrap(),
+            compact: NaiveDate::from_ymd_opt(2021, 11, 12).unwrap(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"long":"令和元年5月1日","compact":"R3.11.12"}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn test_deserialize_variants() {
+        let record: Record =
+            serde_json::from_str(r#"{"long":"平成３１年４月３０日","compact":"h31.4.30"}"#)
+                .unwrap();
+        assert_eq!(record.long, NaiveDate::from_ymd_opt(2019, 4, 30).unwrap());
+        assert_eq!(record.compact, record.long);
+
+        assert!(
+            serde_json::from_str::<Record>(r#"{"long":"2019-04-30","compact":"H31.4.30"}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_era_and_jidai() {
+        use crate::calendar::{Era, Jidai};
+        use crate::eras::{SORTED_ERAS, SOUTHERN_COURT_ERAS};
+
+        let reiwa = crate::parse::utc_dt("2021-11-12");
+        let reiwa = Era::from_datetime(reiwa).unwrap();
+        assert_eq!(serde_json::to_string(reiwa).unwrap(), r#""reiwa""#);
+        assert_eq!(serde_json::from_str::<&Era>(r#""令和""#).unwrap(), reiwa);
+        assert_eq!(serde_json::to_string(&Jidai::Edo).unwrap(), r#""Edo""#);
+        assert_eq!(
+            serde_json::from_str::<Jidai>(r#""AzuchiMomoyama""#).unwrap(),
+            Jidai::AzuchiMomoyama
+        );
+        assert!(serde_json::from_str::<Jidai>(r#""edo""#).is_err());
+        assert!(serde_json::from_str::<&Era>(r#""令""#).is_err());
+
+        // Every named era round-trips, including those whose romaji names were
+        // used twice.
+        for era in SORTED_ERAS
+            .iter()
+            .chain(SOUTHERN_COURT_ERAS)
+            .filter(|era| era.kanji.is_some())
+        {
+            let json = serde_json::to_string(era).unwrap();
+            assert_eq!(
+                serde_json::from_str::<&Era>(&json).unwrap(),
+                era,
+                "{}",
+                json
+            );
+        }
+        let jowa = Era::from_kanji("承和").unwrap();
+        assert_eq!(serde_json::to_string(jowa).unwrap(), r#""承和""#);
+        assert!(serde_json::to_string(Era::from_index(2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_wareki_date() {
+        let date =
+            crate::WarekiDate::try_from(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()).unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""令和元年5月1日""#);
+        assert_eq!(
+            serde_json::from_str::<crate::WarekiDate>(&json).unwrap(),
+            date
+        );
+        assert!(serde_json::from_str::<crate::WarekiDate>(r#""令和元年4月30日""#).is_err());
+
+        // A Southern Court date keeps its era rather than becoming the
+        // Northern Court's 観応2年.
+        let date: crate::WarekiDate = "正平5年1月1日".parse().unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""正平5年1月1日""#);
+        assert_eq!(
+            serde_json::from_str::<crate::WarekiDate>(&json).unwrap(),
+            date
+        );
+    }
+
+    #[cfg(feature = "private-eras")]
+    #[test]
+    fn test_private_era_wareki_date() {
+        let date: crate::WarekiDate = "福徳2年1月1日".parse().unwrap();
+        assert!(date.era().is_private());
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""福徳2年1月1日""#);
+        assert_eq!(
+            serde_json::from_str::<crate::WarekiDate>(&json).unwrap(),
+            date
+        );
+    }
+
+    #[test]
+    fn test_holiday() {
+        let date = NaiveDate::from_ymd_opt(2019, 5, 1).unwrap();
+        let holiday = crate::holidays::Holiday {
+            date,
+            name: crate::holidays::holiday_name(date).unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&holiday).unwrap(),
+            r#"{"date":"2019-05-01","name":"天皇の即位の日"}"#
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_schema() {
+        let mut generator = schemars::SchemaGenerator::default();
+        let schema = super::wareki_date_compact::schema(&mut generator);
+        assert_eq!(schema.get("type").unwrap(), "string");
+    }
+}
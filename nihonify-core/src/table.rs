@@ -0,0 +1,121 @@
+use crate::calendar::Era;
+use crate::eras::SORTED_ERAS;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::ops::RangeInclusive;
+
+/// A wareki⇄seireki lookup table, one row per Gregorian year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionTable {
+    pub rows: Vec<ConversionRow>,
+}
+
+/// A Gregorian year and the era years it overlaps: two in a year the era
+/// changed, e.g. 昭和64年 and 平成元年 for 1989.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionRow {
+    pub year: i32,
+    pub eras: Vec<(&'static Era, u32)>,
+}
+
+impl ConversionRow {
+    /// The row's era years, e.g. 昭和64年・平成元年.
+    pub fn wareki(&self) -> String {
+        let years: Vec<String> = self
+            .eras
+            .iter()
+            .filter_map(|&(era, year)| {
+                let kanji = era.kanji?;
+                Some(match year {
+                    1 => format!("{}元年", kanji),
+                    year => format!("{}{}年", kanji, year),
+                })
+            })
+            .collect();
+        years.join("・")
+    }
+}
+
+impl ConversionTable {
+    /// The table for the given Gregorian years. Years before Taika have no
+    /// era years.
+    pub fn new(years: RangeInclusive<i32>) -> ConversionTable {
+        let rows = years
+            .map(|year| ConversionRow {
+                year,
+                eras: SORTED_ERAS
+                    .iter()
+                    .filter(|era| {
+                        let range = era.date_range();
+                        range.first.year() <= year && year <= range.last.year()
+                    })
+                    .map(|era| (era, (year - era.date_range().first.year() + 1) as u32))
+                    .collect(),
+            })
+            .collect();
+
+        ConversionTable { rows }
+    }
+
+    /// Renders the table as plain text, one year per line, e.g.
+    /// `1989年 昭和64年・平成元年`.
+    pub fn to_text(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| format!("{}年 {}\n", row.year, row.wareki()))
+            .collect()
+    }
+
+    /// Renders the table as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("| 西暦 | 和暦 |\n| --- | --- |\n");
+        for row in &self.rows {
+            out.push_str(&format!("| {} | {} |\n", row.year, row.wareki()));
+        }
+        out
+    }
+
+    /// Renders the table as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("西暦,和暦\n");
+        for row in &self.rows {
+            out.push_str(&format!("{},{}\n", row.year, row.wareki()));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_rows() {
+        let table = ConversionTable::new(1988..=1990);
+        let wareki: Vec<String> = table.rows.iter().map(ConversionRow::wareki).collect();
+        assert_eq!(wareki, vec!["昭和63年", "昭和64年・平成元年", "平成2年"]);
+        assert_eq!(table.rows[1].year, 1989);
+
+        assert_eq!(ConversionTable::new(600..=600).rows[0].eras, vec![]);
+    }
+
+    #[test]
+    fn test_formats() {
+        let table = ConversionTable::new(2019..=2020);
+        assert_eq!(
+            table.to_text(),
+            "2019年 平成31年・令和元年\n2020年 令和2年\n"
+        );
+        assert_eq!(
+            table.to_markdown(),
+            "| 西暦 | 和暦 |\n| --- | --- |\n| 2019 | 平成31年・令和元年 |\n| 2020 | 令和2年 |\n"
+        );
+        assert_eq!(
+            table.to_csv(),
+            "西暦,和暦\n2019,平成31年・令和元年\n2020,令和2年\n"
+        );
+    }
+}
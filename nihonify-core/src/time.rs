@@ -0,0 +1,98 @@
+//! Era lookup and wareki dates for the `time` crate's dates. An
+//! `OffsetDateTime` is a moment, like chrono's `DateTime`, so its wareki date
+//! is its date in Japan; a `Date` is a civil date, like `NaiveDate`.
+
+use crate::calendar::{Era, WarekiDate};
+use crate::error::NihonifyError;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use time::OffsetDateTime;
+
+impl TryFrom<OffsetDateTime> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        Era::try_from_unix_epoch(datetime.unix_timestamp())
+    }
+}
+
+impl TryFrom<time::Date> for &'static Era {
+    type Error = NihonifyError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        Era::try_from_datetime(to_chrono(date).and_time(NaiveTime::MIN).and_utc())
+    }
+}
+
+impl TryFrom<OffsetDateTime> for WarekiDate {
+    type Error = NihonifyError;
+
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        WarekiDate::from_unix_epoch(datetime.unix_timestamp())
+    }
+}
+
+impl TryFrom<time::Date> for WarekiDate {
+    type Error = NihonifyError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        WarekiDate::try_from(to_chrono(date))
+    }
+}
+
+/// The same civil date in chrono. time's years fit in chrono's.
+fn to_chrono(date: time::Date) -> NaiveDate {
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u8::from(date.month()).into(),
+        date.day().into(),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryInto;
+    use time::{Month, UtcOffset};
+
+    fn date(year: i32, month: Month, day: u8) -> time::Date {
+        time::Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date() {
+        let era: &Era = date(2021, Month::November, 12).try_into().unwrap();
+        assert_eq!(era.kanji, Some("令和"));
+        let wareki: WarekiDate = date(2019, Month::May, 1).try_into().unwrap();
+        assert_eq!((wareki.era().kanji, wareki.year()), (Some("令和"), 1));
+        assert_eq!(
+            <&Era>::try_from(date(600, Month::January, 1)),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+        assert_eq!(
+            WarekiDate::try_from(date(600, Month::January, 1)),
+            Err(NihonifyError::BeforeEraSystem {
+                earliest: Era::earliest_supported_date()
+            })
+        );
+    }
+
+    #[test]
+    fn test_offset_date_time() {
+        // 2019-04-30T15:00:00Z, midnight on the first day of Reiwa in Japan.
+        let datetime = OffsetDateTime::from_unix_timestamp(1_556_636_400).unwrap();
+        let era: &Era = datetime.try_into().unwrap();
+        assert_eq!(era.kanji, Some("令和"));
+        let wareki: WarekiDate = datetime.try_into().unwrap();
+        assert_eq!(wareki.to_ymd(), (2019, 5, 1));
+
+        // The offset doesn't change the moment.
+        let datetime = datetime.to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+        assert_eq!(WarekiDate::try_from(datetime), Ok(wareki));
+        let heisei = OffsetDateTime::from_unix_timestamp(1_556_636_399).unwrap();
+        assert_eq!(<&Era>::try_from(heisei).unwrap().kanji, Some("平成"));
+    }
+}
@@ -0,0 +1,82 @@
+//! A `tracing-subscriber` timer that stamps log lines with the wareki date
+//! and time in JST:
+//!
+//! ```
+//! tracing_subscriber::fmt()
+//!     .with_timer(nihonify_core::tracing::WarekiTime::new())
+//!     .init();
+//! ```
+
+use crate::calendar::{Clock, Era, SystemClock};
+use chrono::prelude::*;
+use core::fmt;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+
+/// Formats timestamps as 令和6年1月2日 15:04:05.123 JST, with ASCII digits
+/// and 元年 for the first year of an era. Times outside the era table are
+/// formatted as RFC 3339 instead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WarekiTime<C = SystemClock>(pub C);
+
+impl WarekiTime {
+    /// Stamps log lines with the system time.
+    pub fn new() -> WarekiTime {
+        WarekiTime(SystemClock)
+    }
+}
+
+impl<C: Clock> FormatTime for WarekiTime<C> {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        let now = self.0.now();
+        let jst = now.with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+
+        let era = Era::from_datetime(now).and_then(|era| Some((era.kanji?, era.year_of(now))));
+        match era {
+            Some((kanji, 1)) => write!(w, "{}元年", kanji)?,
+            Some((kanji, year)) => write!(w, "{}{}年", kanji, year)?,
+            None => return write!(w, "{}", jst.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        }
+        write!(
+            w,
+            "{}月{}日 {} JST",
+            jst.month(),
+            jst.day(),
+            jst.format("%H:%M:%S%.3f")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::FixedClock;
+    use alloc::string::String;
+
+    fn format(rfc3339: &str) -> String {
+        let now = DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut out = String::new();
+        WarekiTime(FixedClock(now))
+            .format_time(&mut Writer::new(&mut out))
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(
+            format("2021-11-12T06:04:05.123Z"),
+            "令和3年11月12日 15:04:05.123 JST"
+        );
+        assert_eq!(
+            format("2019-06-01T00:00:00Z"),
+            "令和元年6月1日 09:00:00.000 JST"
+        );
+        assert_eq!(
+            format("0600-01-01T00:00:00Z"),
+            "0600-01-01T09:00:00.000+09:00"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Week numbering the way Japanese calendars and retail reports count it
+//! (week 1 is the week holding the 1st, however few of its days fall in the
+//! month or year), and the nth weekday of a month.
+
+#[cfg(feature = "alloc")]
+use crate::numbers::to_jp_intstring;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use chrono::prelude::*;
+
+/// The day weeks start on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+    /// As on most Japanese calendars.
+    #[default]
+    Sunday,
+    Monday,
+}
+
+impl WeekStart {
+    /// How many days into the week the weekday is, from 0.
+    fn days_into_week(self, weekday: Weekday) -> u32 {
+        match self {
+            WeekStart::Sunday => weekday.num_days_from_sunday(),
+            WeekStart::Monday => weekday.num_days_from_monday(),
+        }
+    }
+}
+
+/// The week of the month the date falls in, from 1.
+pub fn week_of_month(date: NaiveDate, start: WeekStart) -> u32 {
+    week_of(date.day0(), date.with_day(1).unwrap(), start)
+}
+
+/// The week of the year the date falls in, from 1.
+pub fn week_of_year(date: NaiveDate, start: WeekStart) -> u32 {
+    week_of(date.ordinal0(), date.with_ordinal(1).unwrap(), start)
+}
+
+/// The week of the period starting on `first` that the day `day0` days into
+/// it falls in.
+fn week_of(day0: u32, first: NaiveDate, start: WeekStart) -> u32 {
+    (day0 + start.days_into_week(first.weekday())) / 7 + 1
+}
+
+/// The `n`th given weekday of the month, from 1, e.g. the 2nd Monday of
+/// January, or `None` if the month has fewer than `n` of them.
+pub fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+/// Which occurrence of its weekday in the month the date is, from 1, e.g. 2
+/// for the 2nd Monday.
+pub fn weekday_ordinal(date: NaiveDate) -> u32 {
+    date.day0() / 7 + 1
+}
+
+/// The kanji for the date's day of the week, e.g. 金 for a Friday, as in
+/// 金曜日.
+pub fn weekday_kanji(date: NaiveDate) -> char {
+    match date.weekday() {
+        Weekday::Mon => '月',
+        Weekday::Tue => '火',
+        Weekday::Wed => '水',
+        Weekday::Thu => '木',
+        Weekday::Fri => '金',
+        Weekday::Sat => '土',
+        Weekday::Sun => '日',
+    }
+}
+
+/// Labels the week of the month the date falls in, e.g. １１月第２週.
+#[cfg(feature = "alloc")]
+pub fn week_of_month_label(date: NaiveDate, start: WeekStart) -> String {
+    format!(
+        "{}月第{}週",
+        to_jp_intstring(date.month()),
+        to_jp_intstring(week_of_month(date, start))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        // November 2021 starts on a Monday.
+        assert_eq!(week_of_month(ymd(2021, 11, 1), WeekStart::Sunday), 1);
+        assert_eq!(week_of_month(ymd(2021, 11, 6), WeekStart::Sunday), 1);
+        assert_eq!(week_of_month(ymd(2021, 11, 7), WeekStart::Sunday), 2);
+        assert_eq!(week_of_month(ymd(2021, 11, 7), WeekStart::Monday), 1);
+        assert_eq!(week_of_month(ymd(2021, 11, 8), WeekStart::Monday), 2);
+        assert_eq!(week_of_month(ymd(2021, 11, 30), WeekStart::Sunday), 5);
+    }
+
+    #[test]
+    fn test_week_of_year() {
+        // 2022 starts on a Saturday.
+        assert_eq!(week_of_year(ymd(2022, 1, 1), WeekStart::Sunday), 1);
+        assert_eq!(week_of_year(ymd(2022, 1, 2), WeekStart::Sunday), 2);
+        assert_eq!(week_of_year(ymd(2022, 1, 2), WeekStart::Monday), 1);
+        assert_eq!(week_of_year(ymd(2022, 12, 31), WeekStart::Sunday), 53);
+    }
+
+    #[test]
+    fn test_nth_weekday() {
+        // Coming of Age Day is the 2nd Monday of January.
+        assert_eq!(
+            nth_weekday(2022, 1, Weekday::Mon, 2),
+            Some(ymd(2022, 1, 10))
+        );
+        assert_eq!(
+            nth_weekday(2021, 11, Weekday::Tue, 5),
+            Some(ymd(2021, 11, 30))
+        );
+        assert_eq!(nth_weekday(2021, 11, Weekday::Wed, 5), None);
+        assert_eq!(nth_weekday(2021, 11, Weekday::Wed, 0), None);
+    }
+
+    #[test]
+    fn test_weekday_ordinal() {
+        assert_eq!(weekday_ordinal(ymd(2022, 1, 10)), 2);
+        assert_eq!(weekday_ordinal(ymd(2022, 1, 7)), 1);
+        assert_eq!(weekday_ordinal(ymd(2021, 11, 30)), 5);
+    }
+
+    #[test]
+    fn test_weekday_kanji() {
+        assert_eq!(weekday_kanji(ymd(2021, 11, 12)), '金');
+        assert_eq!(weekday_kanji(ymd(2021, 11, 14)), '日');
+        assert_eq!(weekday_kanji(ymd(2021, 11, 15)), '月');
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_week_of_month_label() {
+        assert_eq!(
+            week_of_month_label(ymd(2021, 11, 12), WeekStart::default()),
+            "１１月第２週"
+        );
+    }
+}
@@ -1,1693 +0,0 @@
-use crate::{Era, Jidai};
-
-/// Pre-sorted array of all Japanese Eras.
-pub const SORTED_ERAS: &'static [Era] = &[
-    Era {
-        kanji: Some("大化"),
-        romaji: Some("taika"),
-        jidai: Jidai::Asuka,
-        started_at: -41795654400,
-        ended_at: Some(-41647996800),
-    },
-    Era {
-        kanji: Some("白雉"),
-        romaji: Some("hakuchi"),
-        jidai: Jidai::Asuka,
-        started_at: -41647996800,
-        ended_at: Some(-41500425600),
-    },
-    Era {
-        kanji: None,
-        romaji: None,
-        jidai: Jidai::Asuka,
-        started_at: -41500425600,
-        ended_at: Some(-40499395200),
-    },
-    Era {
-        kanji: Some("朱鳥"),
-        romaji: Some("shuchou"),
-        jidai: Jidai::Asuka,
-        started_at: -40499395200,
-        ended_at: Some(-40495248000),
-    },
-    Era {
-        kanji: None,
-        romaji: None,
-        jidai: Jidai::Asuka,
-        started_at: -40495248000,
-        ended_at: Some(-40034908800),
-    },
-    Era {
-        kanji: Some("大宝"),
-        romaji: Some("taihou"),
-        jidai: Jidai::Asuka,
-        started_at: -40034908800,
-        ended_at: Some(-39936412800),
-    },
-    Era {
-        kanji: Some("慶雲"),
-        romaji: Some("keiun"),
-        jidai: Jidai::Asuka,
-        started_at: -39936412800,
-        ended_at: Some(-39821414400),
-    },
-    Era {
-        kanji: Some("和銅"),
-        romaji: Some("wadou"),
-        jidai: Jidai::Asuka,
-        started_at: -39821414400,
-        ended_at: Some(-39579926400),
-    },
-    Era {
-        kanji: Some("霊亀"),
-        romaji: Some("reiki"),
-        jidai: Jidai::Nara,
-        started_at: -39579926400,
-        ended_at: Some(-39509683200),
-    },
-    Era {
-        kanji: Some("養老"),
-        romaji: Some("yourou"),
-        jidai: Jidai::Nara,
-        started_at: -39509683200,
-        ended_at: Some(-39314332800),
-    },
-    Era {
-        kanji: Some("神亀"),
-        romaji: Some("jinki"),
-        jidai: Jidai::Nara,
-        started_at: -39314332800,
-        ended_at: Some(-39140755200),
-    },
-    Era {
-        kanji: Some("天平"),
-        romaji: Some("tempyou"),
-        jidai: Jidai::Nara,
-        started_at: -39140755200,
-        ended_at: Some(-38520057600),
-    },
-    Era {
-        kanji: Some("天平感宝"),
-        romaji: Some("tempyoukampou"),
-        jidai: Jidai::Nara,
-        started_at: -38520057600,
-        ended_at: Some(-38510812800),
-    },
-    Era {
-        kanji: Some("天平勝宝"),
-        romaji: Some("tempyoushouhou"),
-        jidai: Jidai::Nara,
-        started_at: -38510812800,
-        ended_at: Some(-38256796800),
-    },
-    Era {
-        kanji: Some("天平宝字"),
-        romaji: Some("tempyouhouji"),
-        jidai: Jidai::Nara,
-        started_at: -38256796800,
-        ended_at: Some(-38023084800),
-    },
-    Era {
-        kanji: Some("天平神護"),
-        romaji: Some("tempyoujingo"),
-        jidai: Jidai::Nara,
-        started_at: -38023084800,
-        ended_at: Some(-37940659200),
-    },
-    Era {
-        kanji: Some("神護景雲"),
-        romaji: Some("jingokeiun"),
-        jidai: Jidai::Nara,
-        started_at: -37940659200,
-        ended_at: Some(-37842508800),
-    },
-    Era {
-        kanji: Some("宝亀"),
-        romaji: Some("houki"),
-        jidai: Jidai::Nara,
-        started_at: -37842508800,
-        ended_at: Some(-37518336000),
-    },
-    Era {
-        kanji: Some("天応"),
-        romaji: Some("tennou"),
-        jidai: Jidai::Nara,
-        started_at: -37518336000,
-        ended_at: Some(-37465804800),
-    },
-    Era {
-        kanji: Some("延暦"),
-        romaji: Some("enryaku"),
-        jidai: Jidai::Nara,
-        started_at: -37465804800,
-        ended_at: Some(-36718272000),
-    },
-    Era {
-        kanji: Some("大同"),
-        romaji: Some("daidou"),
-        jidai: Jidai::Heian,
-        started_at: -36718272000,
-        ended_at: Some(-36580464000),
-    },
-    Era {
-        kanji: Some("弘仁"),
-        romaji: Some("kounin"),
-        jidai: Jidai::Heian,
-        started_at: -36580464000,
-        ended_at: Some(-36160646400),
-    },
-    Era {
-        kanji: Some("天長"),
-        romaji: Some("tenchou"),
-        jidai: Jidai::Heian,
-        started_at: -36160646400,
-        ended_at: Some(-35844508800),
-    },
-    Era {
-        kanji: Some("承和"),
-        romaji: Some("jouwa"),
-        jidai: Jidai::Heian,
-        started_at: -35844508800,
-        ended_at: Some(-35389526400),
-    },
-    Era {
-        kanji: Some("嘉祥"),
-        romaji: Some("kashou"),
-        jidai: Jidai::Heian,
-        started_at: -35389526400,
-        ended_at: Some(-35298806400),
-    },
-    Era {
-        kanji: Some("仁寿"),
-        romaji: Some("ninju"),
-        jidai: Jidai::Heian,
-        started_at: -35298806400,
-        ended_at: Some(-35186400000),
-    },
-    Era {
-        kanji: Some("斉衡"),
-        romaji: Some("saikou"),
-        jidai: Jidai::Heian,
-        started_at: -35186400000,
-        ended_at: Some(-35115724800),
-    },
-    Era {
-        kanji: Some("天安"),
-        romaji: Some("tennan"),
-        jidai: Jidai::Heian,
-        started_at: -35115724800,
-        ended_at: Some(-35047382400),
-    },
-    Era {
-        kanji: Some("貞観"),
-        romaji: Some("jougan"),
-        jidai: Jidai::Heian,
-        started_at: -35047382400,
-        ended_at: Some(-34478265600),
-    },
-    Era {
-        kanji: Some("元慶"),
-        romaji: Some("gangyou"),
-        jidai: Jidai::Heian,
-        started_at: -34478265600,
-        ended_at: Some(-34232889600),
-    },
-    Era {
-        kanji: Some("仁和"),
-        romaji: Some("ninna"),
-        jidai: Jidai::Heian,
-        started_at: -34232889600,
-        ended_at: Some(-34099747200),
-    },
-    Era {
-        kanji: Some("寛平"),
-        romaji: Some("kampyou"),
-        jidai: Jidai::Heian,
-        started_at: -34099747200,
-        ended_at: Some(-33816614400),
-    },
-    Era {
-        kanji: Some("昌泰"),
-        romaji: Some("shoutai"),
-        jidai: Jidai::Heian,
-        started_at: -33816614400,
-        ended_at: Some(-33713020800),
-    },
-    Era {
-        kanji: Some("延喜"),
-        romaji: Some("engi"),
-        jidai: Jidai::Heian,
-        started_at: -33713020800,
-        ended_at: Some(-33026918400),
-    },
-    Era {
-        kanji: Some("延長"),
-        romaji: Some("enchou"),
-        jidai: Jidai::Heian,
-        started_at: -33026918400,
-        ended_at: Some(-32775580800),
-    },
-    Era {
-        kanji: Some("承平"),
-        romaji: Some("jouhei"),
-        jidai: Jidai::Heian,
-        started_at: -32775580800,
-        ended_at: Some(-32551459200),
-    },
-    Era {
-        kanji: Some("天慶"),
-        romaji: Some("tengyou"),
-        jidai: Jidai::Heian,
-        started_at: -32551459200,
-        ended_at: Some(-32270745600),
-    },
-    Era {
-        kanji: Some("天暦"),
-        romaji: Some("tenryaku"),
-        jidai: Jidai::Heian,
-        started_at: -32270745600,
-        ended_at: Some(-31938710400),
-    },
-    Era {
-        kanji: Some("天徳"),
-        romaji: Some("tentoku"),
-        jidai: Jidai::Heian,
-        started_at: -31938710400,
-        ended_at: Some(-31835030400),
-    },
-    Era {
-        kanji: Some("応和"),
-        romaji: Some("ouwa"),
-        jidai: Jidai::Heian,
-        started_at: -31835030400,
-        ended_at: Some(-31725907200),
-    },
-    Era {
-        kanji: Some("康保"),
-        romaji: Some("kouhou"),
-        jidai: Jidai::Heian,
-        started_at: -31725907200,
-        ended_at: Some(-31597948800),
-    },
-    Era {
-        kanji: Some("安和"),
-        romaji: Some("anna"),
-        jidai: Jidai::Heian,
-        started_at: -31597948800,
-        ended_at: Some(-31545936000),
-    },
-    Era {
-        kanji: Some("天禄"),
-        romaji: Some("tenroku"),
-        jidai: Jidai::Heian,
-        started_at: -31545936000,
-        ended_at: Some(-31428950400),
-    },
-    Era {
-        kanji: Some("天延"),
-        romaji: Some("tenen"),
-        jidai: Jidai::Heian,
-        started_at: -31428950400,
-        ended_at: Some(-31347907200),
-    },
-    Era {
-        kanji: Some("貞元"),
-        romaji: Some("jougen"),
-        jidai: Jidai::Heian,
-        started_at: -31347907200,
-        ended_at: Some(-31272566400),
-    },
-    Era {
-        kanji: Some("天元"),
-        romaji: Some("tengen"),
-        jidai: Jidai::Heian,
-        started_at: -31272566400,
-        ended_at: Some(-31133462400),
-    },
-    Era {
-        kanji: Some("永観"),
-        romaji: Some("eikan"),
-        jidai: Jidai::Heian,
-        started_at: -31133462400,
-        ended_at: Some(-31071168000),
-    },
-    Era {
-        kanji: Some("寛和"),
-        romaji: Some("kanna"),
-        jidai: Jidai::Heian,
-        started_at: -31071168000,
-        ended_at: Some(-31009305600),
-    },
-    Era {
-        kanji: Some("永延"),
-        romaji: Some("eien"),
-        jidai: Jidai::Heian,
-        started_at: -31009305600,
-        ended_at: Some(-30935088000),
-    },
-    Era {
-        kanji: Some("永祚"),
-        romaji: Some("eiso"),
-        jidai: Jidai::Heian,
-        started_at: -30935088000,
-        ended_at: Some(-30896899200),
-    },
-    Era {
-        kanji: Some("正暦"),
-        romaji: Some("shouryaku"),
-        jidai: Jidai::Heian,
-        started_at: -30896899200,
-        ended_at: Some(-30760387200),
-    },
-    Era {
-        kanji: Some("長徳"),
-        romaji: Some("choutoku"),
-        jidai: Jidai::Heian,
-        started_at: -30760387200,
-        ended_at: Some(-30638649600),
-    },
-    Era {
-        kanji: Some("長保"),
-        romaji: Some("chouhou"),
-        jidai: Jidai::Heian,
-        started_at: -30638649600,
-        ended_at: Some(-30464553600),
-    },
-    Era {
-        kanji: Some("寛弘"),
-        romaji: Some("kankou"),
-        jidai: Jidai::Heian,
-        started_at: -30464553600,
-        ended_at: Some(-30196195200),
-    },
-    Era {
-        kanji: Some("長和"),
-        romaji: Some("chouwa"),
-        jidai: Jidai::Heian,
-        started_at: -30196195200,
-        ended_at: Some(-30061152000),
-    },
-    Era {
-        kanji: Some("寛仁"),
-        romaji: Some("kannin"),
-        jidai: Jidai::Heian,
-        started_at: -30061152000,
-        ended_at: Some(-29940537600),
-    },
-    Era {
-        kanji: Some("治安"),
-        romaji: Some("jian"),
-        jidai: Jidai::Heian,
-        started_at: -29940537600,
-        ended_at: Some(-29832451200),
-    },
-    Era {
-        kanji: Some("万寿"),
-        romaji: Some("manju"),
-        jidai: Jidai::Heian,
-        started_at: -29832451200,
-        ended_at: Some(-29706307200),
-    },
-    Era {
-        kanji: Some("長元"),
-        romaji: Some("chougen"),
-        jidai: Jidai::Heian,
-        started_at: -29706307200,
-        ended_at: Some(-29431036800),
-    },
-    Era {
-        kanji: Some("長暦"),
-        romaji: Some("chouryaku"),
-        jidai: Jidai::Heian,
-        started_at: -29431036800,
-        ended_at: Some(-29317248000),
-    },
-    Era {
-        kanji: Some("長久"),
-        romaji: Some("choukyuu"),
-        jidai: Jidai::Heian,
-        started_at: -29317248000,
-        ended_at: Some(-29191017600),
-    },
-    Era {
-        kanji: Some("寛徳"),
-        romaji: Some("kantoku"),
-        jidai: Jidai::Heian,
-        started_at: -29191017600,
-        ended_at: Some(-29145916800),
-    },
-    Era {
-        kanji: Some("永承"),
-        romaji: Some("eishou"),
-        jidai: Jidai::Heian,
-        started_at: -29145916800,
-        ended_at: Some(-28934409600),
-    },
-    Era {
-        kanji: Some("天喜"),
-        romaji: Some("tenki"),
-        jidai: Jidai::Heian,
-        started_at: -28934409600,
-        ended_at: Some(-28756857600),
-    },
-    Era {
-        kanji: Some("康平"),
-        romaji: Some("kouhei"),
-        jidai: Jidai::Heian,
-        started_at: -28756857600,
-        ended_at: Some(-28537228800),
-    },
-    Era {
-        kanji: Some("治暦"),
-        romaji: Some("jiryaku"),
-        jidai: Jidai::Heian,
-        started_at: -28537228800,
-        ended_at: Some(-28421452800),
-    },
-    Era {
-        kanji: Some("延久"),
-        romaji: Some("enkyuu"),
-        jidai: Jidai::Heian,
-        started_at: -28421452800,
-        ended_at: Some(-28252195200),
-    },
-    Era {
-        kanji: Some("承保"),
-        romaji: Some("jouhou"),
-        jidai: Jidai::Heian,
-        started_at: -28252195200,
-        ended_at: Some(-28150588800),
-    },
-    Era {
-        kanji: Some("承暦"),
-        romaji: Some("jouryaku"),
-        jidai: Jidai::Heian,
-        started_at: -28150588800,
-        ended_at: Some(-28046649600),
-    },
-    Era {
-        kanji: Some("永保"),
-        romaji: Some("eihou"),
-        jidai: Jidai::Heian,
-        started_at: -28046649600,
-        ended_at: Some(-27952560000),
-    },
-    Era {
-        kanji: Some("応徳"),
-        romaji: Some("outoku"),
-        jidai: Jidai::Heian,
-        started_at: -27952560000,
-        ended_at: Some(-27853027200),
-    },
-    Era {
-        kanji: Some("寛治"),
-        romaji: Some("kanji"),
-        jidai: Jidai::Heian,
-        started_at: -27853027200,
-        ended_at: Some(-27609897600),
-    },
-    Era {
-        kanji: Some("嘉保"),
-        romaji: Some("kahou"),
-        jidai: Jidai::Heian,
-        started_at: -27609897600,
-        ended_at: Some(-27548467200),
-    },
-    Era {
-        kanji: Some("永長"),
-        romaji: Some("eichou"),
-        jidai: Jidai::Heian,
-        started_at: -27548467200,
-        ended_at: Some(-27517536000),
-    },
-    Era {
-        kanji: Some("承徳"),
-        romaji: Some("joutoku"),
-        jidai: Jidai::Heian,
-        started_at: -27517536000,
-        ended_at: Some(-27463363200),
-    },
-    Era {
-        kanji: Some("康和"),
-        romaji: Some("kouwa"),
-        jidai: Jidai::Heian,
-        started_at: -27463363200,
-        ended_at: Some(-27322012800),
-    },
-    Era {
-        kanji: Some("長治"),
-        romaji: Some("chouji"),
-        jidai: Jidai::Heian,
-        started_at: -27322012800,
-        ended_at: Some(-27253238400),
-    },
-    Era {
-        kanji: Some("嘉承"),
-        romaji: Some("kashou"),
-        jidai: Jidai::Heian,
-        started_at: -27253238400,
-        ended_at: Some(-27179798400),
-    },
-    Era {
-        kanji: Some("天仁"),
-        romaji: Some("tennin"),
-        jidai: Jidai::Heian,
-        started_at: -27179798400,
-        ended_at: Some(-27120182400),
-    },
-    Era {
-        kanji: Some("天永"),
-        romaji: Some("tennei"),
-        jidai: Jidai::Heian,
-        started_at: -27120182400,
-        ended_at: Some(-27023328000),
-    },
-    Era {
-        kanji: Some("永久"),
-        romaji: Some("eikyuu"),
-        jidai: Jidai::Heian,
-        started_at: -27023328000,
-        ended_at: Some(-26876102400),
-    },
-    Era {
-        kanji: Some("元永"),
-        romaji: Some("gennei"),
-        jidai: Jidai::Heian,
-        started_at: -26876102400,
-        ended_at: Some(-26811734400),
-    },
-    Era {
-        kanji: Some("保安"),
-        romaji: Some("houan"),
-        jidai: Jidai::Heian,
-        started_at: -26811734400,
-        ended_at: Some(-26684726400),
-    },
-    Era {
-        kanji: Some("天治"),
-        romaji: Some("tenji"),
-        jidai: Jidai::Heian,
-        started_at: -26684726400,
-        ended_at: Some(-26629603200),
-    },
-    Era {
-        kanji: Some("大治"),
-        romaji: Some("daiji"),
-        jidai: Jidai::Heian,
-        started_at: -26629603200,
-        ended_at: Some(-26470713600),
-    },
-    Era {
-        kanji: Some("天承"),
-        romaji: Some("tenshou"),
-        jidai: Jidai::Heian,
-        started_at: -26470713600,
-        ended_at: Some(-26421379200),
-    },
-    Era {
-        kanji: Some("長承"),
-        romaji: Some("choushou"),
-        jidai: Jidai::Heian,
-        started_at: -26421379200,
-        ended_at: Some(-26335670400),
-    },
-    Era {
-        kanji: Some("保延"),
-        romaji: Some("houen"),
-        jidai: Jidai::Heian,
-        started_at: -26335670400,
-        ended_at: Some(-26140752000),
-    },
-    Era {
-        kanji: Some("永治"),
-        romaji: Some("eiji"),
-        jidai: Jidai::Heian,
-        started_at: -26140752000,
-        ended_at: Some(-26116128000),
-    },
-    Era {
-        kanji: Some("康治"),
-        romaji: Some("kouji"),
-        jidai: Jidai::Heian,
-        started_at: -26116128000,
-        ended_at: Some(-26057980800),
-    },
-    Era {
-        kanji: Some("天養"),
-        romaji: Some("tennyou"),
-        jidai: Jidai::Heian,
-        started_at: -26057980800,
-        ended_at: Some(-26014608000),
-    },
-    Era {
-        kanji: Some("久安"),
-        romaji: Some("kyuuan"),
-        jidai: Jidai::Heian,
-        started_at: -26014608000,
-        ended_at: Some(-25840771200),
-    },
-    Era {
-        kanji: Some("仁平"),
-        romaji: Some("ninmpei"),
-        jidai: Jidai::Heian,
-        started_at: -25840771200,
-        ended_at: Some(-25720761600),
-    },
-    Era {
-        kanji: Some("久寿"),
-        romaji: Some("kyuuju"),
-        jidai: Jidai::Heian,
-        started_at: -25720761600,
-        ended_at: Some(-25674883200),
-    },
-    Era {
-        kanji: Some("保元"),
-        romaji: Some("hougen"),
-        jidai: Jidai::Heian,
-        started_at: -25674883200,
-        ended_at: Some(-25581052800),
-    },
-    Era {
-        kanji: Some("平治"),
-        romaji: Some("heiji"),
-        jidai: Jidai::Heian,
-        started_at: -25581052800,
-        ended_at: Some(-25556428800),
-    },
-    Era {
-        kanji: Some("永暦"),
-        romaji: Some("eiryaku"),
-        jidai: Jidai::Heian,
-        started_at: -25556428800,
-        ended_at: Some(-25505971200),
-    },
-    Era {
-        kanji: Some("応保"),
-        romaji: Some("ouhou"),
-        jidai: Jidai::Heian,
-        started_at: -25505971200,
-        ended_at: Some(-25455254400),
-    },
-    Era {
-        kanji: Some("長寛"),
-        romaji: Some("choukan"),
-        jidai: Jidai::Heian,
-        started_at: -25455254400,
-        ended_at: Some(-25385961600),
-    },
-    Era {
-        kanji: Some("永万"),
-        romaji: Some("eiman"),
-        jidai: Jidai::Heian,
-        started_at: -25385961600,
-        ended_at: Some(-25348291200),
-    },
-    Era {
-        kanji: Some("仁安"),
-        romaji: Some("ninnan"),
-        jidai: Jidai::Heian,
-        started_at: -25348291200,
-        ended_at: Some(-25265692800),
-    },
-    Era {
-        kanji: Some("嘉応"),
-        romaji: Some("kaou"),
-        jidai: Jidai::Heian,
-        started_at: -25265692800,
-        ended_at: Some(-25200806400),
-    },
-    Era {
-        kanji: Some("承安"),
-        romaji: Some("shouan"),
-        jidai: Jidai::Heian,
-        started_at: -25200806400,
-        ended_at: Some(-25067577600),
-    },
-    Era {
-        kanji: Some("安元"),
-        romaji: Some("angen"),
-        jidai: Jidai::Heian,
-        started_at: -25067577600,
-        ended_at: Some(-25003296000),
-    },
-    Era {
-        kanji: Some("治承"),
-        romaji: Some("jishou"),
-        jidai: Jidai::Heian,
-        started_at: -25003296000,
-        ended_at: Some(-24877411200),
-    },
-    Era {
-        kanji: Some("養和"),
-        romaji: Some("youwa"),
-        jidai: Jidai::Heian,
-        started_at: -24877411200,
-        ended_at: Some(-24850800000),
-    },
-    Era {
-        kanji: Some("寿永"),
-        romaji: Some("juei"),
-        jidai: Jidai::Heian,
-        started_at: -24850800000,
-        ended_at: Some(-24790492800),
-    },
-    Era {
-        kanji: Some("元暦"),
-        romaji: Some("genryaku"),
-        jidai: Jidai::Heian,
-        started_at: -24790492800,
-        ended_at: Some(-24749884800),
-    },
-    Era {
-        kanji: Some("文治"),
-        romaji: Some("bunji"),
-        jidai: Jidai::Kamakura,
-        started_at: -24749884800,
-        ended_at: Some(-24602140800),
-    },
-    Era {
-        kanji: Some("建久"),
-        romaji: Some("kenkyuu"),
-        jidai: Jidai::Kamakura,
-        started_at: -24602140800,
-        ended_at: Some(-24317539200),
-    },
-    Era {
-        kanji: Some("正治"),
-        romaji: Some("shouji"),
-        jidai: Jidai::Kamakura,
-        started_at: -24317539200,
-        ended_at: Some(-24259996800),
-    },
-    Era {
-        kanji: Some("建仁"),
-        romaji: Some("kennin"),
-        jidai: Jidai::Kamakura,
-        started_at: -24259996800,
-        ended_at: Some(-24164956800),
-    },
-    Era {
-        kanji: Some("元久"),
-        romaji: Some("genkyuu"),
-        jidai: Jidai::Kamakura,
-        started_at: -24164956800,
-        ended_at: Some(-24095491200),
-    },
-    Era {
-        kanji: Some("建永"),
-        romaji: Some("kennei"),
-        jidai: Jidai::Kamakura,
-        started_at: -24095491200,
-        ended_at: Some(-24049785600),
-    },
-    Era {
-        kanji: Some("承元"),
-        romaji: Some("jougen"),
-        jidai: Jidai::Kamakura,
-        started_at: -24049785600,
-        ended_at: Some(-23941440000),
-    },
-    Era {
-        kanji: Some("建暦"),
-        romaji: Some("kenryaku"),
-        jidai: Jidai::Kamakura,
-        started_at: -23941440000,
-        ended_at: Some(-23854953600),
-    },
-    Era {
-        kanji: Some("建保"),
-        romaji: Some("kempou"),
-        jidai: Jidai::Kamakura,
-        started_at: -23854953600,
-        ended_at: Some(-23686041600),
-    },
-    Era {
-        kanji: Some("承久"),
-        romaji: Some("joukyuu"),
-        jidai: Jidai::Kamakura,
-        started_at: -23686041600,
-        ended_at: Some(-23591520000),
-    },
-    Era {
-        kanji: Some("貞応"),
-        romaji: Some("jouou"),
-        jidai: Jidai::Kamakura,
-        started_at: -23591520000,
-        ended_at: Some(-23509353600),
-    },
-    Era {
-        kanji: Some("元仁"),
-        romaji: Some("gennin"),
-        jidai: Jidai::Kamakura,
-        started_at: -23509353600,
-        ended_at: Some(-23496566400),
-    },
-    Era {
-        kanji: Some("嘉禄"),
-        romaji: Some("karoku"),
-        jidai: Jidai::Kamakura,
-        started_at: -23496566400,
-        ended_at: Some(-23413190400),
-    },
-    Era {
-        kanji: Some("安貞"),
-        romaji: Some("antei"),
-        jidai: Jidai::Kamakura,
-        started_at: -23413190400,
-        ended_at: Some(-23375347200),
-    },
-    Era {
-        kanji: Some("寛喜"),
-        romaji: Some("kanki"),
-        jidai: Jidai::Kamakura,
-        started_at: -23375347200,
-        ended_at: Some(-23278665600),
-    },
-    Era {
-        kanji: Some("貞永"),
-        romaji: Some("jouei"),
-        jidai: Jidai::Kamakura,
-        started_at: -23278665600,
-        ended_at: Some(-23244364800),
-    },
-    Era {
-        kanji: Some("天福"),
-        romaji: Some("tempuku"),
-        jidai: Jidai::Kamakura,
-        started_at: -23244364800,
-        ended_at: Some(-23196758400),
-    },
-    Era {
-        kanji: Some("文暦"),
-        romaji: Some("bunryaku"),
-        jidai: Jidai::Kamakura,
-        started_at: -23196758400,
-        ended_at: Some(-23167468800),
-    },
-    Era {
-        kanji: Some("嘉禎"),
-        romaji: Some("katei"),
-        jidai: Jidai::Kamakura,
-        started_at: -23167468800,
-        ended_at: Some(-23067676800),
-    },
-    Era {
-        kanji: Some("暦仁"),
-        romaji: Some("ryakunin"),
-        jidai: Jidai::Kamakura,
-        started_at: -23067676800,
-        ended_at: Some(-23061369600),
-    },
-    Era {
-        kanji: Some("延応"),
-        romaji: Some("ennou"),
-        jidai: Jidai::Kamakura,
-        started_at: -23061369600,
-        ended_at: Some(-23017219200),
-    },
-    Era {
-        kanji: Some("仁治"),
-        romaji: Some("ninji"),
-        jidai: Jidai::Kamakura,
-        started_at: -23017219200,
-        ended_at: Some(-22934707200),
-    },
-    Era {
-        kanji: Some("寛元"),
-        romaji: Some("kangen"),
-        jidai: Jidai::Kamakura,
-        started_at: -22934707200,
-        ended_at: Some(-22806921600),
-    },
-    Era {
-        kanji: Some("宝治"),
-        romaji: Some("houji"),
-        jidai: Jidai::Kamakura,
-        started_at: -22806921600,
-        ended_at: Some(-22741430400),
-    },
-    Era {
-        kanji: Some("建長"),
-        romaji: Some("kenchou"),
-        jidai: Jidai::Kamakura,
-        started_at: -22741430400,
-        ended_at: Some(-22505385600),
-    },
-    Era {
-        kanji: Some("康元"),
-        romaji: Some("kougen"),
-        jidai: Jidai::Kamakura,
-        started_at: -22505385600,
-        ended_at: Some(-22491734400),
-    },
-    Era {
-        kanji: Some("正嘉"),
-        romaji: Some("shouka"),
-        jidai: Jidai::Kamakura,
-        started_at: -22491734400,
-        ended_at: Some(-22426934400),
-    },
-    Era {
-        kanji: Some("正元"),
-        romaji: Some("shougen"),
-        jidai: Jidai::Kamakura,
-        started_at: -22426934400,
-        ended_at: Some(-22392374400),
-    },
-    Era {
-        kanji: Some("文応"),
-        romaji: Some("bunnou"),
-        jidai: Jidai::Kamakura,
-        started_at: -22392374400,
-        ended_at: Some(-22366281600),
-    },
-    Era {
-        kanji: Some("弘長"),
-        romaji: Some("kouchou"),
-        jidai: Jidai::Kamakura,
-        started_at: -22366281600,
-        ended_at: Some(-22271155200),
-    },
-    Era {
-        kanji: Some("文永"),
-        romaji: Some("bunnei"),
-        jidai: Jidai::Kamakura,
-        started_at: -22271155200,
-        ended_at: Some(-21919248000),
-    },
-    Era {
-        kanji: Some("建治"),
-        romaji: Some("kenji"),
-        jidai: Jidai::Kamakura,
-        started_at: -21919248000,
-        ended_at: Some(-21829737600),
-    },
-    Era {
-        kanji: Some("弘安"),
-        romaji: Some("kouan"),
-        jidai: Jidai::Kamakura,
-        started_at: -21829737600,
-        ended_at: Some(-21508329600),
-    },
-    Era {
-        kanji: Some("正応"),
-        romaji: Some("shouou"),
-        jidai: Jidai::Kamakura,
-        started_at: -21508329600,
-        ended_at: Some(-21341923200),
-    },
-    Era {
-        kanji: Some("永仁"),
-        romaji: Some("einin"),
-        jidai: Jidai::Kamakura,
-        started_at: -21341923200,
-        ended_at: Some(-21161606400),
-    },
-    Era {
-        kanji: Some("正安"),
-        romaji: Some("shouan"),
-        jidai: Jidai::Kamakura,
-        started_at: -21161606400,
-        ended_at: Some(-21049718400),
-    },
-    Era {
-        kanji: Some("乾元"),
-        romaji: Some("kengen"),
-        jidai: Jidai::Kamakura,
-        started_at: -21049718400,
-        ended_at: Some(-21025526400),
-    },
-    Era {
-        kanji: Some("嘉元"),
-        romaji: Some("kagen"),
-        jidai: Jidai::Kamakura,
-        started_at: -21025526400,
-        ended_at: Some(-20920118400),
-    },
-    Era {
-        kanji: Some("徳治"),
-        romaji: Some("tokuji"),
-        jidai: Jidai::Kamakura,
-        started_at: -20920118400,
-        ended_at: Some(-20861884800),
-    },
-    Era {
-        kanji: Some("延慶"),
-        romaji: Some("enkyou"),
-        jidai: Jidai::Kamakura,
-        started_at: -20861884800,
-        ended_at: Some(-20783606400),
-    },
-    Era {
-        kanji: Some("応長"),
-        romaji: Some("ouchou"),
-        jidai: Jidai::Kamakura,
-        started_at: -20783606400,
-        ended_at: Some(-20753712000),
-    },
-    Era {
-        kanji: Some("正和"),
-        romaji: Some("shouwa"),
-        jidai: Jidai::Kamakura,
-        started_at: -20753712000,
-        ended_at: Some(-20599574400),
-    },
-    Era {
-        kanji: Some("文保"),
-        romaji: Some("bumpou"),
-        jidai: Jidai::Kamakura,
-        started_at: -20599574400,
-        ended_at: Some(-20531059200),
-    },
-    Era {
-        kanji: Some("元応"),
-        romaji: Some("gennou"),
-        jidai: Jidai::Kamakura,
-        started_at: -20531059200,
-        ended_at: Some(-20472825600),
-    },
-    Era {
-        kanji: Some("元亨"),
-        romaji: Some("gennkou"),
-        jidai: Jidai::Kamakura,
-        started_at: -20472825600,
-        ended_at: Some(-20354112000),
-    },
-    Era {
-        kanji: Some("正中"),
-        romaji: Some("shouchuu"),
-        jidai: Jidai::Kamakura,
-        started_at: -20354112000,
-        ended_at: Some(-20309270400),
-    },
-    Era {
-        kanji: Some("嘉暦"),
-        romaji: Some("karyaku"),
-        jidai: Jidai::Kamakura,
-        started_at: -20309270400,
-        ended_at: Some(-20204467200),
-    },
-    Era {
-        kanji: Some("元徳"),
-        romaji: Some("gentoku"),
-        jidai: Jidai::Kamakura,
-        started_at: -20204467200,
-        ended_at: Some(-20120313600),
-    },
-    Era {
-        kanji: Some("正慶"),
-        romaji: Some("shoukyou"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -20120313600,
-        ended_at: Some(-20084889600),
-    },
-    // Small gap here
-    Era {
-        kanji: Some("建武"),
-        romaji: Some("kenmu"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -20064067200,
-        ended_at: Some(-19918828800),
-    },
-    Era {
-        kanji: Some("暦応"),
-        romaji: Some("ryakuou"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19918828800,
-        ended_at: Some(-19804003200),
-    },
-    Era {
-        kanji: Some("康永"),
-        romaji: Some("kouei"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19804003200,
-        ended_at: Some(-19694880000),
-    },
-    Era {
-        kanji: Some("貞和"),
-        romaji: Some("jouwa"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19694880000,
-        ended_at: Some(-19556553600),
-    },
-    Era {
-        kanji: Some("観応"),
-        romaji: Some("kannou"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19556553600,
-        ended_at: Some(-19474905600),
-    },
-    Era {
-        kanji: Some("文和"),
-        romaji: Some("bunna"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19474905600,
-        ended_at: Some(-19365004800),
-    },
-    Era {
-        kanji: Some("延文"),
-        romaji: Some("enbun"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19365004800,
-        ended_at: Some(-19206806400),
-    },
-    Era {
-        kanji: Some("康安"),
-        romaji: Some("kouan"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19206806400,
-        ended_at: Some(-19161446400),
-    },
-    Era {
-        kanji: Some("貞治"),
-        romaji: Some("jouji"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -19161446400,
-        ended_at: Some(-18990892800),
-    },
-    Era {
-        kanji: Some("応安"),
-        romaji: Some("ouan"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18990892800,
-        ended_at: Some(-18768153600),
-    },
-    Era {
-        kanji: Some("永和"),
-        romaji: Some("eiwa"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18768153600,
-        ended_at: Some(-18640972800),
-    },
-    Era {
-        kanji: Some("康暦"),
-        romaji: Some("kouryaku"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18640972800,
-        ended_at: Some(-18579542400),
-    },
-    Era {
-        kanji: Some("永徳"),
-        romaji: Some("eitoku"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18579542400,
-        ended_at: Some(-18484934400),
-    },
-    Era {
-        kanji: Some("至徳"),
-        romaji: Some("shitoku"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18484934400,
-        ended_at: Some(-18373046400),
-    },
-    Era {
-        kanji: Some("嘉慶"),
-        romaji: Some("kakyou"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18373046400,
-        ended_at: Some(-18328896000),
-    },
-    Era {
-        kanji: Some("康応"),
-        romaji: Some("kouou"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18328896000,
-        ended_at: Some(-18294249600),
-    },
-    Era {
-        kanji: Some("明徳"),
-        romaji: Some("meitoku"),
-        jidai: Jidai::Nanbokuchou,
-        started_at: -18294249600,
-        ended_at: Some(-18157651200),
-    },
-    Era {
-        kanji: Some("応永"),
-        romaji: Some("ouei"),
-        jidai: Jidai::Muromachi,
-        started_at: -18157651200,
-        ended_at: Some(-17089228800),
-    },
-    Era {
-        kanji: Some("正長"),
-        romaji: Some("shouchou"),
-        jidai: Jidai::Muromachi,
-        started_at: -17089228800,
-        ended_at: Some(-17047756800),
-    },
-    Era {
-        kanji: Some("永享"),
-        romaji: Some("eikyou"),
-        jidai: Jidai::Muromachi,
-        started_at: -17047756800,
-        ended_at: Some(-16686950400),
-    },
-    Era {
-        kanji: Some("嘉吉"),
-        romaji: Some("kakitsu"),
-        jidai: Jidai::Muromachi,
-        started_at: -16686950400,
-        ended_at: Some(-16593638400),
-    },
-    Era {
-        kanji: Some("文安"),
-        romaji: Some("bunnann"),
-        jidai: Jidai::Muromachi,
-        started_at: -16593638400,
-        ended_at: Some(-16420752000),
-    },
-    Era {
-        kanji: Some("宝徳"),
-        romaji: Some("houtoku"),
-        jidai: Jidai::Muromachi,
-        started_at: -16420752000,
-        ended_at: Some(-16326576000),
-    },
-    Era {
-        kanji: Some("享徳"),
-        romaji: Some("kyoutoku"),
-        jidai: Jidai::Muromachi,
-        started_at: -16326576000,
-        ended_at: Some(-16229635200),
-    },
-    Era {
-        kanji: Some("康正"),
-        romaji: Some("koushou"),
-        jidai: Jidai::Muromachi,
-        started_at: -16229635200,
-        ended_at: Some(-16163020800),
-    },
-    Era {
-        kanji: Some("長禄"),
-        romaji: Some("chouroku"),
-        jidai: Jidai::Muromachi,
-        started_at: -16163020800,
-        ended_at: Some(-16058995200),
-    },
-    Era {
-        kanji: Some("寛正"),
-        romaji: Some("kannshou"),
-        jidai: Jidai::Muromachi,
-        started_at: -16058995200,
-        ended_at: Some(-15897686400),
-    },
-    Era {
-        kanji: Some("文正"),
-        romaji: Some("bunnshou"),
-        jidai: Jidai::Muromachi,
-        started_at: -15897686400,
-        ended_at: Some(-15863904000),
-    },
-    Era {
-        kanji: Some("応仁"),
-        romaji: Some("ouninn"),
-        jidai: Jidai::Sengoku,
-        started_at: -15863904000,
-        ended_at: Some(-15795561600),
-    },
-    Era {
-        kanji: Some("文明"),
-        romaji: Some("bunnmei"),
-        jidai: Jidai::Sengoku,
-        started_at: -15795561600,
-        ended_at: Some(-15222211200),
-    },
-    Era {
-        kanji: Some("長享"),
-        romaji: Some("choukyou"),
-        jidai: Jidai::Sengoku,
-        started_at: -15222211200,
-        ended_at: Some(-15155769600),
-    },
-    Era {
-        kanji: Some("延徳"),
-        romaji: Some("entoku"),
-        jidai: Jidai::Sengoku,
-        started_at: -15155769600,
-        ended_at: Some(-15064099200),
-    },
-    Era {
-        kanji: Some("明応"),
-        romaji: Some("meiou"),
-        jidai: Jidai::Sengoku,
-        started_at: -15064099200,
-        ended_at: Some(-14792803200),
-    },
-    Era {
-        kanji: Some("文亀"),
-        romaji: Some("bunnki"),
-        jidai: Jidai::Sengoku,
-        started_at: -14792803200,
-        ended_at: Some(-14698281600),
-    },
-    Era {
-        kanji: Some("永正"),
-        romaji: Some("eishou"),
-        jidai: Jidai::Sengoku,
-        started_at: -14698281600,
-        ended_at: Some(-14145321600),
-    },
-    Era {
-        kanji: Some("大永"),
-        romaji: Some("daiei"),
-        jidai: Jidai::Sengoku,
-        started_at: -14145321600,
-        ended_at: Some(-13926124800),
-    },
-    Era {
-        kanji: Some("享禄"),
-        romaji: Some("kyouroku"),
-        jidai: Jidai::Sengoku,
-        started_at: -13926124800,
-        ended_at: Some(-13800326400),
-    },
-    Era {
-        kanji: Some("天文"),
-        romaji: Some("tennbunn"),
-        jidai: Jidai::Sengoku,
-        started_at: -13800326400,
-        ended_at: Some(-13068518400),
-    },
-    Era {
-        kanji: Some("弘治"),
-        romaji: Some("kouji"),
-        jidai: Jidai::Sengoku,
-        started_at: -13068518400,
-        ended_at: Some(-12994041600),
-    },
-    Era {
-        kanji: Some("永禄"),
-        romaji: Some("eiroku"),
-        jidai: Jidai::Sengoku,
-        started_at: -12994041600,
-        ended_at: Some(-12609302400),
-    },
-    Era {
-        kanji: Some("元亀"),
-        romaji: Some("gennki"),
-        jidai: Jidai::Sengoku,
-        started_at: -12609302400,
-        ended_at: Some(-12506832000),
-    },
-    Era {
-        kanji: Some("天正"),
-        romaji: Some("tennshou"),
-        jidai: Jidai::AzuchiMomoyama,
-        started_at: -12506832000,
-        ended_at: Some(-11896156800),
-    },
-    Era {
-        kanji: Some("文禄"),
-        romaji: Some("bunnroku"),
-        jidai: Jidai::AzuchiMomoyama,
-        started_at: -11896156800,
-        ended_at: Some(-11772086400),
-    },
-    Era {
-        kanji: Some("慶長"),
-        romaji: Some("keichou"),
-        jidai: Jidai::AzuchiMomoyama,
-        started_at: -11772086400,
-        ended_at: Some(-11181369600),
-    },
-    Era {
-        kanji: Some("元和"),
-        romaji: Some("genna"),
-        jidai: Jidai::Edo,
-        started_at: -11181369600,
-        ended_at: Some(-10909468800),
-    },
-    Era {
-        kanji: Some("寛永"),
-        romaji: Some("kannei"),
-        jidai: Jidai::Edo,
-        started_at: -10909468800,
-        ended_at: Some(-10254902400),
-    },
-    Era {
-        kanji: Some("正保"),
-        romaji: Some("shouhou"),
-        jidai: Jidai::Edo,
-        started_at: -10254902400,
-        ended_at: Some(-10152950400),
-    },
-    Era {
-        kanji: Some("慶安"),
-        romaji: Some("keian"),
-        jidai: Jidai::Edo,
-        started_at: -10152950400,
-        ended_at: Some(-10009785600),
-    },
-    Era {
-        kanji: Some("承応"),
-        romaji: Some("jouou"),
-        jidai: Jidai::Edo,
-        started_at: -10009785600,
-        ended_at: Some(-9928569600),
-    },
-    Era {
-        kanji: Some("明暦"),
-        romaji: Some("meireki"),
-        jidai: Jidai::Edo,
-        started_at: -9928569600,
-        ended_at: Some(-9825667200),
-    },
-    Era {
-        kanji: Some("万治"),
-        romaji: Some("manji"),
-        jidai: Jidai::Edo,
-        started_at: -9825667200,
-        ended_at: Some(-9738748800),
-    },
-    Era {
-        kanji: Some("寛文"),
-        romaji: Some("kannbunn"),
-        jidai: Jidai::Edo,
-        started_at: -9738748800,
-        ended_at: Some(-9346233600),
-    },
-    Era {
-        kanji: Some("延宝"),
-        romaji: Some("empou"),
-        jidai: Jidai::Edo,
-        started_at: -9346233600,
-        ended_at: Some(-9092908800),
-    },
-    Era {
-        kanji: Some("天和"),
-        romaji: Some("tenna"),
-        jidai: Jidai::Edo,
-        started_at: -9092908800,
-        ended_at: Some(-9017049600),
-    },
-    Era {
-        kanji: Some("貞享"),
-        romaji: Some("joukyou"),
-        jidai: Jidai::Edo,
-        started_at: -9017049600,
-        ended_at: Some(-8873452800),
-    },
-    Era {
-        kanji: Some("元禄"),
-        romaji: Some("genroku"),
-        jidai: Jidai::Edo,
-        started_at: -8873452800,
-        ended_at: Some(-8385033600),
-    },
-    Era {
-        kanji: Some("宝永"),
-        romaji: Some("houei"),
-        jidai: Jidai::Edo,
-        started_at: -8385033600,
-        ended_at: Some(-8159356800),
-    },
-    Era {
-        kanji: Some("正徳"),
-        romaji: Some("shoutoku"),
-        jidai: Jidai::Edo,
-        started_at: -8159356800,
-        ended_at: Some(-7996406400),
-    },
-    Era {
-        kanji: Some("享保"),
-        romaji: Some("kyouhou"),
-        jidai: Jidai::Edo,
-        started_at: -7996406400,
-        ended_at: Some(-7370697600),
-    },
-    Era {
-        kanji: Some("元文"),
-        romaji: Some("gennbunn"),
-        jidai: Jidai::Edo,
-        started_at: -7370697600,
-        ended_at: Some(-7217769600),
-    },
-    Era {
-        kanji: Some("寛保"),
-        romaji: Some("kampou"),
-        jidai: Jidai::Edo,
-        started_at: -7217769600,
-        ended_at: Some(-7123852800),
-    },
-    Era {
-        kanji: Some("延享"),
-        romaji: Some("enkyou"),
-        jidai: Jidai::Edo,
-        started_at: -7123852800,
-        ended_at: Some(-6986908800),
-    },
-    Era {
-        kanji: Some("寛延"),
-        romaji: Some("kannenn"),
-        jidai: Jidai::Edo,
-        started_at: -6986908800,
-        ended_at: Some(-6880982400),
-    },
-    Era {
-        kanji: Some("宝暦"),
-        romaji: Some("houreki"),
-        jidai: Jidai::Edo,
-        started_at: -6880982400,
-        ended_at: Some(-6485097600),
-    },
-    Era {
-        kanji: Some("明和"),
-        romaji: Some("meiwa"),
-        jidai: Jidai::Edo,
-        started_at: -6485097600,
-        ended_at: Some(-6218553600),
-    },
-    Era {
-        kanji: Some("安永"),
-        romaji: Some("annei"),
-        jidai: Jidai::Edo,
-        started_at: -6218553600,
-        ended_at: Some(-5954342400),
-    },
-    Era {
-        kanji: Some("天明"),
-        romaji: Some("tennmei"),
-        jidai: Jidai::Edo,
-        started_at: -5954342400,
-        ended_at: Some(-5707497600),
-    },
-    Era {
-        kanji: Some("寛政"),
-        romaji: Some("kannsei"),
-        jidai: Jidai::Edo,
-        started_at: -5707497600,
-        ended_at: Some(-5326473600),
-    },
-    Era {
-        kanji: Some("享和"),
-        romaji: Some("kyouwa"),
-        jidai: Jidai::Edo,
-        started_at: -5326473600,
-        ended_at: Some(-5231520000),
-    },
-    Era {
-        kanji: Some("文化"),
-        romaji: Some("bunnka"),
-        jidai: Jidai::Edo,
-        started_at: -5231520000,
-        ended_at: Some(-4784140800),
-    },
-    Era {
-        kanji: Some("文政"),
-        romaji: Some("bunnsei"),
-        jidai: Jidai::Edo,
-        started_at: -4784140800,
-        ended_at: Some(-4384540800),
-    },
-    Era {
-        kanji: Some("天保"),
-        romaji: Some("tenmpou"),
-        jidai: Jidai::Edo,
-        started_at: -4384540800,
-        ended_at: Some(-3943900800),
-    },
-    Era {
-        kanji: Some("弘化"),
-        romaji: Some("kouka"),
-        jidai: Jidai::Edo,
-        started_at: -3943900800,
-        ended_at: Some(-3842121600),
-    },
-    Era {
-        kanji: Some("嘉永"),
-        romaji: Some("kaei"),
-        jidai: Jidai::Edo,
-        started_at: -3842121600,
-        ended_at: Some(-3627849600),
-    },
-    Era {
-        kanji: Some("安政"),
-        romaji: Some("ansei"),
-        jidai: Jidai::Edo,
-        started_at: -3627849600,
-        ended_at: Some(-3462825600),
-    },
-    Era {
-        kanji: Some("万延"),
-        romaji: Some("mannei"),
-        jidai: Jidai::Edo,
-        started_at: -3462825600,
-        ended_at: Some(-3432153600),
-    },
-    Era {
-        kanji: Some("文久"),
-        romaji: Some("bunnkyuu"),
-        jidai: Jidai::Edo,
-        started_at: -3432153600,
-        ended_at: Some(-3337632000),
-    },
-    Era {
-        kanji: Some("元治"),
-        romaji: Some("genji"),
-        jidai: Jidai::Edo,
-        started_at: -3337632000,
-        ended_at: Some(-3303072000),
-    },
-    Era {
-        kanji: Some("慶応"),
-        romaji: Some("keiou"),
-        jidai: Jidai::Edo,
-        started_at: -3303072000,
-        ended_at: Some(-3193257600),
-    },
-    Era {
-        kanji: Some("明治"),
-        romaji: Some("meiji"),
-        jidai: Jidai::Modern,
-        started_at: -3193257600,
-        ended_at: Some(-1812153600),
-    },
-    Era {
-        kanji: Some("大正"),
-        romaji: Some("taishou"),
-        jidai: Jidai::Modern,
-        started_at: -1812153600,
-        ended_at: Some(-1357603200),
-    },
-    Era {
-        kanji: Some("昭和"),
-        romaji: Some("shouwa"),
-        jidai: Jidai::Modern,
-        started_at: -1357603200,
-        ended_at: Some(600220800),
-    },
-    Era {
-        kanji: Some("平成"),
-        romaji: Some("heisei"),
-        jidai: Jidai::Modern,
-        started_at: 600220800,
-        ended_at: Some(1556668800),
-    },
-    Era {
-        kanji: Some("令和"),
-        romaji: Some("reiwa"),
-        jidai: Jidai::Modern,
-        started_at: 1556668800,
-        ended_at: None,
-    },
-];
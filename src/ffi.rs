@@ -0,0 +1,270 @@
+//! A C ABI for era lookup and nenkou formatting, with the `ffi` feature, so
+//! C, Swift and Kotlin (through JNI) can use the era table. Build the shared
+//! library with
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+//!
+//! and declare the functions as
+//!
+//! ```c
+//! ptrdiff_t nihonify_era_kanji_at(int64_t unix_epoch, char *out, size_t out_len);
+//! ptrdiff_t nihonify_era_romaji_at(int64_t unix_epoch, char *out, size_t out_len);
+//! ptrdiff_t nihonify_nenkou_string_at(int64_t unix_epoch, char *out, size_t out_len);
+//! ptrdiff_t nihonify_format_wareki_at(int64_t unix_epoch, const char *pattern, char *out, size_t out_len);
+//! bool nihonify_wareki_to_epoch(const char *s, int64_t *unix_epoch);
+//! bool nihonify_is_jp_text(const char *s);
+//! ptrdiff_t nihonify_to_hiragana(const char *s, char *out, size_t out_len);
+//! ptrdiff_t nihonify_to_katakana(const char *s, char *out, size_t out_len);
+//! ptrdiff_t nihonify_normalize_width(const char *s, char *out, size_t out_len);
+//! ```
+//!
+//! Times are seconds since the Unix epoch. Strings are NUL-terminated UTF-8,
+//! both passed in and written to `out`. Like `snprintf`, the functions return
+//! the length of the result in bytes, not counting the NUL, and write nothing
+//! if it doesn't fit in `out_len` bytes with the NUL; they return -1 if
+//! there's no result, e.g. for a time before Taika.
+
+use crate::{is_jp, kana, zenkaku};
+use nihonify_core::{format_wareki, Era};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Writes the kanji name of the era at the time, e.g. 令和.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_era_kanji_at(
+    unix_epoch: i64,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let kanji = Era::from_unix_epoch(unix_epoch).and_then(|era| era.kanji);
+    write_c_str(kanji, out, out_len)
+}
+
+/// Writes the romaji name of the era at the time, e.g. reiwa.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_era_romaji_at(
+    unix_epoch: i64,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let romaji = Era::from_unix_epoch(unix_epoch).and_then(|era| era.romaji);
+    write_c_str(romaji, out, out_len)
+}
+
+/// Writes the time as a nenkou datestring, e.g. 令和３年１１月１２日.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_nenkou_string_at(
+    unix_epoch: i64,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let nenkou = chrono::DateTime::from_timestamp(unix_epoch, 0).and_then(Era::to_jp_nenkou_string);
+    write_c_str(nenkou.as_deref(), out, out_len)
+}
+
+/// Writes the time formatted by the `format_wareki` pattern, e.g. 令和3年 for
+/// `%JE%Jy年`.
+///
+/// # Safety
+///
+/// `pattern` must be a NUL-terminated string, and `out` must be valid for
+/// writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_format_wareki_at(
+    unix_epoch: i64,
+    pattern: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let formatted = chrono::DateTime::from_timestamp(unix_epoch, 0)
+        .zip(read_c_str(pattern))
+        .and_then(|(date, pattern)| format_wareki(date, pattern).ok());
+    write_c_str(formatted.as_deref(), out, out_len)
+}
+
+/// Parses a wareki date, e.g. 令和3年11月12日, into the epoch of its midnight
+/// UTC. Returns whether it was one, leaving `unix_epoch` alone if not.
+///
+/// # Safety
+///
+/// `s` must be a NUL-terminated string, and `unix_epoch` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_wareki_to_epoch(s: *const c_char, unix_epoch: *mut i64) -> bool {
+    match read_c_str(s).and_then(|s| Era::parse_wareki(s).ok()) {
+        Some(date) => {
+            *unix_epoch = date.timestamp();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether the string has Japanese in it, as `is_jp`.
+///
+/// # Safety
+///
+/// `s` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_is_jp_text(s: *const c_char) -> bool {
+    read_c_str(s).is_some_and(is_jp)
+}
+
+/// Writes the string with its katakana as hiragana, as `kana::to_hiragana`.
+///
+/// # Safety
+///
+/// `s` must be a NUL-terminated string, and `out` must be valid for writes of
+/// `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_to_hiragana(
+    s: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let hiragana = read_c_str(s).map(kana::to_hiragana);
+    write_c_str(hiragana.as_deref(), out, out_len)
+}
+
+/// Writes the string with its hiragana as katakana, as `kana::to_katakana`.
+///
+/// # Safety
+///
+/// `s` must be a NUL-terminated string, and `out` must be valid for writes of
+/// `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_to_katakana(
+    s: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let katakana = read_c_str(s).map(kana::to_katakana);
+    write_c_str(katakana.as_deref(), out, out_len)
+}
+
+/// Writes the string with half-width ASCII and full-width katakana, as
+/// `zenkaku::normalize_width`.
+///
+/// # Safety
+///
+/// `s` must be a NUL-terminated string, and `out` must be valid for writes of
+/// `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nihonify_normalize_width(
+    s: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    let normalized = read_c_str(s).map(zenkaku::normalize_width);
+    write_c_str(normalized.as_deref(), out, out_len)
+}
+
+unsafe fn read_c_str<'a>(s: *const c_char) -> Option<&'a str> {
+    CStr::from_ptr(s).to_str().ok()
+}
+
+unsafe fn write_c_str(s: Option<&str>, out: *mut c_char, out_len: usize) -> isize {
+    let s = match s {
+        Some(s) => s,
+        None => return -1,
+    };
+    if s.len() < out_len {
+        ptr::copy_nonoverlapping(s.as_ptr(), out as *mut u8, s.len());
+        *out.add(s.len()) = 0;
+    }
+    s.len() as isize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    type Export = unsafe extern "C" fn(i64, *mut c_char, usize) -> isize;
+
+    fn call(f: Export, unix_epoch: i64) -> Option<String> {
+        let mut out = [1; 64];
+        let len = unsafe { f(unix_epoch, out.as_mut_ptr(), out.len()) };
+        if len < 0 {
+            return None;
+        }
+        let s = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(s.to_bytes().len(), len as usize);
+        Some(s.to_str().unwrap().to_owned())
+    }
+
+    #[test]
+    fn test_exports() {
+        // 2021-11-12T00:00:00Z
+        let epoch = 1_636_675_200;
+        assert_eq!(call(nihonify_era_kanji_at, epoch).unwrap(), "令和");
+        assert_eq!(call(nihonify_era_romaji_at, epoch).unwrap(), "reiwa");
+        assert_eq!(
+            call(nihonify_nenkou_string_at, epoch).unwrap(),
+            "令和３年１１月１２日"
+        );
+        assert_eq!(call(nihonify_era_kanji_at, i64::MIN), None);
+        assert_eq!(call(nihonify_nenkou_string_at, i64::MAX), None);
+    }
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn test_string_exports() {
+        let mut out = [1; 64];
+        let string = |len: isize, out: &[c_char]| {
+            let s = unsafe { CStr::from_ptr(out.as_ptr()) };
+            assert_eq!(s.to_bytes().len(), len as usize);
+            s.to_str().unwrap().to_owned()
+        };
+
+        let len = unsafe {
+            nihonify_format_wareki_at(1_636_675_200, c("%JE%Jy年").as_ptr(), out.as_mut_ptr(), 64)
+        };
+        assert_eq!(string(len, &out), "令和3年");
+        let len = unsafe { nihonify_to_hiragana(c("カタカナ").as_ptr(), out.as_mut_ptr(), 64) };
+        assert_eq!(string(len, &out), "かたかな");
+        let len = unsafe { nihonify_to_katakana(c("ひらがな").as_ptr(), out.as_mut_ptr(), 64) };
+        assert_eq!(string(len, &out), "ヒラガナ");
+        let len = unsafe {
+            nihonify_normalize_width(c("ＡＢＣｶﾅ").as_ptr(), out.as_mut_ptr(), 64)
+        };
+        assert_eq!(string(len, &out), "ABCカナ");
+
+        let mut epoch = 0;
+        assert!(unsafe {
+            nihonify_wareki_to_epoch(c("令和3年11月12日").as_ptr(), &mut epoch)
+        });
+        assert_eq!(epoch, 1_636_675_200);
+        assert!(!unsafe { nihonify_wareki_to_epoch(c("2021").as_ptr(), &mut epoch) });
+        assert_eq!(epoch, 1_636_675_200);
+
+        assert!(unsafe { nihonify_is_jp_text(c("ひらがな").as_ptr()) });
+        assert!(!unsafe { nihonify_is_jp_text(c("latin").as_ptr()) });
+    }
+
+    #[test]
+    fn test_small_buffer() {
+        // 令和 is six bytes, so it needs seven with the NUL.
+        let mut out = [1; 6];
+        let len = unsafe { nihonify_era_kanji_at(1_636_675_200, out.as_mut_ptr(), 6) };
+        assert_eq!(len, 6);
+        assert_eq!(out, [1; 6]);
+    }
+}
@@ -0,0 +1,572 @@
+//! Conversions between the kana scripts, and between kana and romaji.
+
+/// How far the katakana block is from the hiragana block.
+const KATAKANA_SHIFT: u32 = 0x60;
+
+/// Converts the hiragana in the string to katakana, e.g. ひらがな to ヒラガナ,
+/// small kana and the ゝゞ iteration marks included. Other chars, like the
+/// prolonged sound mark ー, are kept.
+pub fn to_katakana(s: &str) -> String {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x3041..=0x3096 | 0x309D..=0x309E => {
+                core::char::from_u32(c as u32 + KATAKANA_SHIFT).unwrap()
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts the katakana in the string to hiragana, e.g. カタカナ to かたかな,
+/// small kana and the ヽヾ iteration marks included. ヷ, ヸ, ヹ and ヺ, which
+/// have no hiragana, become わ, ゐ, ゑ and を with a combining dakuten. Other
+/// chars, like the prolonged sound mark ー and half-width katakana, are kept.
+pub fn to_hiragana(s: &str) -> String {
+    let mut hiragana = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c as u32 {
+            0x30A1..=0x30F6 | 0x30FD..=0x30FE => {
+                hiragana.push(core::char::from_u32(c as u32 - KATAKANA_SHIFT).unwrap())
+            }
+            0x30F7..=0x30FA => {
+                hiragana.push(core::char::from_u32(c as u32 - 8 - KATAKANA_SHIFT).unwrap());
+                hiragana.push('\u{3099}');
+            }
+            _ => hiragana.push(c),
+        }
+    }
+
+    hiragana
+}
+
+/// The kana for each romaji syllable, in Hepburn, Kunrei and the spellings
+/// IMEs accept. x- and l- give the small kana.
+const ROMAJI: &[(&str, &str)] = &[
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sa", "さ"),
+    ("si", "し"),
+    ("shi", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("sha", "しゃ"),
+    ("shu", "しゅ"),
+    ("she", "しぇ"),
+    ("sho", "しょ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("sye", "しぇ"),
+    ("syo", "しょ"),
+    ("za", "ざ"),
+    ("zi", "じ"),
+    ("ji", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ja", "じゃ"),
+    ("ju", "じゅ"),
+    ("je", "じぇ"),
+    ("jo", "じょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jye", "じぇ"),
+    ("jyo", "じょ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zye", "じぇ"),
+    ("zyo", "じょ"),
+    ("ta", "た"),
+    ("ti", "ち"),
+    ("chi", "ち"),
+    ("tu", "つ"),
+    ("tsu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("cha", "ちゃ"),
+    ("chu", "ちゅ"),
+    ("che", "ちぇ"),
+    ("cho", "ちょ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tye", "ちぇ"),
+    ("tyo", "ちょ"),
+    ("cya", "ちゃ"),
+    ("cyu", "ちゅ"),
+    ("cye", "ちぇ"),
+    ("cyo", "ちょ"),
+    ("tsa", "つぁ"),
+    ("thi", "てぃ"),
+    ("thu", "てゅ"),
+    ("da", "だ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("dya", "ぢゃ"),
+    ("dyu", "ぢゅ"),
+    ("dyo", "ぢょ"),
+    ("dhi", "でぃ"),
+    ("dhu", "でゅ"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("hu", "ふ"),
+    ("fu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("fa", "ふぁ"),
+    ("fi", "ふぃ"),
+    ("fe", "ふぇ"),
+    ("fo", "ふぉ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("ye", "いぇ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    ("wa", "わ"),
+    ("wi", "うぃ"),
+    ("we", "うぇ"),
+    ("wo", "を"),
+    ("va", "ゔぁ"),
+    ("vi", "ゔぃ"),
+    ("vu", "ゔ"),
+    ("ve", "ゔぇ"),
+    ("vo", "ゔぉ"),
+    ("xa", "ぁ"),
+    ("xi", "ぃ"),
+    ("xu", "ぅ"),
+    ("xe", "ぇ"),
+    ("xo", "ぉ"),
+    ("la", "ぁ"),
+    ("li", "ぃ"),
+    ("lu", "ぅ"),
+    ("le", "ぇ"),
+    ("lo", "ぉ"),
+    ("xya", "ゃ"),
+    ("xyu", "ゅ"),
+    ("xyo", "ょ"),
+    ("lya", "ゃ"),
+    ("lyu", "ゅ"),
+    ("lyo", "ょ"),
+    ("xtu", "っ"),
+    ("xtsu", "っ"),
+    ("ltu", "っ"),
+    ("ltsu", "っ"),
+    ("xwa", "ゎ"),
+    ("lwa", "ゎ"),
+];
+
+/// Converts romaji to hiragana the way an IME does, e.g. nihongo to にほんご,
+/// in any of Hepburn, Kunrei and the IME spellings. A doubled consonant is
+/// っ, as is the t of tch; n is ん unless a vowel or y follows, and nn or n'
+/// force ん, e.g. kan'i to かんい. Hepburn's m before b, m and p and vowels
+/// with macrons are understood, e.g. shimbun to しんぶん, Shōwa to しょうわ.
+/// Anything else, like digits and punctuation, is kept.
+pub fn romaji_to_hiragana(s: &str) -> String {
+    // Vowels with macrons or circumflexes are long vowels, ō being おう.
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.to_lowercase().chars() {
+        match c {
+            'ā' | 'â' => chars.extend(['a', 'a']),
+            'ī' | 'î' => chars.extend(['i', 'i']),
+            'ū' | 'û' => chars.extend(['u', 'u']),
+            'ē' | 'ê' => chars.extend(['e', 'e']),
+            'ō' | 'ô' => chars.extend(['o', 'u']),
+            _ => chars.push(c),
+        }
+    }
+    let vowel_or_y = |c: Option<&char>| matches!(c, Some('a' | 'i' | 'u' | 'e' | 'o' | 'y'));
+
+    let mut hiragana = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let (c, next) = (chars[i], chars.get(i + 1));
+        let moraic = match c {
+            'n' if next == Some(&'\'') => Some(('ん', 2)),
+            'n' if next == Some(&'n') && !vowel_or_y(chars.get(i + 2)) => Some(('ん', 2)),
+            'n' if !vowel_or_y(next) => Some(('ん', 1)),
+            'm' if matches!(next, Some('b' | 'm' | 'p')) => Some(('ん', 1)),
+            'a'..='z'
+                if !vowel_or_y(Some(&c))
+                    && (next == Some(&c) || (c, next) == ('t', Some(&'c'))) =>
+            {
+                Some(('っ', 1))
+            }
+            _ => None,
+        };
+        if let Some((kana, len)) = moraic {
+            hiragana.push(kana);
+            i += len;
+            continue;
+        }
+
+        let syllable = (1..=4).rev().find_map(|len| {
+            let romaji: String = chars.get(i..i + len)?.iter().collect();
+            ROMAJI
+                .iter()
+                .find(|&&(other, _)| other == romaji)
+                .map(|&(_, kana)| (len, kana))
+        });
+        match syllable {
+            Some((len, kana)) => {
+                hiragana.push_str(kana);
+                i += len;
+            }
+            None => {
+                hiragana.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    hiragana
+}
+
+/// The romanization systems `to_romaji` writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomajiStyle {
+    /// Modified Hepburn, with macrons for long vowels, e.g. Tōkyō, shinbun.
+    Hepburn,
+    /// Kunrei-shiki, with circumflexes for long vowels, e.g. Tôkyô, sinbun.
+    Kunrei,
+}
+
+/// The Hepburn romaji of each kana.
+const HEPBURN: &[(char, &str)] = &[
+    ('あ', "a"),
+    ('い', "i"),
+    ('う', "u"),
+    ('え', "e"),
+    ('お', "o"),
+    ('か', "ka"),
+    ('き', "ki"),
+    ('く', "ku"),
+    ('け', "ke"),
+    ('こ', "ko"),
+    ('が', "ga"),
+    ('ぎ', "gi"),
+    ('ぐ', "gu"),
+    ('げ', "ge"),
+    ('ご', "go"),
+    ('さ', "sa"),
+    ('し', "shi"),
+    ('す', "su"),
+    ('せ', "se"),
+    ('そ', "so"),
+    ('ざ', "za"),
+    ('じ', "ji"),
+    ('ず', "zu"),
+    ('ぜ', "ze"),
+    ('ぞ', "zo"),
+    ('た', "ta"),
+    ('ち', "chi"),
+    ('つ', "tsu"),
+    ('て', "te"),
+    ('と', "to"),
+    ('だ', "da"),
+    ('ぢ', "ji"),
+    ('づ', "zu"),
+    ('で', "de"),
+    ('ど', "do"),
+    ('な', "na"),
+    ('に', "ni"),
+    ('ぬ', "nu"),
+    ('ね', "ne"),
+    ('の', "no"),
+    ('は', "ha"),
+    ('ひ', "hi"),
+    ('ふ', "fu"),
+    ('へ', "he"),
+    ('ほ', "ho"),
+    ('ば', "ba"),
+    ('び', "bi"),
+    ('ぶ', "bu"),
+    ('べ', "be"),
+    ('ぼ', "bo"),
+    ('ぱ', "pa"),
+    ('ぴ', "pi"),
+    ('ぷ', "pu"),
+    ('ぺ', "pe"),
+    ('ぽ', "po"),
+    ('ま', "ma"),
+    ('み', "mi"),
+    ('む', "mu"),
+    ('め', "me"),
+    ('も', "mo"),
+    ('や', "ya"),
+    ('ゆ', "yu"),
+    ('よ', "yo"),
+    ('ら', "ra"),
+    ('り', "ri"),
+    ('る', "ru"),
+    ('れ', "re"),
+    ('ろ', "ro"),
+    ('わ', "wa"),
+    ('ゐ', "i"),
+    ('ゑ', "e"),
+    ('を', "o"),
+    ('ゔ', "vu"),
+    ('ぁ', "a"),
+    ('ぃ', "i"),
+    ('ぅ', "u"),
+    ('ぇ', "e"),
+    ('ぉ', "o"),
+    ('ゃ', "ya"),
+    ('ゅ', "yu"),
+    ('ょ', "yo"),
+    ('ゎ', "wa"),
+    ('ゕ', "ka"),
+    ('ゖ', "ke"),
+];
+
+/// The kana Kunrei-shiki writes differently from Hepburn.
+const KUNREI: &[(char, &str)] = &[
+    ('し', "si"),
+    ('じ', "zi"),
+    ('ち', "ti"),
+    ('ぢ', "zi"),
+    ('つ', "tu"),
+    ('ふ', "hu"),
+];
+
+/// Romanizes the kana in the string, e.g. とうきょう to tōkyō in Hepburn.
+/// Small ゃ, ゅ and ょ make digraphs (しゃ is sha, or sya in Kunrei), っ
+/// doubles the next consonant (まっちゃ is matcha), ん is written n' before
+/// a vowel or y (きんえん is kin'en), and long vowels, written with う or ー
+/// after an o or a repeated vowel, get a macron or circumflex. Other chars
+/// are kept, as is a っ with nothing after it.
+pub fn to_romaji(s: &str, style: RomajiStyle) -> String {
+    let chars: Vec<char> = to_hiragana(s).chars().collect();
+    let mut romaji = String::with_capacity(s.len());
+    let (mut sokuon, mut after_n) = (false, false);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let mut syllable = match syllable_of(c, style) {
+            Some(syllable) => String::from(syllable),
+            None => {
+                match c {
+                    'っ' => sokuon = true,
+                    'ん' => romaji.push('n'),
+                    'ー' => lengthen(&mut romaji, style),
+                    _ => romaji.push(c),
+                }
+                after_n = c == 'ん';
+                sokuon &= c == 'っ';
+                i += 1;
+                continue;
+            }
+        };
+
+        // Digraphs, e.g. きゃ and ふぁ.
+        let mut digraph = true;
+        match chars.get(i + 1) {
+            Some(&small @ ('ゃ' | 'ゅ' | 'ょ'))
+                if syllable.len() > 1 && syllable.ends_with('i') =>
+            {
+                syllable.pop();
+                if !syllable.ends_with('h') && syllable != "j" {
+                    syllable.push('y');
+                }
+                syllable.push_str(&syllable_of(small, style).unwrap()[1..]);
+            }
+            Some(&small @ ('ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ'))
+                if syllable.len() > 1 || c == 'う' || c == 'い' =>
+            {
+                match c {
+                    'う' => syllable = String::from("w"),
+                    'い' => syllable = String::from("y"),
+                    _ => {
+                        syllable.pop();
+                    }
+                }
+                syllable.push_str(syllable_of(small, style).unwrap());
+            }
+            _ => digraph = false,
+        }
+
+        // A vowel after the same vowel, or う after o, makes it long, but
+        // not across ん or for いい.
+        let last = romaji.chars().last();
+        let long = !digraph
+            && !after_n
+            && "あうえお".contains(c)
+            && (last == syllable.chars().next() || (last, c) == (Some('o'), 'う'));
+        if long {
+            lengthen(&mut romaji, style);
+        } else {
+            if after_n && syllable.starts_with(|c| "aiueoy".contains(c)) {
+                romaji.push('\'');
+            }
+            if sokuon && !syllable.starts_with(|c| "aiueo".contains(c)) {
+                let doubled = if syllable.starts_with("ch") {
+                    Some('t')
+                } else {
+                    syllable.chars().next()
+                };
+                romaji.extend(doubled);
+            }
+            romaji.push_str(&syllable);
+        }
+        sokuon = false;
+        after_n = false;
+        i += if digraph { 2 } else { 1 };
+    }
+
+    romaji
+}
+
+/// The romaji of the kana, on its own.
+fn syllable_of(kana: char, style: RomajiStyle) -> Option<&'static str> {
+    let kunrei = match style {
+        RomajiStyle::Kunrei => KUNREI.iter().find(|&&(other, _)| other == kana),
+        RomajiStyle::Hepburn => None,
+    };
+    kunrei
+        .or_else(|| HEPBURN.iter().find(|&&(other, _)| other == kana))
+        .map(|&(_, romaji)| romaji)
+}
+
+/// Marks the vowel the romaji ends with as long, e.g. o as ō in Hepburn.
+fn lengthen(romaji: &mut String, style: RomajiStyle) {
+    let long_vowels = match style {
+        RomajiStyle::Hepburn => "āīūēō",
+        RomajiStyle::Kunrei => "âîûêô",
+    };
+    if let Some(i) = romaji.chars().last().and_then(|vowel| "aiueo".find(vowel)) {
+        romaji.pop();
+        romaji.push(long_vowels.chars().nth(i).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_katakana() {
+        assert_eq!(to_katakana("ひらがな"), "ヒラガナ");
+        assert_eq!(to_katakana("きゃっと"), "キャット");
+        assert_eq!(to_katakana("らーめん"), "ラーメン");
+        assert_eq!(to_katakana("いすゞ"), "イスヾ");
+        assert_eq!(to_katakana("令和ゔ Reiwa"), "令和ヴ Reiwa");
+    }
+
+    #[test]
+    fn test_to_hiragana() {
+        assert_eq!(to_hiragana("カタカナ"), "かたかな");
+        assert_eq!(to_hiragana("ヴァイオリン"), "ゔぁいおりん");
+        assert_eq!(to_hiragana("ケーキ"), "けーき");
+        assert_eq!(to_hiragana("ヶ月"), "ゖ月");
+        assert_eq!(to_hiragana("ヷ"), "わ\u{3099}");
+        assert_eq!(to_hiragana("ｶﾀｶﾅ"), "ｶﾀｶﾅ");
+        assert_eq!(to_hiragana(&to_katakana("ひらがな")), "ひらがな");
+    }
+
+    #[test]
+    fn test_romaji_to_hiragana() {
+        assert_eq!(romaji_to_hiragana("nihongo"), "にほんご");
+        assert_eq!(romaji_to_hiragana("Kyoto"), "きょと");
+        assert_eq!(romaji_to_hiragana("shashin"), "しゃしん");
+        assert_eq!(romaji_to_hiragana("sasshi"), "さっし");
+        assert_eq!(romaji_to_hiragana("matcha"), "まっちゃ");
+        assert_eq!(romaji_to_hiragana("konnichiwa"), "こんにちわ");
+        assert_eq!(romaji_to_hiragana("honn"), "ほん");
+        assert_eq!(romaji_to_hiragana("kan'i"), "かんい");
+        assert_eq!(romaji_to_hiragana("kani"), "かに");
+        assert_eq!(romaji_to_hiragana("shinyou"), "しにょう");
+        assert_eq!(romaji_to_hiragana("shin'you"), "しんよう");
+        assert_eq!(romaji_to_hiragana("shimbun"), "しんぶん");
+        assert_eq!(romaji_to_hiragana("Shōwa"), "しょうわ");
+        assert_eq!(romaji_to_hiragana("siti tuzi hutatu"), "しち つじ ふたつ");
+        assert_eq!(romaji_to_hiragana("Reiwa 3"), "れいわ 3");
+        assert_eq!(romaji_to_hiragana("xtsu"), "っ");
+    }
+
+    #[test]
+    fn test_to_romaji() {
+        let hepburn = |s| to_romaji(s, RomajiStyle::Hepburn);
+        let kunrei = |s| to_romaji(s, RomajiStyle::Kunrei);
+        assert_eq!(hepburn("とうきょう"), "tōkyō");
+        assert_eq!(kunrei("とうきょう"), "tôkyô");
+        assert_eq!(hepburn("しんぶん"), "shinbun");
+        assert_eq!(kunrei("しんぶん"), "sinbun");
+        assert_eq!(hepburn("ちゃ しゃ じゃ つ ふ"), "cha sha ja tsu fu");
+        assert_eq!(kunrei("ちゃ しゃ じゃ つ ふ"), "tya sya zya tu hu");
+        assert_eq!(hepburn("まっちゃ"), "matcha");
+        assert_eq!(kunrei("まっちゃ"), "mattya");
+        assert_eq!(hepburn("きんえん"), "kin'en");
+        assert_eq!(hepburn("しんよう"), "shin'yō");
+        assert_eq!(hepburn("おかあさん"), "okāsan");
+        assert_eq!(hepburn("おにいさん"), "oniisan");
+        assert_eq!(hepburn("ラーメン"), "rāmen");
+        assert_eq!(hepburn("ヴァイオリン"), "vaiorin");
+        assert_eq!(hepburn("ウィキ"), "wiki");
+        assert_eq!(hepburn("パーティー"), "pātī");
+        assert_eq!(hepburn("令和3年"), "令和3年");
+        assert_eq!(hepburn("あっ"), "a");
+        assert_eq!(hepburn("ンー"), "n");
+        assert_eq!(hepburn("れいわ"), "reiwa");
+    }
+}
@@ -0,0 +1,198 @@
+//! A Python module, with the `python` feature, for looking up eras and
+//! formatting and parsing wareki dates, plus the text utilities. Build the
+//! extension module with
+//!
+//! ```text
+//! cargo rustc --release --lib --features python,pyo3/extension-module --crate-type cdylib
+//! cp target/release/libnihonify.so nihonify.so
+//! ```
+//!
+//! and use it as
+//!
+//! ```text
+//! >>> import datetime, nihonify
+//! >>> date = datetime.datetime(2021, 11, 12, tzinfo=datetime.timezone.utc)
+//! >>> nihonify.Era.at(date)
+//! Era(kanji='令和', romaji='reiwa')
+//! >>> nihonify.nenkou_string(date)
+//! '令和３年１１月１２日'
+//! ```
+//!
+//! Times are timezone-aware `datetime`s. Functions with no result for their
+//! input, e.g. a time before Taika, return `None`; parsing and formatting
+//! raise `ValueError` instead.
+
+use crate::{kana, zenkaku};
+use chrono::prelude::*;
+use nihonify_core::{format_wareki as format, Era};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// An era, e.g. 令和.
+#[pyclass(name = "Era", module = "nihonify", frozen)]
+struct PyEra(&'static Era);
+
+#[pymethods]
+impl PyEra {
+    /// The era at the time.
+    #[staticmethod]
+    fn at(time: DateTime<FixedOffset>) -> Option<Self> {
+        Era::from_datetime(time.to_utc()).map(PyEra)
+    }
+
+    /// The era with the given kanji name, e.g. 令和.
+    #[staticmethod]
+    fn from_kanji(kanji: &str) -> Option<Self> {
+        Era::from_kanji(kanji).map(PyEra)
+    }
+
+    /// The era with the given romaji name, as `Era::from_romaji`.
+    #[staticmethod]
+    fn from_romaji(romaji: &str) -> Option<Self> {
+        Era::from_romaji(romaji).map(PyEra)
+    }
+
+    #[getter]
+    fn kanji(&self) -> Option<&'static str> {
+        self.0.kanji
+    }
+
+    #[getter]
+    fn romaji(&self) -> Option<&'static str> {
+        self.0.romaji
+    }
+
+    #[getter]
+    fn reading(&self) -> Option<&'static str> {
+        self.0.reading
+    }
+
+    /// The first day of the era in Japan.
+    #[getter]
+    fn start_date(&self) -> NaiveDate {
+        self.0.start_date_jst()
+    }
+
+    /// The last day of the era in Japan, or `None` for the current era.
+    #[getter]
+    fn end_date(&self) -> Option<NaiveDate> {
+        self.0.end_date_jst()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Era(kanji='{}', romaji='{}')",
+            self.0.kanji.unwrap_or_default(),
+            self.0.romaji.unwrap_or_default()
+        )
+    }
+}
+
+/// The time as a nenkou datestring, e.g. 令和３年１１月１２日.
+#[pyfunction]
+fn nenkou_string(time: DateTime<FixedOffset>) -> Option<String> {
+    Era::to_jp_nenkou_string(time.to_utc())
+}
+
+/// The time formatted by a `format_wareki` pattern, e.g. 令和3年 for
+/// `%JE%Jy年`.
+#[pyfunction]
+fn format_wareki(time: DateTime<FixedOffset>, pattern: &str) -> PyResult<String> {
+    format(time.to_utc(), pattern).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Parses a wareki date, e.g. 令和3年11月12日, into midnight UTC.
+#[pyfunction]
+fn parse_wareki(s: &str) -> PyResult<DateTime<Utc>> {
+    Era::parse_wareki(s).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Whether the string has Japanese in it.
+#[pyfunction]
+fn is_jp(s: &str) -> bool {
+    crate::is_jp(s)
+}
+
+/// The string with its katakana as hiragana.
+#[pyfunction]
+fn to_hiragana(s: &str) -> String {
+    kana::to_hiragana(s)
+}
+
+/// The string with its hiragana as katakana.
+#[pyfunction]
+fn to_katakana(s: &str) -> String {
+    kana::to_katakana(s)
+}
+
+/// The string with half-width ASCII and full-width katakana.
+#[pyfunction]
+fn normalize_width(s: &str) -> String {
+    zenkaku::normalize_width(s)
+}
+
+#[pymodule]
+fn nihonify(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEra>()?;
+    module.add_function(wrap_pyfunction!(nenkou_string, module)?)?;
+    module.add_function(wrap_pyfunction!(format_wareki, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_wareki, module)?)?;
+    module.add_function(wrap_pyfunction!(is_jp, module)?)?;
+    module.add_function(wrap_pyfunction!(to_hiragana, module)?)?;
+    module.add_function(wrap_pyfunction!(to_katakana, module)?)?;
+    module.add_function(wrap_pyfunction!(normalize_width, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::IntoPyDict;
+
+    #[test]
+    fn test_module() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "nihonify").unwrap();
+            nihonify(&module).unwrap();
+            let locals = [("nihonify", module)].into_py_dict(py).unwrap();
+            py.run(
+                pyo3::ffi::c_str!(
+                    r#"
+import datetime
+
+date = datetime.datetime(2021, 11, 12, tzinfo=datetime.timezone.utc)
+era = nihonify.Era.at(date)
+assert repr(era) == "Era(kanji='令和', romaji='reiwa')", era
+assert era.reading == "れいわ"
+assert era.start_date == datetime.date(2019, 5, 1)
+assert era.end_date is None
+assert nihonify.Era.from_romaji("Heisei").end_date == datetime.date(2019, 4, 30)
+assert nihonify.Era.from_kanji("令") is None
+assert nihonify.Era.at(datetime.datetime(600, 1, 1, tzinfo=datetime.timezone.utc)) is None
+
+# Midnight in Japan is still the day before in UTC.
+jst = datetime.timezone(datetime.timedelta(hours=9))
+assert nihonify.nenkou_string(datetime.datetime(2019, 5, 1, tzinfo=jst)) == "令和元年５月１日"
+assert nihonify.format_wareki(date, "%JE%Jy年") == "令和3年"
+assert nihonify.parse_wareki("令和3年11月12日") == date
+try:
+    nihonify.parse_wareki("2021")
+    assert False
+except ValueError:
+    pass
+
+assert nihonify.is_jp("ひらがな")
+assert not nihonify.is_jp("latin")
+assert nihonify.to_hiragana("カタカナ") == "かたかな"
+assert nihonify.to_katakana("ひらがな") == "ヒラガナ"
+assert nihonify.normalize_width("ＡＢＣｶﾅ") == "ABCカナ"
+"#
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
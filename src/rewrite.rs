@@ -0,0 +1,613 @@
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use nihonify_core::parse::parse_wareki_prefix;
+use std::io::{self, Read, Write};
+
+/// Rewrites every wareki date in the text, e.g. 令和3年11月12日, to the
+/// Gregorian date formatted with the chrono format string, e.g. "%Y-%m-%d".
+/// Everything else is kept as is.
+///
+/// Panics if `format` isn't a valid chrono format string.
+pub fn rewrite_wareki_to_gregorian(text: &str, format: &str) -> String {
+    rewrite_wareki_with(text, |date| date.format(format).to_string())
+}
+
+/// Like `rewrite_wareki_to_gregorian`, but each date is rendered by `render`.
+pub fn rewrite_wareki_with<F>(text: &str, render: F) -> String
+where
+    F: FnMut(NaiveDate) -> String,
+{
+    replace_matches(text, parse_wareki_prefix, render)
+}
+
+/// The markup a text is written in, which decides what the rewriting
+/// functions leave alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Markup {
+    /// Rewrite everything.
+    Plain,
+    /// Skip fenced code blocks, code spans, link destinations, autolinks,
+    /// bare URLs and inline HTML tags. Indented code blocks aren't detected.
+    Markdown,
+    /// Skip tags, comments, and the contents of `<script>`, `<style>`,
+    /// `<code>` and `<pre>` elements.
+    Html,
+}
+
+/// Like `rewrite_wareki_with`, but only the text of a Markdown or HTML
+/// document is rewritten; code, URLs and tags are kept byte for byte.
+pub fn rewrite_wareki_in<F>(text: &str, markup: Markup, mut render: F) -> String
+where
+    F: FnMut(NaiveDate) -> String,
+{
+    let mut out = String::with_capacity(text.len());
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < text.len() {
+        let at_line_start = i == 0 || text.as_bytes()[i - 1] == b'\n';
+        match skipped_len(&text[i..], at_line_start, markup) {
+            Some(len) => {
+                out.push_str(&replace_matches(
+                    &text[text_start..i],
+                    parse_wareki_prefix,
+                    &mut render,
+                ));
+                out.push_str(&text[i..i + len]);
+                i += len;
+                text_start = i;
+            }
+            None => i += text[i..].chars().next().unwrap().len_utf8(),
+        }
+    }
+    out.push_str(&replace_matches(
+        &text[text_start..],
+        parse_wareki_prefix,
+        &mut render,
+    ));
+
+    out
+}
+
+/// The length of the markup at the start of `s` that rewriting must skip, if
+/// any.
+fn skipped_len(s: &str, at_line_start: bool, markup: Markup) -> Option<usize> {
+    match markup {
+        Markup::Plain => None,
+        Markup::Markdown => {
+            if at_line_start {
+                if let Some(len) = fenced_code_len(s) {
+                    return Some(len);
+                }
+            }
+
+            if s.starts_with('`') {
+                // A code span closes at the next run of as many backticks.
+                let ticks = s.len() - s.trim_start_matches('`').len();
+                let fence = &s[..ticks];
+                let close = s[ticks..].find(fence)?;
+                Some(ticks + close + ticks)
+            } else if s.starts_with("](") {
+                s.find(')').map(|close| close + 1)
+            } else if s.starts_with("http://") || s.starts_with("https://") {
+                Some(s.find(char::is_whitespace).unwrap_or(s.len()))
+            } else {
+                tag_len(s)
+            }
+        }
+        Markup::Html => {
+            if s.starts_with("<!--") {
+                return Some(s.find("-->").map_or(s.len(), |end| end + 3));
+            }
+
+            let len = tag_len(s)?;
+            let name: String = s[1..]
+                .chars()
+                .take_while(char::is_ascii_alphanumeric)
+                .collect::<String>()
+                .to_ascii_lowercase();
+            if !["script", "style", "code", "pre"].contains(&name.as_str()) {
+                return Some(len);
+            }
+
+            // Skip the element's contents through to its closing tag.
+            let close = format!("</{}", name);
+            let end = s.to_ascii_lowercase()[len..]
+                .find(&close)
+                .map_or(s.len(), |at| len + at);
+            Some(end + tag_len(&s[end..]).unwrap_or(0))
+        }
+    }
+}
+
+/// The length of the HTML tag or autolink at the start of `s`, if any.
+fn tag_len(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+    if chars.next() != Some('<') {
+        return None;
+    }
+    if !chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!')
+    {
+        return None;
+    }
+    s.find('>').map(|close| close + 1)
+}
+
+/// The length of the Markdown fenced code block at the start of `s`, if any,
+/// through to its closing fence or the end of the text.
+fn fenced_code_len(s: &str) -> Option<usize> {
+    let indented = s.trim_start_matches(' ');
+    if s.len() - indented.len() > 3 {
+        return None;
+    }
+    let fence_char = indented.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let fence = indented.len() - indented.trim_start_matches(fence_char).len();
+    if fence < 3 {
+        return None;
+    }
+
+    let mut at = s.find('\n').map_or(s.len(), |end| end + 1);
+    while at < s.len() {
+        let line_end = s[at..].find('\n').map_or(s.len(), |end| at + end + 1);
+        let line = s[at..line_end].trim();
+        if line.len() >= fence && line.chars().all(|c| c == fence_char) {
+            return Some(line_end);
+        }
+        at = line_end;
+    }
+
+    Some(s.len())
+}
+
+/// Like `rewrite_wareki_with`, but reads the text from `input` and writes the
+/// result to `output` a chunk at a time, so inputs of any size are rewritten
+/// in bounded memory. Fails if the input isn't UTF-8.
+pub fn rewrite_wareki_stream<R, W, F>(input: R, mut output: W, mut render: F) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(NaiveDate) -> String,
+{
+    for_each_chunk(input, |text, eof| {
+        let mut result = Ok(());
+        let consumed = scan_matches(text, eof, parse_wareki_prefix, |text, date| {
+            if result.is_ok() {
+                result = match date {
+                    Some(date) => output.write_all(render(date).as_bytes()),
+                    None => output.write_all(text.as_bytes()),
+                };
+            }
+        });
+        result.map(|_| consumed)
+    })?;
+
+    output.flush()
+}
+
+/// Calls `found` with each wareki date in `input` and the text it was
+/// written as, reading a chunk at a time. Fails if the input isn't UTF-8.
+pub fn find_wareki_stream<R, F>(input: R, mut found: F) -> io::Result<()>
+where
+    R: Read,
+    F: FnMut(&str, NaiveDate),
+{
+    for_each_chunk(input, |text, eof| {
+        Ok(scan_matches(
+            text,
+            eof,
+            parse_wareki_prefix,
+            |text, date| {
+                if let Some(date) = date {
+                    found(text, date);
+                }
+            },
+        ))
+    })
+}
+
+/// The log timestamp formats `parse_log_timestamp_prefix` recognizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// The Common Log Format of Apache and nginx access logs, e.g.
+    /// 12/Nov/2021:15:04:05 +0900.
+    Clf,
+    /// e.g. 2021-11-12T15:04:05.123+09:00.
+    Rfc3339,
+    /// The BSD syslog format, e.g. Nov 12 15:04:05, which has no year.
+    Syslog,
+}
+
+/// A timestamp found in a log line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogTimestamp<'a> {
+    pub format: LogFormat,
+    /// The timestamp as written.
+    pub text: &'a str,
+    /// The date as written, in the timestamp's own offset.
+    pub date: NaiveDate,
+    /// The time of day as written, with its offset if it has one, e.g.
+    /// "15:04:05 +0900".
+    pub time: &'a str,
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Parses the log timestamp at the start of `s`, if any. Syslog timestamps
+/// are taken to be in `syslog_year`.
+pub fn parse_log_timestamp_prefix(s: &str, syslog_year: i32) -> Option<LogTimestamp<'_>> {
+    parse_clf(s)
+        .or_else(|| parse_rfc3339(s))
+        .or_else(|| parse_syslog(s, syslog_year))
+}
+
+fn parse_clf(s: &str) -> Option<LogTimestamp<'_>> {
+    let text = s.get(..26)?;
+    let datetime = DateTime::parse_from_str(text, "%d/%b/%Y:%H:%M:%S %z").ok()?;
+    Some(LogTimestamp {
+        format: LogFormat::Clf,
+        text,
+        date: datetime.date_naive(),
+        time: &text[12..],
+    })
+}
+
+fn parse_rfc3339(s: &str) -> Option<LogTimestamp<'_>> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20 || bytes[10] != b'T' {
+        return None;
+    }
+
+    let mut len = 19;
+    if bytes[len] == b'.' {
+        len += 1 + bytes[len + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+    }
+    len += match bytes.get(len)? {
+        b'Z' => 1,
+        b'+' | b'-' => 6,
+        _ => return None,
+    };
+
+    let text = s.get(..len)?;
+    let datetime = DateTime::parse_from_rfc3339(text).ok()?;
+    Some(LogTimestamp {
+        format: LogFormat::Rfc3339,
+        text,
+        date: datetime.date_naive(),
+        time: &text[11..],
+    })
+}
+
+fn parse_syslog(s: &str, year: i32) -> Option<LogTimestamp<'_>> {
+    let text = s.get(..15)?;
+    let month = MONTHS.iter().position(|&month| text.starts_with(month))? as u32 + 1;
+    // Days are padded with a space, e.g. "Nov  2".
+    let day = text
+        .get(3..6)?
+        .strip_prefix(' ')?
+        .trim_start()
+        .parse()
+        .ok()?;
+    let time = text.get(6..)?.strip_prefix(' ')?;
+    NaiveTime::parse_from_str(time, "%H:%M:%S").ok()?;
+
+    Some(LogTimestamp {
+        format: LogFormat::Syslog,
+        text,
+        date: NaiveDate::from_ymd_opt(year, month, day)?,
+        time,
+    })
+}
+
+/// Replaces every CLF, RFC 3339 and syslog timestamp in the text with its
+/// rendering, keeping the rest of each line byte for byte. Syslog timestamps
+/// are taken to be in `syslog_year`.
+pub fn rewrite_log_timestamps<F>(text: &str, syslog_year: i32, render: F) -> String
+where
+    F: FnMut(LogTimestamp) -> String,
+{
+    replace_matches(
+        text,
+        |s| parse_log_timestamp_prefix(s, syslog_year).map(|found| (found, found.text.len())),
+        render,
+    )
+}
+
+/// Like `rewrite_log_timestamps`, but reads the text from `input` and writes
+/// the result to `output` a chunk at a time. Fails if the input isn't UTF-8.
+pub fn rewrite_log_timestamps_stream<R, W, F>(
+    input: R,
+    mut output: W,
+    syslog_year: i32,
+    mut render: F,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(LogTimestamp) -> String,
+{
+    for_each_chunk(input, |text, eof| {
+        let mut result = Ok(());
+        let consumed = scan_matches(
+            text,
+            eof,
+            |s| parse_log_timestamp_prefix(s, syslog_year).map(|found| (found, found.text.len())),
+            |text, found| {
+                if result.is_ok() {
+                    result = match found {
+                        Some(found) => output.write_all(render(found).as_bytes()),
+                        None => output.write_all(text.as_bytes()),
+                    };
+                }
+            },
+        );
+        result.map(|_| consumed)
+    })?;
+
+    output.flush()
+}
+
+/// How many bytes to read at a time when streaming.
+const CHUNK_LEN: usize = 64 * 1024;
+
+/// How far from the end of a chunk a date may start and still be matched in
+/// it. Wareki dates are well under this, so none are missed at chunk
+/// boundaries.
+const MAX_MATCH_LEN: usize = 256;
+
+/// Calls `f` with the input decoded as UTF-8 a chunk at a time, along with
+/// whether it's the end of the input. `f` returns how many bytes of the chunk
+/// it's done with; the rest are passed to it again with the next chunk.
+pub(crate) fn for_each_chunk<R, F>(mut input: R, mut f: F) -> io::Result<()>
+where
+    R: Read,
+    F: FnMut(&str, bool) -> io::Result<usize>,
+{
+    let mut buf = Vec::with_capacity(CHUNK_LEN + MAX_MATCH_LEN);
+    loop {
+        let len = buf.len();
+        buf.resize(len + CHUNK_LEN, 0);
+        let read = loop {
+            match input.read(&mut buf[len..]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                read => break read?,
+            }
+        };
+        buf.truncate(len + read);
+        let eof = read == 0;
+
+        // A char may be split across reads; leave its start for the next one.
+        let valid = match std::str::from_utf8(&buf) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() && !eof => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let text = std::str::from_utf8(&buf[..valid]).unwrap();
+        let consumed = f(text, eof)?;
+        buf.drain(..consumed);
+
+        if eof {
+            return Ok(());
+        }
+    }
+}
+
+/// Scans a chunk for matches of `parse`, passing `emit` the text between
+/// them, and each match along with the text it was written as. Unless it's
+/// the end of the input, stops `MAX_MATCH_LEN` short of the end of the chunk,
+/// and returns how far it got.
+fn scan_matches<'a, T, P, F>(text: &'a str, eof: bool, parse: P, mut emit: F) -> usize
+where
+    P: Fn(&'a str) -> Option<(T, usize)>,
+    F: FnMut(&'a str, Option<T>),
+{
+    let stop = if eof {
+        text.len()
+    } else {
+        text.len().saturating_sub(MAX_MATCH_LEN)
+    };
+
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < stop {
+        match parse(&text[i..]) {
+            Some((found, len)) => {
+                emit(&text[text_start..i], None);
+                emit(&text[i..i + len], Some(found));
+                i += len;
+                text_start = i;
+            }
+            None => i += text[i..].chars().next().unwrap().len_utf8(),
+        }
+    }
+    emit(&text[text_start..i], None);
+
+    i
+}
+
+/// Replaces every match of `parse` in the text with its rendering. `parse`
+/// is tried at every char, and returns what it matched and the match length.
+pub(crate) fn replace_matches<'a, T, P, F>(text: &'a str, parse: P, mut render: F) -> String
+where
+    P: Fn(&'a str) -> Option<(T, usize)>,
+    F: FnMut(T) -> String,
+{
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        match parse(rest) {
+            Some((date, len)) => {
+                out.push_str(&render(date));
+                rest = &rest[len..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_wareki_to_gregorian() {
+        assert_eq!(
+            rewrite_wareki_to_gregorian(
+                "本契約は平成３１年４月１日から令和元年５月３１日まで有効とする。",
+                "%Y年%-m月%-d日"
+            ),
+            "本契約は2019年4月1日から2019年5月31日まで有効とする。"
+        );
+        assert_eq!(
+            rewrite_wareki_to_gregorian("Signed 昭和60年1月1日, amended 令和3年.", "%Y-%m-%d"),
+            "Signed 1985-01-01, amended 令和3年."
+        );
+    }
+
+    #[test]
+    fn test_rewrite_wareki_stream() {
+        // Put dates across every chunk boundary.
+        let line = "記録：令和3年11月12日、平成31年4月30日。\n";
+        let text = line.repeat(2 * CHUNK_LEN / line.len() + 1);
+        let mut out = Vec::new();
+        rewrite_wareki_stream(text.as_bytes(), &mut out, |date| date.to_string()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "記録：2021-11-12、2019-04-30。\n".repeat(2 * CHUNK_LEN / line.len() + 1)
+        );
+
+        let mut out = Vec::new();
+        let invalid = rewrite_wareki_stream(&b"\xff"[..], &mut out, |date| date.to_string());
+        assert_eq!(invalid.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_find_wareki_stream() {
+        let mut found = Vec::new();
+        find_wareki_stream(
+            "令和3年11月12日と令和元年5月1日".as_bytes(),
+            |text, date| found.push((text.to_owned(), date.to_string())),
+        )
+        .unwrap();
+        assert_eq!(
+            found,
+            vec![
+                ("令和3年11月12日".to_owned(), "2021-11-12".to_owned()),
+                ("令和元年5月1日".to_owned(), "2019-05-01".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_log_timestamp_prefix() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let clf = parse_log_timestamp_prefix("12/Nov/2021:15:04:05 +0900] \"GET /", 2000).unwrap();
+        assert_eq!(clf.format, LogFormat::Clf);
+        assert_eq!(clf.text, "12/Nov/2021:15:04:05 +0900");
+        assert_eq!(clf.date, ymd(2021, 11, 12));
+        assert_eq!(clf.time, "15:04:05 +0900");
+
+        // The date is the one written, not the UTC one.
+        let rfc3339 =
+            parse_log_timestamp_prefix("2019-05-01T08:00:00.123+09:00 INFO", 2000).unwrap();
+        assert_eq!(rfc3339.text, "2019-05-01T08:00:00.123+09:00");
+        assert_eq!(rfc3339.date, ymd(2019, 5, 1));
+        assert_eq!(rfc3339.time, "08:00:00.123+09:00");
+        assert_eq!(
+            parse_log_timestamp_prefix("2021-11-12T15:04:05Z", 2000).map(|found| found.text),
+            Some("2021-11-12T15:04:05Z")
+        );
+
+        let syslog = parse_log_timestamp_prefix("Nov  2 15:04:05 host sshd", 2021).unwrap();
+        assert_eq!(syslog.format, LogFormat::Syslog);
+        assert_eq!(syslog.text, "Nov  2 15:04:05");
+        assert_eq!(syslog.date, ymd(2021, 11, 2));
+        assert_eq!(syslog.time, "15:04:05");
+
+        assert_eq!(
+            parse_log_timestamp_prefix("2021-11-12 15:04:05", 2021),
+            None
+        );
+        assert_eq!(
+            parse_log_timestamp_prefix("31/Feb/2021:15:04:05 +0900", 2021),
+            None
+        );
+        assert_eq!(parse_log_timestamp_prefix("Nov 31 15:04:05", 2021), None);
+    }
+
+    #[test]
+    fn test_rewrite_log_timestamps() {
+        let render =
+            |found: LogTimestamp| format!("{} {}", found.date.format("%Y年%-m月%-d日"), found.time);
+        assert_eq!(
+            rewrite_log_timestamps(
+                "127.0.0.1 - - [12/Nov/2021:15:04:05 +0900] \"GET / HTTP/1.1\" 200 612\n\
+                Nov 12 15:04:05 host sshd[42]: Accepted\n",
+                2021,
+                render
+            ),
+            "127.0.0.1 - - [2021年11月12日 15:04:05 +0900] \"GET / HTTP/1.1\" 200 612\n\
+            2021年11月12日 15:04:05 host sshd[42]: Accepted\n"
+        );
+
+        let line = "2021-11-12T15:04:05Z level=info msg=\"令和3年11月12日\"\n";
+        let text = line.repeat(2 * CHUNK_LEN / line.len() + 1);
+        let mut out = Vec::new();
+        rewrite_log_timestamps_stream(text.as_bytes(), &mut out, 2021, |found| {
+            format!("{} ({})", found.text, found.date)
+        })
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2021-11-12T15:04:05Z (2021-11-12) level=info msg=\"令和3年11月12日\"\n"
+                .repeat(2 * CHUNK_LEN / line.len() + 1)
+        );
+    }
+
+    #[test]
+    fn test_rewrite_wareki_in_markdown() {
+        let iso = |date: NaiveDate| date.format("%Y-%m-%d").to_string();
+        let markdown = "令和3年11月12日に[公開](https://example.com/令和3年11月12日)。\n\
+            `令和3年11月12日` と https://example.com/?d=令和3年11月12日 <b>令和3年11月12日</b>\n\
+            ```\n令和3年11月12日\n```\n\
+            令和3年11月13日\n";
+        assert_eq!(
+            rewrite_wareki_in(markdown, Markup::Markdown, iso),
+            "2021-11-12に[公開](https://example.com/令和3年11月12日)。\n\
+            `令和3年11月12日` と https://example.com/?d=令和3年11月12日 <b>2021-11-12</b>\n\
+            ```\n令和3年11月12日\n```\n\
+            2021-11-13\n"
+        );
+        assert_eq!(
+            rewrite_wareki_in("`令和3年11月12日", Markup::Markdown, iso),
+            "`2021-11-12"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_wareki_in_html() {
+        let iso = |date: NaiveDate| date.format("%Y-%m-%d").to_string();
+        let html = "<p title=\"令和3年11月12日\">令和3年11月12日</p>\
+            <!-- 令和3年11月12日 --><CODE>令和3年11月12日</CODE>\
+            <script>let d = \"令和3年11月12日\";</script>令和3年11月13日";
+        assert_eq!(
+            rewrite_wareki_in(html, Markup::Html, iso),
+            "<p title=\"令和3年11月12日\">2021-11-12</p>\
+            <!-- 令和3年11月12日 --><CODE>令和3年11月12日</CODE>\
+            <script>let d = \"令和3年11月12日\";</script>2021-11-13"
+        );
+        assert_eq!(
+            rewrite_wareki_in("<p>令和3年11月12日</p>", Markup::Plain, iso),
+            "<p>2021-11-12</p>"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::rewrite::replace_matches;
+use nihonify_core::parse::parse_wareki_period_prefix;
+use std::cmp::Ordering;
+
+/// The key that `cmp_wareki` orders strings by: the string with every wareki
+/// date or period in it, e.g. 昭和60年 or 令和3年11月12日, replaced by the
+/// ISO date it starts on.
+pub fn wareki_sort_key(s: &str) -> String {
+    replace_matches(s, parse_wareki_period_prefix, |date| {
+        date.format("%Y-%m-%d").to_string()
+    })
+}
+
+/// Orders strings chronologically by the wareki dates in them, e.g.
+/// 「昭和60年度報告」 before 「平成2年度報告」, and otherwise as strings.
+///
+/// For sorting many strings, `sort_by_cached_key` with `wareki_sort_key` is
+/// cheaper.
+pub fn cmp_wareki(a: &str, b: &str) -> Ordering {
+    wareki_sort_key(a)
+        .cmp(&wareki_sort_key(b))
+        .then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmp_wareki() {
+        let mut names = vec![
+            "平成2年度報告.xlsx",
+            "令和元年5月議事録.docx",
+            "昭和60年度報告.xlsx",
+            "平成31年4月議事録.docx",
+        ];
+        names.sort_by(|a, b| cmp_wareki(a, b));
+        assert_eq!(
+            names,
+            vec![
+                "昭和60年度報告.xlsx",
+                "平成2年度報告.xlsx",
+                "平成31年4月議事録.docx",
+                "令和元年5月議事録.docx",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wareki_sort_key() {
+        assert_eq!(wareki_sort_key("昭和60年度報告"), "1985-01-01度報告");
+        assert_eq!(wareki_sort_key("no dates"), "no dates");
+    }
+}
@@ -0,0 +1,707 @@
+/// A rudimentary way to detect Japanese-language strings.
+/// Note: O(n) on the length of the string.
+/// Note: Short-circuit returns true on any Japanese grapheme.
+/// Note: Does not handle mixed-language strings well; see `jp_spans`.
+/// Note: Only kana count, so kanji-only strings are false; see `is_jp_with`.
+pub fn is_jp(s: &str) -> bool {
+    for c in s.chars() {
+        match c as u32 {
+            // Hiragana graphemes
+            0x3040..=0x309F => return true,
+            // Katakana graphemes
+            0x30A0..=0x30FF => return true,
+            // Katakana phonetic extension graphemes
+            0x31F0..=0x31FF => return true,
+            _ => (),
+        }
+    }
+
+    false
+}
+
+/// A set of the scripts Japanese is written in, for `is_jp_with`. Combine
+/// them with `|`, e.g. `Scripts::KANA | Scripts::KANJI`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Scripts(u8);
+
+impl Scripts {
+    pub const HIRAGANA: Scripts = Scripts(0b001);
+    /// Katakana, including the phonetic extensions and the half-width forms.
+    pub const KATAKANA: Scripts = Scripts(0b010);
+    pub const KANA: Scripts = Scripts(0b011);
+    /// Kanji, which Chinese text is also written in.
+    pub const KANJI: Scripts = Scripts(0b100);
+    pub const ALL: Scripts = Scripts(0b111);
+
+    /// Whether all the scripts in `other` are in the set.
+    pub fn contains(self, other: Scripts) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether the char is in one of the scripts in the set.
+    pub fn matches(self, c: char) -> bool {
+        (self.contains(Scripts::HIRAGANA) && is_hiragana(c))
+            || (self.contains(Scripts::KATAKANA) && is_katakana(c))
+            || (self.contains(Scripts::KANJI) && is_kanji(c))
+    }
+}
+
+impl core::ops::BitOr for Scripts {
+    type Output = Scripts;
+
+    fn bitor(self, other: Scripts) -> Scripts {
+        Scripts(self.0 | other.0)
+    }
+}
+
+/// Like `is_jp`, but true on any char in the given scripts. Counting kanji
+/// catches kanji-only Japanese like 日本国憲法, but also Chinese; use
+/// `classify_cjk` to tell them apart.
+pub fn is_jp_with(s: &str, scripts: Scripts) -> bool {
+    s.chars().any(|c| scripts.matches(c))
+}
+
+/// Whether the char is in the hiragana block.
+pub fn is_hiragana(c: char) -> bool {
+    matches!(c as u32, 0x3040..=0x309F)
+}
+
+/// Whether the char is katakana, including the phonetic extensions and the
+/// half-width forms.
+pub fn is_katakana(c: char) -> bool {
+    match c as u32 {
+        // Katakana graphemes
+        0x30A0..=0x30FF => true,
+        // Katakana phonetic extension graphemes
+        0x31F0..=0x31FF => true,
+        // Half-width katakana graphemes
+        0xFF66..=0xFF9F => true,
+        _ => false,
+    }
+}
+
+/// Whether the char is a CJK ideograph, or the 々 iteration mark.
+pub fn is_kanji(c: char) -> bool {
+    match c as u32 {
+        // The 々 iteration mark
+        0x3005 => true,
+        // CJK unified ideographs extension A
+        0x3400..=0x4DBF => true,
+        // CJK unified ideographs
+        0x4E00..=0x9FFF => true,
+        // CJK compatibility ideographs
+        0xF900..=0xFAFF => true,
+        // CJK unified ideographs extensions B and later, and the compatibility
+        // ideographs supplement
+        0x20000..=0x3134F => true,
+        _ => false,
+    }
+}
+
+/// Whether the char is a full-width form, e.g. Ａ, １ or ！, or the ideographic
+/// space.
+pub fn is_fullwidth(c: char) -> bool {
+    match c as u32 {
+        // Ideographic space
+        0x3000 => true,
+        // Full-width ASCII variants
+        0xFF01..=0xFF60 => true,
+        // Full-width signs, e.g. ￥
+        0xFFE0..=0xFFE6 => true,
+        _ => false,
+    }
+}
+
+/// The kind of char, as `classify_char` sorts them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    Hiragana,
+    /// Katakana, including the prolonged sound mark ー and the half-width
+    /// forms.
+    Katakana,
+    /// Kanji, including the 々 iteration mark.
+    Kanji,
+    /// Latin letters, including accented ones like ō.
+    Latin,
+    /// Full-width Latin letters, e.g. Ａ.
+    FullWidthLatin,
+    /// ASCII or full-width digits.
+    Digit,
+    /// ASCII, Japanese, full-width and half-width punctuation and symbols,
+    /// e.g. ! 。 「 ！ ｢.
+    Punctuation,
+    /// Everything else, including whitespace.
+    Other,
+}
+
+/// Which kind of char it is.
+pub fn classify_char(c: char) -> Script {
+    // The ASCII char a full-width form stands for.
+    let ascii = match c as u32 {
+        0xFF01..=0xFF5E => core::char::from_u32(c as u32 - 0xFEE0).unwrap(),
+        _ => c,
+    };
+    match c {
+        c if is_hiragana(c) => Script::Hiragana,
+        c if is_katakana(c) => Script::Katakana,
+        c if is_kanji(c) => Script::Kanji,
+        '0'..='9' | '０'..='９' => Script::Digit,
+        'Ａ'..='Ｚ' | 'ａ'..='ｚ' => Script::FullWidthLatin,
+        c if c.is_alphabetic() && matches!(c as u32, 0x0000..=0x024F | 0x1E00..=0x1EFF) => {
+            Script::Latin
+        }
+        _ if ascii.is_ascii_punctuation() => Script::Punctuation,
+        // CJK punctuation, the half-width forms of 。「」、・, general
+        // punctuation like … and full-width signs like ￥
+        '\u{3001}'..='\u{303F}' | '\u{FF5F}'..='\u{FF65}' | '\u{2010}'..='\u{205E}' => {
+            Script::Punctuation
+        }
+        '\u{FFE0}'..='\u{FFE6}' => Script::Punctuation,
+        _ => Script::Other,
+    }
+}
+
+/// Each char of the string with its kind.
+pub fn classify_chars(s: &str) -> impl Iterator<Item = (char, Script)> + '_ {
+    s.chars().map(|c| (c, classify_char(c)))
+}
+
+/// The runs of chars of the same kind that make up the string, e.g. 令和,
+/// 3 and 年 for 令和3年.
+pub fn script_runs(s: &str) -> impl Iterator<Item = (Script, &str)> + '_ {
+    let mut rest = s;
+    core::iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        let script = classify_char(chars.next()?.1);
+        let end = chars
+            .find(|&(_, c)| classify_char(c) != script)
+            .map_or(rest.len(), |(i, _)| i);
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+        Some((script, run))
+    })
+}
+
+/// The runs of Japanese text within a mixed-language string, as byte ranges
+/// and the text in them, e.g. 東京タワー and 楽しかった！ in "I went to
+/// 東京タワー (Tokyo Tower), 楽しかった！". A run is kana and kanji, along
+/// with the digits, full-width letters and Japanese punctuation among them,
+/// e.g. 「令和3年」; runs of those without any kana or kanji are skipped.
+pub fn jp_spans(s: &str) -> impl Iterator<Item = (core::ops::Range<usize>, &str)> + '_ {
+    let in_span = |c: char| match classify_char(c) {
+        Script::Hiragana | Script::Katakana | Script::Kanji => true,
+        Script::Digit | Script::FullWidthLatin => true,
+        Script::Punctuation => !c.is_ascii(),
+        Script::Latin | Script::Other => false,
+    };
+    let mut start = 0;
+    core::iter::from_fn(move || loop {
+        let rest = &s[start..];
+        let from = start + rest.find(in_span)?;
+        let to = s[from..]
+            .find(|c| !in_span(c))
+            .map_or(s.len(), |i| from + i);
+        start = to;
+        let span = &s[from..to];
+        if span
+            .chars()
+            .any(|c| is_hiragana(c) || is_katakana(c) || is_kanji(c))
+        {
+            return Some((from..to, span));
+        }
+    })
+}
+
+/// The number of chars of each kind in a string, as `classify_char` sorts
+/// them. Whitespace isn't counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStats {
+    pub hiragana: usize,
+    pub katakana: usize,
+    pub kanji: usize,
+    pub latin: usize,
+    pub fullwidth_latin: usize,
+    pub digit: usize,
+    pub punctuation: usize,
+    pub other: usize,
+}
+
+impl TextStats {
+    /// The number of chars of the kind.
+    pub fn count(&self, script: Script) -> usize {
+        match script {
+            Script::Hiragana => self.hiragana,
+            Script::Katakana => self.katakana,
+            Script::Kanji => self.kanji,
+            Script::Latin => self.latin,
+            Script::FullWidthLatin => self.fullwidth_latin,
+            Script::Digit => self.digit,
+            Script::Punctuation => self.punctuation,
+            Script::Other => self.other,
+        }
+    }
+
+    /// The number of kana and kanji.
+    pub fn japanese(&self) -> usize {
+        self.hiragana + self.katakana + self.kanji
+    }
+
+    /// The share of the letters that are kana or kanji, from 0 to 1. Digits
+    /// and punctuation are left out, as any language uses them; 0 if there
+    /// are no letters.
+    pub fn jp_ratio(&self) -> f32 {
+        let letters = self.japanese() + self.latin + self.fullwidth_latin + self.other;
+        if letters == 0 {
+            return 0.0;
+        }
+        self.japanese() as f32 / letters as f32
+    }
+}
+
+/// Counts the chars of the string by kind.
+pub fn text_stats(s: &str) -> TextStats {
+    let mut stats = TextStats::default();
+    for (c, script) in classify_chars(s) {
+        if c.is_whitespace() {
+            continue;
+        }
+        let count = match script {
+            Script::Hiragana => &mut stats.hiragana,
+            Script::Katakana => &mut stats.katakana,
+            Script::Kanji => &mut stats.kanji,
+            Script::Latin => &mut stats.latin,
+            Script::FullWidthLatin => &mut stats.fullwidth_latin,
+            Script::Digit => &mut stats.digit,
+            Script::Punctuation => &mut stats.punctuation,
+            Script::Other => &mut stats.other,
+        };
+        *count += 1;
+    }
+
+    stats
+}
+
+/// The share of the string's letters that are kana or kanji, from 0 to 1,
+/// for routing mixed-language text. See `TextStats::jp_ratio`.
+pub fn jp_ratio(s: &str) -> f32 {
+    text_stats(s).jp_ratio()
+}
+
+/// The number of hiragana chars in the string.
+pub fn count_hiragana(s: &str) -> usize {
+    s.chars().filter(|&c| is_hiragana(c)).count()
+}
+
+/// The number of katakana chars in the string.
+pub fn count_katakana(s: &str) -> usize {
+    s.chars().filter(|&c| is_katakana(c)).count()
+}
+
+/// The number of kanji chars in the string.
+pub fn count_kanji(s: &str) -> usize {
+    s.chars().filter(|&c| is_kanji(c)).count()
+}
+
+/// The number of full-width chars in the string.
+pub fn count_fullwidth(s: &str) -> usize {
+    s.chars().filter(|&c| is_fullwidth(c)).count()
+}
+
+/// The number of chars in each of the Unicode blocks relevant to Japanese
+/// text, as counted by `script_report`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptReport {
+    /// Hiragana (U+3040–U+309F)
+    pub hiragana: usize,
+    /// Katakana and its phonetic extensions (U+30A0–U+30FF, U+31F0–U+31FF)
+    pub katakana: usize,
+    /// CJK unified and compatibility ideographs, including the extensions
+    pub cjk_ideographs: usize,
+    /// Halfwidth forms, e.g. ｱ (U+FF61–U+FFDC, U+FFE8–U+FFEE)
+    pub halfwidth_forms: usize,
+    /// Fullwidth forms, e.g. Ａ (U+FF01–U+FF60, U+FFE0–U+FFE6)
+    pub fullwidth_forms: usize,
+    /// CJK symbols and punctuation, e.g. 。 and 「 (U+3000–U+303F)
+    pub jp_punctuation: usize,
+    /// Basic Latin, Latin-1 and the Latin extensions
+    pub latin: usize,
+    /// Everything else.
+    pub other: usize,
+}
+
+impl core::ops::AddAssign for ScriptReport {
+    fn add_assign(&mut self, other: ScriptReport) {
+        self.hiragana += other.hiragana;
+        self.katakana += other.katakana;
+        self.cjk_ideographs += other.cjk_ideographs;
+        self.halfwidth_forms += other.halfwidth_forms;
+        self.fullwidth_forms += other.fullwidth_forms;
+        self.jp_punctuation += other.jp_punctuation;
+        self.latin += other.latin;
+        self.other += other.other;
+    }
+}
+
+/// Counts the chars of the string per Unicode block.
+pub fn script_report(s: &str) -> ScriptReport {
+    let mut report = ScriptReport::default();
+    for c in s.chars() {
+        let count = match c as u32 {
+            0x3040..=0x309F => &mut report.hiragana,
+            0x30A0..=0x30FF | 0x31F0..=0x31FF => &mut report.katakana,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x3134F => {
+                &mut report.cjk_ideographs
+            }
+            0xFF61..=0xFFDC | 0xFFE8..=0xFFEE => &mut report.halfwidth_forms,
+            0xFF01..=0xFF60 | 0xFFE0..=0xFFE6 => &mut report.fullwidth_forms,
+            0x3000..=0x303F => &mut report.jp_punctuation,
+            0x0000..=0x024F | 0x1E00..=0x1EFF => &mut report.latin,
+            _ => &mut report.other,
+        };
+        *count += 1;
+    }
+
+    report
+}
+
+/// The number of terminal columns the char takes up, going by its East Asian
+/// Width: 2 for wide and full-width chars like あ, 漢 and Ａ, 0 for control
+/// chars and combining marks, and 1 otherwise. Ambiguous-width chars like ○
+/// count as 1, as they do in most terminals outside CJK locales.
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        // Control chars
+        0x0000..=0x001F | 0x007F..=0x009F => 0,
+        // Combining diacritics, zero-width spaces and joiners, the combining
+        // (han)dakuten and variation selectors
+        0x0300..=0x036F | 0x200B..=0x200F | 0x3099..=0x309A | 0xFE00..=0xFE0F => 0,
+        // Hangul jamo
+        0x1100..=0x115F => 2,
+        // CJK radicals through CJK compatibility, including kana, CJK
+        // punctuation and the ideographic space
+        0x2E80..=0x303E | 0x3041..=0x33FF => 2,
+        // CJK unified ideographs, extension A, and Yi
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xA000..=0xA4CF => 2,
+        // Hangul syllables
+        0xAC00..=0xD7A3 => 2,
+        // CJK compatibility ideographs, and vertical and small form variants
+        0xF900..=0xFAFF | 0xFE10..=0xFE19 | 0xFE30..=0xFE6F => 2,
+        // Full-width forms
+        0xFF00..=0xFF60 | 0xFFE0..=0xFFE6 => 2,
+        // Emoji
+        0x1F300..=0x1F64F | 0x1F900..=0x1F9FF => 2,
+        // CJK unified ideographs extensions B and later
+        0x20000..=0x2FFFD | 0x30000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// The number of terminal columns the string takes up. Unlike `len()`, this
+/// lines up with what's displayed, e.g. 7 for 令和3年 rather than 10.
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Pads the string with spaces on the right up to the given display width.
+pub fn pad_end(s: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    format!("{}{}", s, " ".repeat(padding))
+}
+
+/// Pads the string with spaces on the left up to the given display width.
+pub fn pad_start(s: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    format!("{}{}", " ".repeat(padding), s)
+}
+
+/// The longest prefix of the string that fits in the given display width,
+/// without splitting a char.
+pub fn truncate_to_width(s: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (i, c) in s.char_indices() {
+        used += char_width(c);
+        if used > width {
+            return &s[..i];
+        }
+    }
+
+    s
+}
+
+/// Like `script_report`, but reads the text from `input` a chunk at a time, so
+/// inputs of any size are counted in bounded memory. Fails if the input isn't
+/// UTF-8.
+pub fn script_report_stream<R: std::io::Read>(input: R) -> std::io::Result<ScriptReport> {
+    let mut report = ScriptReport::default();
+    crate::rewrite::for_each_chunk(input, |text, _| {
+        report += script_report(text);
+        Ok(text.len())
+    })?;
+
+    Ok(report)
+}
+
+/// A language that CJK text can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CjkLanguage {
+    Japanese,
+    Chinese,
+    Korean,
+}
+
+/// The outcome of `classify_cjk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CjkClassification {
+    pub language: CjkLanguage,
+    /// The share of the evidence that points to `language`, from 0 to 1.
+    pub confidence: f32,
+}
+
+/// Kanji that are only written this way in Japanese: kokuji (kanji made in
+/// Japan) and shinjitai that differ from both simplified and traditional
+/// Chinese.
+const JP_ONLY_KANJI: &str = "畑峠込匂枠働凪凧榊躾辻笹栃雫鰯搾塀噺麿俣\
+    気駅広売読図楽発県鉄実歳戦続関経総験沢円払単仏桜帰変対処転薬労営絵";
+
+/// Chars that are only used in Chinese: simplified forms, traditional forms
+/// that Japanese replaced, and particles.
+const CN_ONLY_CHARS: &str =
+    "这们说个么为对东车马门长书见还过进觉问话让从爱发经习头买卖认识谁样开关电网语\
+    這們說麼對國體學會來傳灣裡吗呢嗎";
+
+/// Guesses whether CJK text is Japanese, Chinese or Korean from kana,
+/// hangul, language-specific kanji and punctuation conventions. Returns
+/// `None` if the text has none of those signals or kanji.
+///
+/// Note: kanji-only text with no distinguishing chars, like 日本国憲法, is
+/// reported as Japanese with a confidence of 0.5; treat low confidences as
+/// unknown.
+pub fn classify_cjk(s: &str) -> Option<CjkClassification> {
+    let (mut jp, mut cn, mut ko) = (0.0f32, 0.0f32, 0.0f32);
+    for c in s.chars() {
+        match c {
+            c if is_hiragana(c) || is_katakana(c) => jp += 3.0,
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+                ko += 3.0
+            }
+            c if JP_ONLY_KANJI.contains(c) => jp += 2.0,
+            c if CN_ONLY_CHARS.contains(c) => cn += 2.0,
+            // Other kanji could be either.
+            c if is_kanji(c) => {
+                jp += 0.5;
+                cn += 0.5;
+            }
+            // Japanese separates clauses with 、 where Chinese uses ，.
+            '、' => jp += 1.0,
+            '，' => cn += 1.0,
+            _ => (),
+        }
+    }
+
+    let total = jp + cn + ko;
+    if total == 0.0 {
+        return None;
+    }
+
+    let (language, score) = if jp >= cn && jp >= ko {
+        (CjkLanguage::Japanese, jp)
+    } else if cn >= ko {
+        (CjkLanguage::Chinese, cn)
+    } else {
+        (CjkLanguage::Korean, ko)
+    };
+
+    Some(CjkClassification {
+        language,
+        confidence: score / total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_jp() {
+        assert!(!is_jp("testing 123 Hello, world!"));
+        assert!(is_jp("日本語の文です。"));
+    }
+
+    #[test]
+    fn test_is_jp_with() {
+        assert!(!is_jp("日本国憲法"));
+        assert!(is_jp_with("日本国憲法", Scripts::KANA | Scripts::KANJI));
+        assert!(!is_jp_with("日本国憲法", Scripts::KANA));
+        assert!(is_jp_with("ｶﾀｶﾅ", Scripts::KATAKANA));
+        assert!(!is_jp_with("ひらがな", Scripts::KATAKANA));
+        assert!(!is_jp_with("testing 123", Scripts::ALL));
+        assert!(Scripts::ALL.contains(Scripts::KANA));
+        assert!(!Scripts::KANA.contains(Scripts::KANJI));
+        assert_eq!(Scripts::HIRAGANA | Scripts::KATAKANA, Scripts::KANA);
+    }
+
+    #[test]
+    fn test_char_predicates() {
+        assert!(is_hiragana('あ'));
+        assert!(!is_hiragana('ア'));
+        assert!(is_katakana('ア'));
+        assert!(is_katakana('ｱ'));
+        assert!(!is_katakana('あ'));
+        assert!(is_kanji('日'));
+        assert!(is_kanji('々'));
+        assert!(!is_kanji('あ'));
+        assert!(is_fullwidth('Ａ'));
+        assert!(is_fullwidth('１'));
+        assert!(!is_fullwidth('A'));
+    }
+
+    #[test]
+    fn test_classify_char() {
+        assert_eq!(classify_char('あ'), Script::Hiragana);
+        assert_eq!(classify_char('ー'), Script::Katakana);
+        assert_eq!(classify_char('ｱ'), Script::Katakana);
+        assert_eq!(classify_char('々'), Script::Kanji);
+        assert_eq!(classify_char('ō'), Script::Latin);
+        assert_eq!(classify_char('Ａ'), Script::FullWidthLatin);
+        assert_eq!(classify_char('3'), Script::Digit);
+        assert_eq!(classify_char('３'), Script::Digit);
+        assert_eq!(classify_char('!'), Script::Punctuation);
+        assert_eq!(classify_char('！'), Script::Punctuation);
+        assert_eq!(classify_char('。'), Script::Punctuation);
+        assert_eq!(classify_char('｢'), Script::Punctuation);
+        assert_eq!(classify_char('…'), Script::Punctuation);
+        assert_eq!(classify_char('　'), Script::Other);
+        assert_eq!(classify_char('한'), Script::Other);
+    }
+
+    #[test]
+    fn test_script_runs() {
+        let runs: Vec<_> = script_runs("令和3年はReiwaです。").collect();
+        assert_eq!(
+            runs,
+            [
+                (Script::Kanji, "令和"),
+                (Script::Digit, "3"),
+                (Script::Kanji, "年"),
+                (Script::Hiragana, "は"),
+                (Script::Latin, "Reiwa"),
+                (Script::Hiragana, "です"),
+                (Script::Punctuation, "。"),
+            ]
+        );
+        assert_eq!(script_runs("").count(), 0);
+        assert_eq!(
+            classify_chars("ｶ1").collect::<Vec<_>>(),
+            [('ｶ', Script::Katakana), ('1', Script::Digit)]
+        );
+    }
+
+    #[test]
+    fn test_text_stats() {
+        let stats = text_stats("東京タワーは333m, Tokyo Towerです。");
+        assert_eq!(stats.kanji, 2);
+        assert_eq!(stats.katakana, 3);
+        assert_eq!(stats.hiragana, 3);
+        assert_eq!(stats.latin, 11);
+        assert_eq!(stats.count(Script::Digit), 3);
+        assert_eq!(stats.punctuation, 2);
+        assert_eq!(stats.other, 0);
+        assert_eq!(stats.japanese(), 8);
+    }
+
+    #[test]
+    fn test_jp_ratio() {
+        assert_eq!(jp_ratio("日本国憲法"), 1.0);
+        assert_eq!(jp_ratio("Hello, world!"), 0.0);
+        assert_eq!(jp_ratio("東京 Tokyo"), 2.0 / 7.0);
+        assert_eq!(jp_ratio("123。"), 0.0);
+    }
+
+    #[test]
+    fn test_jp_spans() {
+        let s = "I went to 東京タワー (Tokyo Tower) on 2021年11月12日, 楽しかった！ 123 ！";
+        let spans: Vec<_> = jp_spans(s).collect();
+        assert_eq!(
+            spans.iter().map(|&(_, span)| span).collect::<Vec<_>>(),
+            ["東京タワー", "2021年11月12日", "楽しかった！"]
+        );
+        assert_eq!(spans[0].0, 10..25);
+        assert_eq!(&s[spans[0].0.clone()], "東京タワー");
+        assert_eq!(
+            jp_spans("「令和3年」")
+                .map(|(_, span)| span)
+                .collect::<Vec<_>>(),
+            ["「令和3年」"]
+        );
+        assert_eq!(jp_spans("Hello, world!").count(), 0);
+    }
+
+    #[test]
+    fn test_counters() {
+        let s = "東京タワーは３３３ｍです。";
+        assert_eq!(count_hiragana(s), 3);
+        assert_eq!(count_katakana(s), 3);
+        assert_eq!(count_kanji(s), 2);
+        assert_eq!(count_fullwidth(s), 4);
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("令和3年"), 7);
+        assert_eq!(display_width("Reiwa 3"), 7);
+        assert_eq!(display_width("ｶﾀｶﾅ"), 4);
+        assert_eq!(display_width("ＡＢ"), 4);
+        assert_eq!(display_width("か\u{3099}"), 2);
+        assert_eq!(char_width('\t'), 0);
+    }
+
+    #[test]
+    fn test_padding_and_truncation() {
+        assert_eq!(pad_end("令和", 6), "令和  ");
+        assert_eq!(pad_start("令和", 6), "  令和");
+        assert_eq!(pad_end("令和3年", 3), "令和3年");
+        assert_eq!(truncate_to_width("令和3年", 5), "令和3");
+        assert_eq!(truncate_to_width("令和3年", 3), "令");
+        assert_eq!(truncate_to_width("令和", 10), "令和");
+    }
+
+    #[test]
+    fn test_script_report_stream() {
+        let text = "「東京」はTokyoで、ｶﾀｶﾅ！".repeat(10_000);
+        assert_eq!(
+            script_report_stream(text.as_bytes()).unwrap(),
+            script_report(&text)
+        );
+    }
+
+    #[test]
+    fn test_classify_cjk() {
+        let language = |s| classify_cjk(s).unwrap().language;
+        assert_eq!(language("日本語の文です。"), CjkLanguage::Japanese);
+        assert_eq!(language("東京駅"), CjkLanguage::Japanese);
+        assert_eq!(language("这是中文，我们说汉语。"), CjkLanguage::Chinese);
+        assert_eq!(language("這是台灣。"), CjkLanguage::Chinese);
+        assert_eq!(language("한국어입니다"), CjkLanguage::Korean);
+
+        assert_eq!(classify_cjk("東京駅").unwrap().confidence, 0.75);
+        assert_eq!(classify_cjk("日本国憲法").unwrap().confidence, 0.5);
+        assert_eq!(classify_cjk("Hello, world!"), None);
+    }
+
+    #[test]
+    fn test_script_report() {
+        assert_eq!(
+            script_report("「東京」はTokyoで、ｶﾀｶﾅ！"),
+            ScriptReport {
+                hiragana: 2,
+                katakana: 0,
+                cjk_ideographs: 2,
+                halfwidth_forms: 4,
+                fullwidth_forms: 1,
+                jp_punctuation: 3,
+                latin: 5,
+                other: 0,
+            }
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Bindings for calling the main conversions from JavaScript, with the `wasm`
+//! feature, through `wasm-bindgen`. Build the module and its JavaScript glue
+//! with
+//!
+//! ```text
+//! cargo rustc --release --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/nihonify.wasm
+//! ```
+//!
+//! Times are milliseconds since the Unix epoch, as from `Date.getTime()`.
+//! Functions with no result for their input, e.g. a time before Taika, return
+//! `undefined`.
+//!
+//! ```text
+//! import init, { nenkouString } from "./pkg/nihonify.js";
+//! await init();
+//! const text = nenkouString(Date.now());
+//! ```
+
+use chrono::prelude::*;
+use nihonify_core::Era;
+use wasm_bindgen::prelude::*;
+
+/// The kanji name of the era at the time, e.g. 令和.
+#[wasm_bindgen(js_name = eraKanji)]
+pub fn era_kanji(millis: f64) -> Option<String> {
+    let era = datetime(millis).and_then(Era::from_datetime)?;
+    era.kanji.map(String::from)
+}
+
+/// The time as a nenkou datestring, e.g. 令和３年１１月１２日.
+#[wasm_bindgen(js_name = nenkouString)]
+pub fn nenkou_string(millis: f64) -> Option<String> {
+    datetime(millis).and_then(Era::to_jp_nenkou_string)
+}
+
+/// Parses a wareki date, e.g. 令和3年11月12日, into milliseconds since the
+/// epoch.
+#[wasm_bindgen(js_name = parseWareki)]
+pub fn parse_wareki(s: &str) -> Option<f64> {
+    let datetime = Era::parse_wareki(s).ok()?;
+    Some(datetime.timestamp_millis() as f64)
+}
+
+/// Whether the string has Japanese in it, as `is_jp`.
+#[wasm_bindgen(js_name = isJp)]
+pub fn is_jp(s: &str) -> bool {
+    crate::is_jp(s)
+}
+
+fn datetime(millis: f64) -> Option<DateTime<Utc>> {
+    if !millis.is_finite() {
+        return None;
+    }
+    DateTime::from_timestamp_millis(millis as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exports() {
+        // 2021-11-12T00:00:00Z
+        let millis = 1_636_675_200_000.0;
+        assert_eq!(era_kanji(millis).unwrap(), "令和");
+        assert_eq!(nenkou_string(millis).unwrap(), "令和３年１１月１２日");
+        assert_eq!(era_kanji(f64::NAN), None);
+        assert_eq!(nenkou_string(-1e15), None);
+
+        assert_eq!(parse_wareki("令和3年11月12日"), Some(millis));
+        assert_eq!(parse_wareki("x"), None);
+        assert!(is_jp("ひらがな"));
+        assert!(!is_jp("latin"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Conversions between full-width (zenkaku) and half-width (hankaku) forms,
+//! which Japanese input mixes freely: ＡＢＣ１２３ for ABC123, ｶﾀｶﾅ for カタカナ.
+
+/// How far the full-width ASCII variants are from ASCII.
+const FULL_WIDTH_SHIFT: u32 = 0xFEE0;
+
+/// The full-width forms of the half-width katakana and punctuation, from
+/// U+FF61 ｡ to U+FF9F ﾟ.
+const HALF_WIDTH_FORMS: &str =
+    "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテト\
+    ナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// Katakana with the dakuten, and the same without.
+const VOICED: &str = "ガギグゲゴザジズゼゾダヂヅデドバビブベボヴヷヺ";
+const UNVOICED: &str = "カキクケコサシスセソタチツテトハヒフヘホウワヲ";
+/// Katakana with the handakuten, and the same without.
+const SEMI_VOICED: &str = "パピプペポ";
+const UNVOICED_H: &str = "ハヒフヘホ";
+
+/// Converts full-width ASCII letters, digits and punctuation, and the
+/// ideographic space, to ASCII, e.g. ＡＢＣ１２３！ to ABC123!.
+pub fn to_halfwidth_ascii(s: &str) -> String {
+    s.chars()
+        .map(|c| match c as u32 {
+            0xFF01..=0xFF5E => core::char::from_u32(c as u32 - FULL_WIDTH_SHIFT).unwrap(),
+            0x3000 => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts printable ASCII and the space to their full-width forms, e.g.
+/// ABC123! to ＡＢＣ１２３！.
+pub fn to_fullwidth_ascii(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '!'..='~' => core::char::from_u32(c as u32 + FULL_WIDTH_SHIFT).unwrap(),
+            ' ' => '\u{3000}',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts half-width katakana and punctuation to full-width, e.g. ｶﾞｯｺｳ｡
+/// to ガッコウ。, joining the separate (han)dakuten to the kana before them.
+pub fn to_fullwidth_katakana(s: &str) -> String {
+    let mut fullwidth = String::with_capacity(s.len());
+    for c in s.chars() {
+        let c = match c as u32 {
+            0xFF61..=0xFF9F => HALF_WIDTH_FORMS.chars().nth(c as usize - 0xFF61).unwrap(),
+            _ => c,
+        };
+        let joined = match (c, fullwidth.chars().last()) {
+            ('゛', Some(base)) => swap(base, UNVOICED, VOICED),
+            ('゜', Some(base)) => swap(base, UNVOICED_H, SEMI_VOICED),
+            _ => None,
+        };
+        match joined {
+            Some(joined) => {
+                fullwidth.pop();
+                fullwidth.push(joined);
+            }
+            None => fullwidth.push(c),
+        }
+    }
+
+    fullwidth
+}
+
+/// Converts katakana and Japanese punctuation to half-width, e.g. ガッコウ。
+/// to ｶﾞｯｺｳ｡, splitting off the (han)dakuten. Katakana without a half-width
+/// form, like ヶ, are kept.
+pub fn to_halfwidth_katakana(s: &str) -> String {
+    let mut halfwidth = String::with_capacity(s.len());
+    for c in s.chars() {
+        let (base, mark) = match (swap(c, VOICED, UNVOICED), swap(c, SEMI_VOICED, UNVOICED_H)) {
+            (Some(base), _) => (base, Some('ﾞ')),
+            (_, Some(base)) => (base, Some('ﾟ')),
+            _ => (c, None),
+        };
+        match HALF_WIDTH_FORMS.chars().position(|form| form == base) {
+            Some(i) => {
+                halfwidth.push(core::char::from_u32(0xFF61 + i as u32).unwrap());
+                halfwidth.extend(mark);
+            }
+            None => halfwidth.push(c),
+        }
+    }
+
+    halfwidth
+}
+
+/// Normalizes the widths the way search and matching usually want them:
+/// ASCII half-width and katakana full-width, e.g. ＡＢＣｶﾀｶﾅ to ABCカタカナ.
+pub fn normalize_width(s: &str) -> String {
+    to_fullwidth_katakana(&to_halfwidth_ascii(s))
+}
+
+/// The char at the same position in `to` as `c` is in `from`.
+fn swap(c: char, from: &str, to: &str) -> Option<char> {
+    from.chars()
+        .position(|other| other == c)
+        .and_then(|i| to.chars().nth(i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(to_halfwidth_ascii("ＡＢＣ１２３！　～"), "ABC123! ~");
+        assert_eq!(to_fullwidth_ascii("ABC123! ~"), "ＡＢＣ１２３！　～");
+        assert_eq!(to_halfwidth_ascii("令和３年"), "令和3年");
+        assert_eq!(to_fullwidth_ascii("ｶﾀｶﾅ"), "ｶﾀｶﾅ");
+    }
+
+    #[test]
+    fn test_katakana() {
+        assert_eq!(HALF_WIDTH_FORMS.chars().count(), 0xFF9F - 0xFF61 + 1);
+        assert_eq!(to_fullwidth_katakana("ｶﾞｯｺｳ｡"), "ガッコウ。");
+        assert_eq!(to_fullwidth_katakana("ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ ｳﾞｧ"), "パピプペポ ヴァ");
+        assert_eq!(to_fullwidth_katakana("ﾞｱﾞ"), "゛ア゛");
+        assert_eq!(to_fullwidth_katakana("｢ﾗｰﾒﾝ｣"), "「ラーメン」");
+        assert_eq!(to_halfwidth_katakana("ガッコウ。"), "ｶﾞｯｺｳ｡");
+        assert_eq!(to_halfwidth_katakana("「パピプペポ」ヴ"), "｢ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ｣ｳﾞ");
+        assert_eq!(to_halfwidth_katakana("ヶ月"), "ヶ月");
+        assert_eq!(
+            to_fullwidth_katakana(&to_halfwidth_katakana("ヂヅヷヺ")),
+            "ヂヅヷヺ"
+        );
+    }
+
+    #[test]
+    fn test_normalize_width() {
+        assert_eq!(normalize_width("ＡＢＣｶﾀｶﾅ１２３"), "ABCカタカナ123");
+    }
+}